@@ -0,0 +1,279 @@
+//! Implementation of [`Getters`] and [`Setters`] derive macros.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens as _};
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream},
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::utils::{
+    attr::{self, ParseMultiple as _},
+    Spanning,
+};
+
+/// Expands a [`Getters`] or a [`Setters`] derive macro.
+///
+/// Available macros:
+/// - [`Getters`]
+/// - [`Setters`]
+pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenStream> {
+    let (setters, attr_name) = match trait_name {
+        "Getters" => (false, format_ident!("getter")),
+        "Setters" => (true, format_ident!("setter")),
+        _ => unimplemented!(),
+    };
+
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        syn::Data::Struct(data) => {
+            return Err(syn::Error::new(
+                data.struct_token.span(),
+                format!(
+                    "`{trait_name}` can be derived only for structs with named fields"
+                ),
+            ))
+        }
+        syn::Data::Enum(data) => {
+            return Err(syn::Error::new(
+                data.enum_token.span(),
+                format!("`{trait_name}` cannot be derived for enums"),
+            ))
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                format!("`{trait_name}` cannot be derived for unions"),
+            ))
+        }
+    };
+
+    let container = Attribute::parse_attrs(&input.attrs, &attr_name)?
+        .map(Spanning::into_inner)
+        .unwrap_or_default();
+    if let Some(span) = [container.copy, container.deref, container.into]
+        .into_iter()
+        .flatten()
+        .next()
+    {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "only `skip` and `vis = ...` arguments are allowed in \
+                 `#[{attr_name}(...)]` attribute placed on a struct",
+            ),
+        ));
+    }
+    // Methods are no more visible than the struct itself by default, so private fields of a
+    // private or restricted struct don't leak through them.
+    let default_vis = container.vis.unwrap_or_else(|| input.vis.clone());
+
+    let mut methods = vec![];
+    for field in fields {
+        let attr = Attribute::parse_attrs(&field.attrs, &attr_name)?;
+        let enabled = match &attr {
+            Some(attr) => !attr.skip,
+            None => !container.skip,
+        };
+        let attr = attr.map(Spanning::into_inner).unwrap_or_default();
+        attr.validate(&attr_name, setters)?;
+        if !enabled {
+            continue;
+        }
+
+        let ident = field.ident.as_ref().unwrap_or_else(|| unreachable!());
+        let field_name = ident.unraw().to_string();
+        let field_ty = &field.ty;
+        let vis = attr.vis.as_ref().unwrap_or(&default_vis);
+
+        methods.push(if setters {
+            let setter = format_ident!("set_{}", ident.unraw(), span = ident.span());
+            let (arg_ty, value) = if attr.into.is_some() {
+                (
+                    quote! { impl derive_more::core::convert::Into<#field_ty> },
+                    quote! { derive_more::core::convert::Into::into(value) },
+                )
+            } else {
+                (quote! { #field_ty }, quote! { value })
+            };
+            let doc = format!("Sets the `{field_name}` field.");
+
+            quote! {
+                #[doc = #doc]
+                #[inline]
+                #vis fn #setter(&mut self, value: #arg_ty) -> &mut Self {
+                    self.#ident = #value;
+                    self
+                }
+            }
+        } else {
+            let (constness, return_ty, value) = if attr.copy.is_some() {
+                (
+                    quote! { const },
+                    quote! { #field_ty },
+                    quote! { self.#ident },
+                )
+            } else if attr.deref.is_some() {
+                (
+                    quote! {},
+                    quote! { &<#field_ty as derive_more::core::ops::Deref>::Target },
+                    quote! { derive_more::core::ops::Deref::deref(&self.#ident) },
+                )
+            } else {
+                (
+                    quote! { const },
+                    quote! { &#field_ty },
+                    quote! { &self.#ident },
+                )
+            };
+            let doc = format!("Returns the `{field_name}` field.");
+
+            quote! {
+                #[doc = #doc]
+                #[inline]
+                #[must_use]
+                #vis #constness fn #ident(&self) -> #return_ty {
+                    #value
+                }
+            }
+        });
+    }
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[allow(deprecated)] // omit warnings on deprecated fields
+        #[automatically_derived]
+        impl #impl_generics #ty #ty_generics #where_clause {
+            #( #methods )*
+        }
+    })
+}
+
+/// Representation of a [`Getters`]/[`Setters`] derive macro struct container or field attribute.
+///
+/// ```rust,ignore
+/// #[<attribute>]
+/// #[<attribute>(skip)]
+/// #[<attribute>(ignore)]
+/// #[<attribute>(vis = <visibility>)]
+/// #[getter(copy)]
+/// #[getter(deref)]
+/// #[setter(into)]
+/// ```
+///
+/// Multiple arguments can be specified in a single attribute, separated by commas.
+#[derive(Default)]
+struct Attribute {
+    /// Indicator whether the accessor should not be generated.
+    ///
+    /// Placed on a struct, makes the fields not having the attribute being skipped.
+    skip: bool,
+
+    /// [`Span`] of the `copy` argument, if any, making a getter returning the field by value.
+    copy: Option<Span>,
+
+    /// [`Span`] of the `deref` argument, if any, making a getter returning a [`Deref::Target`] of
+    /// the field.
+    ///
+    /// [`Deref::Target`]: std::ops::Deref::Target
+    deref: Option<Span>,
+
+    /// [`Span`] of the `into` argument, if any, making a setter accepting any type [`Into`] the
+    /// field type.
+    into: Option<Span>,
+
+    /// [`syn::Visibility`] of the generated accessor, if specified.
+    vis: Option<syn::Visibility>,
+}
+
+impl Attribute {
+    /// Validates this [`Attribute`] placed on a field to contain only the arguments applicable to
+    /// getters or setters.
+    fn validate(&self, attr_name: &syn::Ident, setters: bool) -> syn::Result<()> {
+        let invalid = if setters {
+            vec![("copy", self.copy), ("deref", self.deref)]
+        } else {
+            vec![("into", self.into)]
+        };
+        if let Some((arg, span)) = invalid
+            .into_iter()
+            .find_map(|(arg, span)| span.map(|s| (arg, s)))
+        {
+            return Err(syn::Error::new(
+                span,
+                format!("`{arg}` argument is not allowed in `#[{attr_name}(...)]` attribute"),
+            ));
+        }
+        if let (Some(_), Some(span)) = (self.copy, self.deref) {
+            return Err(syn::Error::new(
+                span,
+                "`copy` and `deref` arguments cannot be combined",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Parse for Attribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut attr = Self::default();
+        loop {
+            let ident = input.call(syn::Ident::parse_any)?;
+            let span = ident.span();
+            match ident.to_string().as_str() {
+                "skip" | "ignore" => attr.skip = true,
+                "copy" => attr.copy = Some(span),
+                "deref" => attr.deref = Some(span),
+                "into" => attr.into = Some(span),
+                "vis" => {
+                    _ = input.parse::<token::Eq>()?;
+                    attr.vis = Some(input.parse()?);
+                }
+                _ => return Err(syn::Error::new(
+                    span,
+                    "unknown attribute argument, expected `skip`, `ignore`, `copy`, \
+                         `deref`, `into` or `vis = ...`",
+                )),
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            _ = input.parse::<token::Comma>()?;
+            if input.is_empty() {
+                break;
+            }
+        }
+        Ok(attr)
+    }
+}
+
+impl attr::ParseMultiple for Attribute {
+    fn parse_attr_with<P: attr::Parser>(
+        attr: &syn::Attribute,
+        parser: &P,
+    ) -> syn::Result<Self> {
+        match &attr.meta {
+            syn::Meta::Path(_) => Ok(Self::default()),
+            syn::Meta::List(_) => {
+                attr.parse_args_with(|ps: ParseStream<'_>| parser.parse(ps))
+            }
+            syn::Meta::NameValue(meta) => {
+                let name = meta.path.to_token_stream();
+                Err(syn::Error::new(
+                    meta.eq_token.span(),
+                    format!(
+                        "only `#[{name}]` or `#[{name}(...)]` forms are allowed here"
+                    ),
+                ))
+            }
+        }
+    }
+}
@@ -19,23 +19,33 @@ pub fn expand(
     input: &syn::DeriveInput,
     trait_info: ExpansionCtx<'_>,
 ) -> syn::Result<TokenStream> {
-    let (trait_ident, attr_name, _) = trait_info;
+    let (trait_ident, _, _) = trait_info;
 
-    let data = match &input.data {
-        syn::Data::Struct(data) => Ok(data),
-        syn::Data::Enum(e) => Err(syn::Error::new(
-            e.enum_token.span(),
-            format!("`{trait_ident}` cannot be derived for enums"),
-        )),
-        syn::Data::Union(u) => Err(syn::Error::new(
-            u.union_token.span(),
-            format!("`{trait_ident}` cannot be derived for unions"),
-        )),
-    }?;
-
-    let expansions = if let Some(attr) =
-        StructAttribute::parse_attrs(&input.attrs, attr_name)?
-    {
+    let expansions = match &input.data {
+        syn::Data::Struct(data) => expand_struct(input, data, trait_info)?,
+        syn::Data::Enum(data) => vec![expand_enum(input, data, trait_info)?],
+        syn::Data::Union(u) => {
+            return Err(syn::Error::new(
+                u.union_token.span(),
+                format!("`{trait_ident}` cannot be derived for unions"),
+            ))
+        }
+    };
+    Ok(expansions
+        .into_iter()
+        .map(ToTokens::into_token_stream)
+        .collect())
+}
+
+/// Creates [`Expansion`]s of an [`AsRef`]/[`AsMut`] derive macro for the provided struct.
+fn expand_struct<'a>(
+    input: &'a syn::DeriveInput,
+    data: &'a syn::DataStruct,
+    trait_info: ExpansionCtx<'a>,
+) -> syn::Result<Vec<Expansion<'a>>> {
+    let (_, attr_name, _) = trait_info;
+
+    if let Some(attr) = StructAttribute::parse_attrs(&input.attrs, attr_name)? {
         if data.fields.len() != 1 {
             return Err(syn::Error::new(
                 if data.fields.is_empty() {
@@ -58,89 +68,181 @@ pub fn expand(
             ));
         }
 
-        vec![Expansion {
+        return Ok(vec![Expansion {
             trait_info,
             ident: &input.ident,
             generics: &input.generics,
-            field,
-            field_index: 0,
+            fields: vec![SelectedField {
+                variant: None,
+                field,
+                index: 0,
+            }],
             conversions: Some(attr.into_inner()),
-        }]
-    } else {
-        let attrs = data
-            .fields
-            .iter()
-            .map(|field| FieldAttribute::parse_attrs(&field.attrs, attr_name))
-            .collect::<syn::Result<Vec<_>>>()?;
-
-        let present_attrs = attrs.iter().filter_map(Option::as_ref).collect::<Vec<_>>();
-
-        let all = present_attrs
-            .iter()
-            .all(|attr| matches!(attr.item, FieldAttribute::Skip(_)));
-
-        if !all {
-            if let Some(skip_attr) = present_attrs.iter().find_map(|attr| {
-                if let FieldAttribute::Skip(skip) = &attr.item {
-                    Some(attr.as_ref().map(|_| skip))
-                } else {
-                    None
-                }
-            }) {
+        }]);
+    }
+
+    Ok(select_fields(&data.fields, attr_name, "struct")?
+        .into_iter()
+        .map(|(field, conversions)| Expansion {
+            trait_info,
+            ident: &input.ident,
+            generics: &input.generics,
+            fields: vec![field],
+            conversions,
+        })
+        .collect())
+}
+
+/// Creates an [`Expansion`] of an [`AsRef`]/[`AsMut`] derive macro for the provided enum, using a
+/// single selected field of each its variant.
+fn expand_enum<'a>(
+    input: &'a syn::DeriveInput,
+    data: &'a syn::DataEnum,
+    trait_info: ExpansionCtx<'a>,
+) -> syn::Result<Expansion<'a>> {
+    let (trait_ident, attr_name, _) = trait_info;
+
+    if data.variants.is_empty() {
+        return Err(syn::Error::new(
+            data.enum_token.span(),
+            format!("`{trait_ident}` cannot be derived for enums with no variants"),
+        ));
+    }
+    if let Some(attr) = data
+        .variants
+        .iter()
+        .flat_map(|v| &v.attrs)
+        .find(|a| a.path().is_ident(attr_name))
+    {
+        return Err(syn::Error::new(
+            attr.span(),
+            format!(
+                "`#[{attr_name}(...)]` attribute cannot be placed on enum variants, only on \
+                 their fields or the enum itself",
+            ),
+        ));
+    }
+
+    let container = StructAttribute::parse_attrs(&input.attrs, attr_name)?
+        .map(Spanning::into_inner);
+
+    let mut fields = vec![];
+    let mut conversions = vec![];
+    for variant in &data.variants {
+        let mut selected = select_fields(&variant.fields, attr_name, "variant")?;
+        if selected.len() != 1 {
+            return Err(syn::Error::new(
+                variant.span(),
+                format!(
+                    "`{trait_ident}` can be derived for enums only when exactly one field is \
+                     selected in each variant, consider marking it with `#[{attr_name}]` \
+                     attribute",
+                ),
+            ));
+        }
+        let (mut field, conversion) = selected.pop().unwrap();
+        if container.is_some() && conversion.is_some() {
+            return Err(syn::Error::new(
+                field.field.span(),
+                format!(
+                    "`#[{attr_name}(...)]` cannot be placed on both enum and its variant's \
+                     field",
+                ),
+            ));
+        }
+        field.variant = Some(&variant.ident);
+
+        let key = match &conversion {
+            None if container.is_none() => Some(field.field.ty.to_token_stream()),
+            None => None,
+            Some(attr::Conversion::Forward(_)) => Some(quote! { forward }),
+            Some(attr::Conversion::Types(tys)) => Some(tys.0.to_token_stream()),
+        }
+        .map(|key| key.to_string());
+        if let Some((prev, _)) = conversions.first() {
+            if *prev != key {
                 return Err(syn::Error::new(
-                    skip_attr.span(),
+                    field.field.span(),
                     format!(
-                        "`#[{attr_name}({})]` cannot be used in the same struct with other \
-                         `#[{attr_name}(...)]` attributes",
-                        skip_attr.name(),
+                        "`{trait_ident}` can be derived for enums only when the selected fields \
+                         of all variants are converted into the same types, consider \
+                         specifying them via `#[{attr_name}(<types>)]` attribute on the enum",
                     ),
                 ));
             }
         }
 
-        if all {
-            data.fields
-                .iter()
-                .enumerate()
-                .zip(attrs)
-                .filter_map(|((i, field), attr)| {
-                    attr.is_none().then_some(Expansion {
-                        trait_info,
-                        ident: &input.ident,
-                        generics: &input.generics,
-                        field,
-                        field_index: i,
-                        conversions: None,
-                    })
-                })
-                .collect()
-        } else {
-            data.fields
-                .iter()
-                .enumerate()
-                .zip(attrs)
-                .filter_map(|((i, field), attr)| match attr.map(Spanning::into_inner) {
-                    Some(
-                        attr @ (FieldAttribute::Empty(_)
-                        | FieldAttribute::Forward(_)
-                        | FieldAttribute::Types(_)),
-                    ) => Some(Expansion {
-                        trait_info,
-                        ident: &input.ident,
-                        generics: &input.generics,
-                        field,
-                        field_index: i,
-                        conversions: attr.into(),
-                    }),
-                    Some(FieldAttribute::Skip(_)) => unreachable!(),
-                    None => None,
-                })
-                .collect()
+        fields.push(field);
+        conversions.push((key, conversion));
+    }
+
+    Ok(Expansion {
+        trait_info,
+        ident: &input.ident,
+        generics: &input.generics,
+        fields,
+        conversions: container.or_else(|| conversions.swap_remove(0).1),
+    })
+}
+
+/// Selects the fields of a struct or an enum variant to generate [`AsRef`]/[`AsMut`]
+/// implementations for, according to their attributes, along with the conversions to be
+/// generated for each of them.
+fn select_fields<'a>(
+    fields: &'a syn::Fields,
+    attr_name: &syn::Ident,
+    container_kind: &str,
+) -> syn::Result<Vec<(SelectedField<'a>, Option<attr::Conversion>)>> {
+    let attrs = fields
+        .iter()
+        .map(|field| FieldAttribute::parse_attrs(&field.attrs, attr_name))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let present_attrs = attrs.iter().filter_map(Option::as_ref).collect::<Vec<_>>();
+
+    let all = present_attrs
+        .iter()
+        .all(|attr| matches!(attr.item, FieldAttribute::Skip(_)));
+
+    if !all {
+        if let Some(skip_attr) = present_attrs.iter().find_map(|attr| {
+            if let FieldAttribute::Skip(skip) = &attr.item {
+                Some(attr.as_ref().map(|_| skip))
+            } else {
+                None
+            }
+        }) {
+            return Err(syn::Error::new(
+                skip_attr.span(),
+                format!(
+                    "`#[{attr_name}({})]` cannot be used in the same {container_kind} with \
+                     other `#[{attr_name}(...)]` attributes",
+                    skip_attr.name(),
+                ),
+            ));
         }
-    };
-    Ok(expansions
-        .into_iter()
-        .map(ToTokens::into_token_stream)
+    }
+
+    Ok(fields
+        .iter()
+        .enumerate()
+        .zip(attrs)
+        .filter_map(|((index, field), attr)| {
+            let field = SelectedField {
+                variant: None,
+                field,
+                index,
+            };
+            match attr.map(Spanning::into_inner) {
+                None => all.then_some((field, None)),
+                Some(FieldAttribute::Skip(_)) => None,
+                Some(
+                    attr @ (FieldAttribute::Empty(_)
+                    | FieldAttribute::Forward(_)
+                    | FieldAttribute::Types(_)),
+                ) => Some((field, attr.into())),
+            }
+        })
         .collect())
 }
 
@@ -153,44 +255,57 @@ pub fn expand(
 type ExpansionCtx<'a> = (&'a syn::Ident, &'a syn::Ident, Option<&'a Token![mut]>);
 
 /// Expansion of a macro for generating [`AsRef`]/[`AsMut`] implementations for a single field of a
-/// struct.
+/// struct, or for a single field of each enum variant.
 struct Expansion<'a> {
     /// [`ExpansionCtx`] of the derived trait.
     trait_info: ExpansionCtx<'a>,
 
-    /// [`syn::Ident`] of the struct.
+    /// [`syn::Ident`] of the struct or enum.
     ///
     /// [`syn::Ident`]: struct@syn::Ident
     ident: &'a syn::Ident,
 
-    /// [`syn::Generics`] of the struct.
+    /// [`syn::Generics`] of the struct or enum.
     generics: &'a syn::Generics,
 
-    /// [`syn::Field`] of the struct.
+    /// [`SelectedField`]s to generate implementations for: a single one for a struct, or one for
+    /// each enum variant.
+    fields: Vec<SelectedField<'a>>,
+
+    /// Attribute specifying which conversions should be generated.
+    conversions: Option<attr::Conversion>,
+}
+
+/// [`syn::Field`] of a struct or an enum variant selected for an [`Expansion`].
+struct SelectedField<'a> {
+    /// [`syn::Ident`] of the enum variant, if this [`SelectedField`] belongs to one.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    variant: Option<&'a syn::Ident>,
+
+    /// Selected [`syn::Field`].
     field: &'a syn::Field,
 
     /// Index of the [`syn::Field`].
-    field_index: usize,
+    index: usize,
+}
 
-    /// Attribute specifying which conversions should be generated.
-    conversions: Option<attr::Conversion>,
+impl SelectedField<'_> {
+    /// Returns the [`syn::Member`] of this [`SelectedField`].
+    fn member(&self) -> syn::Member {
+        self.field.ident.clone().map_or_else(
+            || syn::Member::Unnamed(syn::Index::from(self.index)),
+            syn::Member::Named,
+        )
+    }
 }
 
 impl ToTokens for Expansion<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let field_ty = &self.field.ty;
-        let field_ident = self.field.ident.as_ref().map_or_else(
-            || Either::Right(syn::Index::from(self.field_index)),
-            Either::Left,
-        );
-
         let (trait_ident, method_ident, mut_) = &self.trait_info;
         let ty_ident = &self.ident;
 
-        let field_ref = quote! { & #mut_ self.#field_ident };
-
         let generics_search = GenericsSearch::from(self.generics);
-        let field_contains_generics = generics_search.any_in(field_ty);
 
         let is_blanket =
             matches!(&self.conversions, Some(attr::Conversion::Forward(_)));
@@ -202,7 +317,7 @@ impl ToTokens for Expansion<'_> {
             Some(attr::Conversion::Types(tys)) => {
                 Either::Right(tys.0.iter().map(Cow::Borrowed))
             }
-            None => Either::Left(iter::once(Cow::Borrowed(field_ty))),
+            None => Either::Left(iter::once(Cow::Borrowed(&self.fields[0].field.ty))),
         };
 
         for return_ty in return_tys {
@@ -221,55 +336,81 @@ impl ToTokens for Expansion<'_> {
                 Specialized,
             }
 
-            let impl_kind = if is_blanket {
-                ImplKind::Forwarded
-            } else if field_ty == return_ty.as_ref() {
-                ImplKind::Direct
-            } else if field_contains_generics || generics_search.any_in(&return_ty) {
-                ImplKind::Forwarded
-            } else {
-                ImplKind::Specialized
-            };
-
             let trait_ty = quote! {
                 derive_more::core::convert::#trait_ident <#return_ty>
             };
 
-            let generics = match &impl_kind {
-                ImplKind::Forwarded => {
-                    let mut generics = self.generics.clone();
-                    generics
-                        .make_where_clause()
-                        .predicates
-                        .push(parse_quote! { #field_ty: #trait_ty });
-                    if is_blanket {
-                        generics
-                            .params
-                            .push(parse_quote! { #return_ty: ?derive_more::core::marker::Sized });
+            let mut predicates = Vec::<syn::WherePredicate>::new();
+            let bodies = self.fields.iter().map(|selected| {
+                let field_ty = &selected.field.ty;
+                let member = selected.member();
+                let field_ref = if selected.variant.is_some() {
+                    quote! { __derive_more_field }
+                } else {
+                    quote! { & #mut_ self.#member }
+                };
+
+                let impl_kind = if is_blanket {
+                    ImplKind::Forwarded
+                } else if field_ty == return_ty.as_ref() {
+                    ImplKind::Direct
+                } else if generics_search.any_in(field_ty)
+                    || generics_search.any_in(&return_ty)
+                {
+                    ImplKind::Forwarded
+                } else {
+                    ImplKind::Specialized
+                };
+
+                let body = match &impl_kind {
+                    ImplKind::Direct => field_ref,
+                    ImplKind::Forwarded => {
+                        predicates.push(parse_quote! { #field_ty: #trait_ty });
+                        quote! {
+                            <#field_ty as #trait_ty>::#method_ident(#field_ref)
+                        }
                     }
-                    Cow::Owned(generics)
+                    ImplKind::Specialized => quote! {
+                        use derive_more::__private::ExtractRef as _;
+
+                        let conv =
+                            <derive_more::__private::Conv<& #mut_ #field_ty, #return_ty>
+                             as derive_more::core::default::Default>::default();
+                        (&&conv).__extract_ref(#field_ref)
+                    },
+                };
+
+                match selected.variant {
+                    Some(variant) => quote! {
+                        Self::#variant { #member: __derive_more_field, .. } => { #body }
+                    },
+                    None => body,
                 }
-                ImplKind::Direct | ImplKind::Specialized => {
-                    Cow::Borrowed(self.generics)
+            }).collect::<Vec<_>>();
+            let body = if self.fields.iter().any(|f| f.variant.is_some()) {
+                quote! {
+                    match self {
+                        #( #bodies )*
+                    }
                 }
+            } else {
+                quote! { #( #bodies )* }
             };
-            let (impl_gens, _, where_clause) = generics.split_for_impl();
-            let (_, ty_gens, _) = self.generics.split_for_impl();
 
-            let body = match &impl_kind {
-                ImplKind::Direct => Cow::Borrowed(&field_ref),
-                ImplKind::Forwarded => Cow::Owned(quote! {
-                    <#field_ty as #trait_ty>::#method_ident(#field_ref)
-                }),
-                ImplKind::Specialized => Cow::Owned(quote! {
-                    use derive_more::__private::ExtractRef as _;
-
-                    let conv =
-                        <derive_more::__private::Conv<& #mut_ #field_ty, #return_ty>
-                         as derive_more::core::default::Default>::default();
-                    (&&conv).__extract_ref(#field_ref)
-                }),
+            let generics = if predicates.is_empty() && !is_blanket {
+                Cow::Borrowed(self.generics)
+            } else {
+                let mut generics = self.generics.clone();
+                generics.make_where_clause().predicates.extend(predicates);
+                if is_blanket {
+                    generics.params.push(
+                        parse_quote! { #return_ty: ?derive_more::core::marker::Sized },
+                    );
+                }
+                Cow::Owned(generics)
             };
+            let (impl_gens, _, where_clause) = generics.split_for_impl();
+            let (_, ty_gens, _) = self.generics.split_for_impl();
 
             quote! {
                 #[allow(deprecated)] // omit warnings on deprecated fields/variants
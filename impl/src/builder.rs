@@ -0,0 +1,307 @@
+//! Implementation of a [`Builder`] derive macro.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream},
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::utils::{
+    attr::{self, ParseMultiple as _},
+    Spanning,
+};
+
+/// Expands a [`Builder`] derive macro.
+pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStream> {
+    let attr_name = format_ident!("builder");
+
+    if let Some(attr) = input.attrs.iter().find(|a| a.path().is_ident(&attr_name)) {
+        return Err(syn::Error::new(
+            attr.span(),
+            "`#[builder(...)]` attribute can be placed only on fields",
+        ));
+    }
+
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        syn::Data::Struct(data) => {
+            return Err(syn::Error::new(
+                data.struct_token.span(),
+                "`Builder` can be derived only for structs with named fields",
+            ))
+        }
+        syn::Data::Enum(data) => {
+            return Err(syn::Error::new(
+                data.enum_token.span(),
+                "`Builder` cannot be derived for enums",
+            ))
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "`Builder` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let ty = &input.ident;
+    let ty_name = ty.unraw().to_string();
+    let vis = &input.vis;
+    let builder_ty = format_ident!("{ty}Builder");
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut builder_fields = vec![];
+    let mut setters = vec![];
+    let mut initializers = vec![];
+    let mut required = vec![];
+    let mut missing_checks = vec![];
+    for field in fields {
+        let FieldAttribute { default, into } =
+            FieldAttribute::parse_attrs(&field.attrs, &attr_name)?
+                .map(Spanning::into_inner)
+                .unwrap_or_default();
+
+        let ident = field.ident.as_ref().unwrap_or_else(|| unreachable!());
+        let field_name = ident.unraw().to_string();
+        let field_ty = &field.ty;
+
+        if field_name == "build" {
+            return Err(syn::Error::new(
+                ident.span(),
+                "field named `build` conflicts with the `build()` method of the generated \
+                 builder, consider renaming it",
+            ));
+        }
+
+        builder_fields.push(quote! {
+            #ident: derive_more::core::option::Option<#field_ty>
+        });
+
+        let (arg_ty, value) = if into {
+            (
+                quote! { impl derive_more::core::convert::Into<#field_ty> },
+                quote! { derive_more::core::convert::Into::into(value) },
+            )
+        } else {
+            (quote! { #field_ty }, quote! { value })
+        };
+        let doc = format!("Sets the `{field_name}` field of the built `{ty_name}`.");
+        setters.push(quote! {
+            #[doc = #doc]
+            #[inline]
+            #[must_use]
+            #vis fn #ident(mut self, value: #arg_ty) -> Self {
+                self.#ident = derive_more::core::option::Option::Some(#value);
+                self
+            }
+        });
+
+        let fallback = match default {
+            Some(Some(expr)) => quote! { #expr },
+            Some(None) => quote! { derive_more::core::default::Default::default() },
+            None => {
+                let bit = required.len();
+                if bit >= MAX_REQUIRED_FIELDS {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!(
+                            "`Builder` supports at most {MAX_REQUIRED_FIELDS} required fields, \
+                             consider making some of them optional with \
+                             `#[{attr_name}(default)]`",
+                        ),
+                    ));
+                }
+                required.push(field_name);
+                missing_checks.push(quote! {
+                    if self.#ident.is_none() {
+                        __missing |= 1 << #bit;
+                    }
+                });
+                quote! { derive_more::core::unreachable!() }
+            }
+        };
+        initializers.push(quote! {
+            #ident: match self.#ident {
+                derive_more::core::option::Option::Some(value) => value,
+                derive_more::core::option::Option::None => #fallback,
+            }
+        });
+    }
+    let field_idents = fields.iter().map(|f| &f.ident);
+
+    let builder_doc = format!("Builder of `{ty_name}`.");
+    let builder_fn_doc =
+        format!("Creates a new [`{builder_ty}`] for building a `{ty_name}`.");
+    let build_fn_doc = format!(
+        "Builds a `{ty_name}` out of this [`{builder_ty}`].\n\n\
+         # Errors\n\n\
+         If any of the required fields have not been set.",
+    );
+
+    Ok(quote! {
+        #[doc = #builder_doc]
+        #vis struct #builder_ty #generics #where_clause {
+            #( #builder_fields, )*
+        }
+
+        #[allow(deprecated)] // omit warnings on deprecated fields
+        #[automatically_derived]
+        impl #impl_generics #ty #ty_generics #where_clause {
+            #[doc = #builder_fn_doc]
+            #[inline]
+            #[must_use]
+            #vis fn builder() -> #builder_ty #ty_generics {
+                derive_more::core::default::Default::default()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics derive_more::core::default::Default for #builder_ty #ty_generics
+             #where_clause
+        {
+            #[inline]
+            fn default() -> Self {
+                Self {
+                    #( #field_idents: derive_more::core::option::Option::None, )*
+                }
+            }
+        }
+
+        #[allow(deprecated)] // omit warnings on deprecated fields
+        #[automatically_derived]
+        impl #impl_generics #builder_ty #ty_generics #where_clause {
+            #( #setters )*
+
+            #[doc = #build_fn_doc]
+            #vis fn build(
+                self,
+            ) -> derive_more::core::result::Result<#ty #ty_generics, derive_more::BuilderError> {
+                let mut __missing = 0_u128;
+                #( #missing_checks )*
+                if __missing != 0 {
+                    return derive_more::core::result::Result::Err(
+                        derive_more::BuilderError::new(#ty_name, &[#( #required ),*], __missing),
+                    );
+                }
+
+                derive_more::core::result::Result::Ok(#ty {
+                    #( #initializers, )*
+                })
+            }
+        }
+    })
+}
+
+/// Maximum number of required fields supported by a [`Builder`] derive macro, being tracked as bits
+/// of a [`u128`] in the generated `build()` method.
+const MAX_REQUIRED_FIELDS: usize = u128::BITS as usize;
+
+/// Representation of a [`Builder`] derive macro field attribute.
+///
+/// ```rust,ignore
+/// #[builder(default)]
+/// #[builder(default = <expr>)]
+/// #[builder(into)]
+/// #[builder(default = <expr>, into)]
+/// ```
+///
+/// Multiple `#[builder(...)]` attributes are merged together, but each argument can be specified
+/// only once.
+#[derive(Default)]
+struct FieldAttribute {
+    /// Default value of the field, if it's optional to be set.
+    ///
+    /// [`None`] inside means that [`Default`] value of the field type should be used.
+    default: Option<Option<syn::Expr>>,
+
+    /// Indicator whether the setter accepts any type [`Into`] the field type.
+    into: bool,
+}
+
+impl Parse for FieldAttribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        mod ident {
+            use syn::custom_keyword;
+
+            custom_keyword!(default);
+            custom_keyword!(into);
+        }
+
+        let mut attr = Self::default();
+        loop {
+            let ahead = input.lookahead1();
+            if ahead.peek(ident::default) {
+                let span = input.parse::<ident::default>()?.span;
+                let expr = if input.peek(token::Eq) {
+                    _ = input.parse::<token::Eq>()?;
+                    Some(input.parse()?)
+                } else {
+                    None
+                };
+                if attr.default.replace(expr).is_some() {
+                    return Err(syn::Error::new(
+                        span,
+                        "only single `default` argument is allowed",
+                    ));
+                }
+            } else if ahead.peek(ident::into) {
+                let span = input.parse::<ident::into>()?.span;
+                if attr.into {
+                    return Err(syn::Error::new(
+                        span,
+                        "only single `into` argument is allowed",
+                    ));
+                }
+                attr.into = true;
+            } else {
+                return Err(ahead.error());
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            _ = input.parse::<token::Comma>()?;
+            if input.is_empty() {
+                break;
+            }
+        }
+        Ok(attr)
+    }
+}
+
+impl attr::ParseMultiple for FieldAttribute {
+    fn merge_attrs(
+        prev: Spanning<Self>,
+        new: Spanning<Self>,
+        name: &syn::Ident,
+    ) -> syn::Result<Spanning<Self>> {
+        let span = prev.span.join(new.span).unwrap_or(prev.span);
+        let new_span = new.span;
+        let mut prev = prev.into_inner();
+        let new = new.into_inner();
+
+        if prev.default.is_some() && new.default.is_some() {
+            return Err(syn::Error::new(
+                new_span,
+                format!("multiple `#[{name}(default)]` arguments aren't allowed"),
+            ));
+        }
+        if prev.into && new.into {
+            return Err(syn::Error::new(
+                new_span,
+                format!("multiple `#[{name}(into)]` arguments aren't allowed"),
+            ));
+        }
+        prev.default = prev.default.or(new.default);
+        prev.into |= new.into;
+
+        Ok(Spanning::new(prev, span))
+    }
+}
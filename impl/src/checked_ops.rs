@@ -0,0 +1,231 @@
+//! Implementation of [`CheckedOps`], [`SaturatingOps`] and [`WrappingOps`] derive macros.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::utils::{
+    attr::{self, ParseMultiple as _},
+    Spanning,
+};
+
+/// Expands a [`CheckedOps`], [`SaturatingOps`] or [`WrappingOps`] derive macro.
+///
+/// Available macros:
+/// - [`CheckedOps`]
+/// - [`SaturatingOps`]
+/// - [`WrappingOps`]
+pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenStream> {
+    let (mode, attr_name) = match trait_name {
+        "CheckedOps" => (Mode::Checked, format_ident!("checked_ops")),
+        "SaturatingOps" => (Mode::Saturating, format_ident!("saturating_ops")),
+        "WrappingOps" => (Mode::Wrapping, format_ident!("wrapping_ops")),
+        _ => unimplemented!(),
+    };
+
+    let data = match &input.data {
+        syn::Data::Struct(data) if matches!(data.fields, syn::Fields::Unit) => {
+            return Err(syn::Error::new(
+                data.struct_token.span(),
+                format!("`{trait_name}` cannot be derived for unit structs"),
+            ))
+        }
+        syn::Data::Struct(data) => data,
+        syn::Data::Enum(data) => {
+            return Err(syn::Error::new(
+                data.enum_token.span(),
+                format!("`{trait_name}` cannot be derived for enums"),
+            ))
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                format!("`{trait_name}` cannot be derived for unions"),
+            ))
+        }
+    };
+
+    let ops = ContainerAttribute::parse_attrs(&input.attrs, &attr_name)?
+        .map(Spanning::into_inner)
+        .map_or_else(|| Op::ALL.to_vec(), |attr| attr.ops);
+
+    let mut fields = vec![];
+    for (n, field) in data.fields.iter().enumerate() {
+        let member = field
+            .ident
+            .clone()
+            .map_or_else(|| syn::Member::from(n), syn::Member::from);
+        let skipped = attr::Skip::parse_attrs(&field.attrs, &attr_name)?.is_some();
+        fields.push((member, &field.ty, skipped));
+    }
+    if fields.iter().all(|(_, _, skipped)| *skipped) {
+        return Err(syn::Error::new(
+            data.struct_token.span(),
+            format!(
+                "`{trait_name}` cannot be derived for structs with all the fields being skipped",
+            ),
+        ));
+    }
+
+    let methods = ops.iter().map(|op| {
+        let method = format_ident!("{}_{}", mode.prefix(), op.name());
+        let fields = fields.iter().map(|(member, ty, skipped)| {
+            if *skipped {
+                quote! { #member: self.#member }
+            } else {
+                let question = matches!(mode, Mode::Checked).then(|| quote! { ? });
+                let method = syn::Ident::new(&method.to_string(), ty.span());
+                quote! { #member: <#ty>::#method(self.#member, rhs.#member) #question }
+            }
+        });
+        let (output, value) = match mode {
+            Mode::Checked => (
+                quote! { derive_more::core::option::Option<Self> },
+                quote! { derive_more::core::option::Option::Some(Self { #( #fields, )* }) },
+            ),
+            Mode::Saturating | Mode::Wrapping => {
+                (quote! { Self }, quote! { Self { #( #fields, )* } })
+            }
+        };
+        let doc = mode.doc(*op);
+
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            #[must_use]
+            pub fn #method(self, rhs: Self) -> #output {
+                #value
+            }
+        }
+    });
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[allow(deprecated)] // omit warnings on deprecated fields
+        #[automatically_derived]
+        impl #impl_generics #ty #ty_generics #where_clause {
+            #( #methods )*
+        }
+    })
+}
+
+/// Kind of the derived arithmetic methods.
+#[derive(Clone, Copy, Debug)]
+enum Mode {
+    /// `checked_*` methods, returning [`None`] if any of the fields overflows.
+    Checked,
+
+    /// `saturating_*` methods, saturating each field at its numeric bounds.
+    Saturating,
+
+    /// `wrapping_*` methods, wrapping around each field at its numeric bounds.
+    Wrapping,
+}
+
+impl Mode {
+    /// Returns the prefix of the method names for this [`Mode`].
+    const fn prefix(self) -> &'static str {
+        match self {
+            Self::Checked => "checked",
+            Self::Saturating => "saturating",
+            Self::Wrapping => "wrapping",
+        }
+    }
+
+    /// Generates a doc comment for the method performing the provided [`Op`] in this [`Mode`].
+    fn doc(self, op: Op) -> String {
+        let (name, sign) = match op {
+            Op::Add => ("addition", "+"),
+            Op::Sub => ("subtraction", "-"),
+            Op::Mul => ("multiplication", "*"),
+            Op::Div => ("division", "/"),
+        };
+        match self {
+            Self::Checked => format!(
+                "Checked {name}. Computes `self {sign} rhs` field-wise, returning `None` if \
+                 overflow occurred in any of the fields.",
+            ),
+            Self::Saturating => format!(
+                "Saturating {name}. Computes `self {sign} rhs` field-wise, saturating each field \
+                 at its numeric bounds instead of overflowing.",
+            ),
+            Self::Wrapping => format!(
+                "Wrapping {name}. Computes `self {sign} rhs` field-wise, wrapping around each \
+                 field at its numeric bounds.",
+            ),
+        }
+    }
+}
+
+/// Arithmetic operation to derive a method for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Op {
+    /// All the supported [`Op`]s, in the order their methods are generated by default.
+    const ALL: [Self; 4] = [Self::Add, Self::Sub, Self::Mul, Self::Div];
+
+    /// Returns the name of this [`Op`], used as a suffix of the derived method name.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Sub => "sub",
+            Self::Mul => "mul",
+            Self::Div => "div",
+        }
+    }
+}
+
+/// Representation of a [`CheckedOps`]-like derive macro struct container attribute.
+///
+/// ```rust,ignore
+/// #[<attribute>(<op>, <op>, ...)]
+/// ```
+///
+/// Possible `<op>`s are `add`, `sub`, `mul` and `div`.
+struct ContainerAttribute {
+    /// [`Op`]s to derive the methods for.
+    ops: Vec<Op>,
+}
+
+impl Parse for ContainerAttribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut ops = vec![];
+        for ident in Punctuated::<syn::Ident, token::Comma>::parse_terminated(input)? {
+            let op = Op::ALL
+                .into_iter()
+                .find(|op| ident == op.name())
+                .ok_or_else(|| {
+                    syn::Error::new(
+                        ident.span(),
+                        "unknown operation, expected one of: `add`, `sub`, `mul`, `div`",
+                    )
+                })?;
+            if ops.contains(&op) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("`{ident}` operation is specified more than once"),
+                ));
+            }
+            ops.push(op);
+        }
+        if ops.is_empty() {
+            return Err(input.error("at least one operation is expected"));
+        }
+        Ok(Self { ops })
+    }
+}
+
+impl attr::ParseMultiple for ContainerAttribute {}
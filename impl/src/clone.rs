@@ -0,0 +1,331 @@
+//! Implementation of [`Clone`] and [`Copy`] derive macros.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::utils::{
+    attr::{self, ParseMultiple as _},
+    pattern_matching::FieldsExt as _,
+    structural_inclusion::TypeExt as _,
+    GenericsSearch, Spanning,
+};
+
+/// Expands a [`Clone`] or a [`Copy`] derive macro.
+///
+/// Available macros:
+/// - [`Clone`]
+/// - [`Copy`]
+pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenStream> {
+    let (copy, attr_name) = match trait_name {
+        "Clone" => (false, format_ident!("clone")),
+        "Copy" => (true, format_ident!("copy")),
+        _ => unimplemented!(),
+    };
+
+    let bounds = BoundsAttribute::parse_attrs(&input.attrs, &attr_name)?
+        .map(Spanning::into_inner);
+
+    let mut variants = vec![];
+    match &input.data {
+        syn::Data::Struct(data) => {
+            variants.push(VariantClone::parse(None, &data.fields, &attr_name, copy)?);
+        }
+        syn::Data::Enum(data) => {
+            for variant in &data.variants {
+                if let Some(attr) =
+                    variant.attrs.iter().find(|a| a.path().is_ident(&attr_name))
+                {
+                    return Err(syn::Error::new(
+                        attr.span(),
+                        format!(
+                            "`#[{attr_name}(...)]` attribute cannot be placed on enum variants",
+                        ),
+                    ));
+                }
+                variants.push(VariantClone::parse(
+                    Some(&variant.ident),
+                    &variant.fields,
+                    &attr_name,
+                    copy,
+                )?);
+            }
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                format!("`{trait_name}` cannot be derived for unions"),
+            ))
+        }
+    }
+
+    Ok(StructuralExpansion {
+        self_ty: (&input.ident, &input.generics),
+        variants,
+        bounds,
+        is_enum: matches!(input.data, syn::Data::Enum(_)),
+        copy,
+    }
+    .into_token_stream())
+}
+
+/// Representation of a [`Clone`]/[`Copy`] derive macro struct container or enum attribute,
+/// overriding the inferred trait bounds.
+///
+/// ```rust,ignore
+/// #[clone(bound(<where-predicates>))]
+/// #[clone(bounds(<where-predicates>))]
+/// #[clone(where(<where-predicates>))]
+/// ```
+///
+/// Multiple `#[clone(bound(...))]` attributes are merged together.
+#[derive(Default)]
+struct BoundsAttribute(Punctuated<syn::WherePredicate, token::Comma>);
+
+impl Parse for BoundsAttribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let path = input.parse::<syn::Path>()?;
+        if !["bound", "bounds", "where"]
+            .into_iter()
+            .any(|i| path.is_ident(i))
+        {
+            return Err(syn::Error::new(
+                path.span(),
+                "unknown attribute argument, expected `bound(...)`",
+            ));
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+
+        content
+            .parse_terminated(syn::WherePredicate::parse, token::Comma)
+            .map(Self)
+    }
+}
+
+impl attr::ParseMultiple for BoundsAttribute {
+    fn merge_attrs(
+        prev: Spanning<Self>,
+        new: Spanning<Self>,
+        _: &syn::Ident,
+    ) -> syn::Result<Spanning<Self>> {
+        let span = prev.span.join(new.span).unwrap_or(prev.span);
+        let mut bounds = prev.into_inner().0;
+        bounds.extend(new.into_inner().0);
+        Ok(Spanning::new(Self(bounds), span))
+    }
+}
+
+/// Representation of a [`Clone`] derive macro field attribute, specifying a custom function to
+/// duplicate the field with.
+///
+/// ```rust,ignore
+/// #[clone(with = <path>)]
+/// ```
+struct WithAttribute(syn::Path);
+
+impl Parse for WithAttribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let ident = input.parse::<syn::Ident>()?;
+        if ident != "with" {
+            return Err(syn::Error::new(
+                ident.span(),
+                "only `with = <path>` allowed here",
+            ));
+        }
+        _ = input.parse::<token::Eq>()?;
+        input.parse().map(Self)
+    }
+}
+
+impl attr::ParseMultiple for WithAttribute {}
+
+/// Cloning of a single enum variant or a struct in a [`StructuralExpansion`].
+struct VariantClone<'i> {
+    /// [`syn::Ident`] of the enum variant, or [`None`] for a struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    ident: Option<&'i syn::Ident>,
+
+    /// [`syn::Fields`] of the enum variant/struct.
+    fields: &'i syn::Fields,
+
+    /// Custom functions to duplicate the [`syn::Fields`] with, if any.
+    with: Vec<Option<syn::Path>>,
+}
+
+impl<'i> VariantClone<'i> {
+    /// Parses a [`VariantClone`] from the provided enum variant or struct definition.
+    fn parse(
+        ident: Option<&'i syn::Ident>,
+        fields: &'i syn::Fields,
+        attr_name: &syn::Ident,
+        copy: bool,
+    ) -> syn::Result<Self> {
+        let with = fields
+            .iter()
+            .map(|field| {
+                if copy {
+                    if let Some(attr) =
+                        field.attrs.iter().find(|a| a.path().is_ident(attr_name))
+                    {
+                        return Err(syn::Error::new(
+                            attr.span(),
+                            format!(
+                                "`#[{attr_name}(...)]` attribute cannot be placed on fields",
+                            ),
+                        ));
+                    }
+                    return Ok(None);
+                }
+                Ok(WithAttribute::parse_attrs(&field.attrs, attr_name)?
+                    .map(|attr| attr.into_inner().0))
+            })
+            .collect::<syn::Result<_>>()?;
+
+        Ok(Self {
+            ident,
+            fields,
+            with,
+        })
+    }
+}
+
+/// Expansion of a macro for generating a structural [`Clone`]/[`Copy`] implementation of an enum
+/// or a struct.
+struct StructuralExpansion<'i> {
+    /// [`syn::Ident`] and [`syn::Generics`] of the enum/struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    self_ty: (&'i syn::Ident, &'i syn::Generics),
+
+    /// [`VariantClone`]s of the enum/struct to be cloned in this [`StructuralExpansion`].
+    variants: Vec<VariantClone<'i>>,
+
+    /// Trait bounds overriding the inferred ones, if any.
+    bounds: Option<BoundsAttribute>,
+
+    /// Indicator whether this expansion is for an enum.
+    is_enum: bool,
+
+    /// Indicator whether this expansion is for a [`Copy`] implementation.
+    copy: bool,
+}
+
+impl StructuralExpansion<'_> {
+    /// Generates body of the [`Clone::clone()`] method implementation for this
+    /// [`StructuralExpansion`].
+    fn body(&self) -> TokenStream {
+        // Special case: empty enum.
+        if self.is_enum && self.variants.is_empty() {
+            return quote! { match *self {} };
+        }
+
+        let match_arms =
+            self.variants.iter().map(|v| {
+                let variant = v.ident.map(|variant| quote! { :: #variant });
+                let self_pattern = v.fields.exhaustive_arm_pattern("__self_");
+                let fields = v.fields.iter().zip(&v.with).enumerate().map(
+                    |(n, (field, with))| {
+                        let member = field.ident.clone().map_or_else(
+                            || syn::Member::Unnamed(n.into()),
+                            syn::Member::Named,
+                        );
+                        let self_val = format_ident!("__self_{n}");
+                        let expr = with.as_ref().map_or_else(
+                        || quote! { derive_more::core::clone::Clone::clone(#self_val) },
+                        |with| quote! { #with(#self_val) },
+                    );
+                        quote! { #member: #expr }
+                    },
+                );
+
+                quote! {
+                    Self #variant #self_pattern => Self #variant { #( #fields ),* }
+                }
+            });
+
+        quote! {
+            match self {
+                #( #match_arms , )*
+            }
+        }
+    }
+}
+
+impl ToTokens for StructuralExpansion<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ty = self.self_ty.0;
+        let (_, ty_generics, _) = self.self_ty.1.split_for_impl();
+        let trait_ty = if self.copy {
+            quote! { derive_more::core::marker::Copy }
+        } else {
+            quote! { derive_more::core::clone::Clone }
+        };
+
+        let mut generics = self.self_ty.1.clone();
+        if let Some(bounds) = &self.bounds {
+            generics
+                .make_where_clause()
+                .predicates
+                .extend(bounds.0.iter().cloned());
+        } else {
+            let generics_search = GenericsSearch::from(self.self_ty.1);
+            let self_ty: syn::Type = parse_quote! { Self };
+            let implementor_ty: syn::Type = parse_quote! { #ty #ty_generics };
+            for v in &self.variants {
+                for field_ty in v
+                    .fields
+                    .iter()
+                    .zip(&v.with)
+                    .filter(|(_, with)| with.is_none())
+                    .map(|(field, _)| &field.ty)
+                {
+                    if generics_search.any_in(field_ty)
+                        && !field_ty.contains_type_structurally(&self_ty)
+                        && !field_ty.contains_type_structurally(&implementor_ty)
+                    {
+                        generics.make_where_clause().predicates.push(parse_quote! {
+                            #field_ty: #trait_ty
+                        });
+                    }
+                }
+            }
+        }
+        if self.copy && !generics.params.is_empty() {
+            generics.make_where_clause().predicates.insert(
+                0,
+                parse_quote! {
+                    Self: derive_more::core::clone::Clone
+                },
+            );
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let clone_method = (!self.copy).then(|| {
+            let body = self.body();
+            quote! {
+                #[inline]
+                fn clone(&self) -> Self { #body }
+            }
+        });
+
+        quote! {
+            #[allow(private_bounds)]
+            #[automatically_derived]
+            impl #impl_generics #trait_ty for #ty #ty_generics
+                 #where_clause
+            {
+                #clone_method
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
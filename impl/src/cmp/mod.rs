@@ -2,5 +2,9 @@
 //!
 //! [`cmp`]: std::cmp
 
+#[cfg(feature = "eq")]
 pub(crate) mod eq;
+#[cfg(feature = "ord")]
+pub(crate) mod ord;
+#[cfg(feature = "eq")]
 pub(crate) mod partial_eq;
@@ -0,0 +1,624 @@
+//! Implementation of [`PartialOrd`] and [`Ord`] derive macros.
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    Token,
+};
+
+use crate::utils::{
+    attr, pattern_matching::FieldsExt as _, structural_inclusion::TypeExt as _, Either,
+    GenericsSearch, HashSet, Spanning,
+};
+
+/// Expands a [`PartialOrd`] or an [`Ord`] derive macro.
+///
+/// Available macros:
+/// - [`Ord`]
+/// - [`PartialOrd`]
+pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenStream> {
+    let (total, attr_names) = match trait_name {
+        "PartialOrd" => (false, [format_ident!("partial_ord"), format_ident!("ord")]),
+        "Ord" => (true, [format_ident!("ord"), format_ident!("partial_ord")]),
+        _ => unimplemented!(),
+    };
+    // Fields ignored by `PartialEq`/`Eq` derives must be ignored in ordering too, otherwise the
+    // ordering becomes inconsistent with the equality.
+    let eq_attr_names = [format_ident!("partial_eq"), format_ident!("eq")];
+
+    let mut variants = vec![];
+    match &input.data {
+        syn::Data::Struct(data) => {
+            let container_attr =
+                parse_attrs::<ContainerAttribute>(&input.attrs, &attr_names)?;
+            let is_skipped = attr::Skip::is_present(&input.attrs, &eq_attr_names)?
+                || matches!(container_attr.as_deref(), Some(Either::Left(_)));
+            let order = container_attr.and_then(|attr| match attr.item {
+                Either::Left(_) => None,
+                Either::Right(order) => Some(Spanning::new(order, attr.span)),
+            });
+
+            variants.push(VariantComparison::parse(
+                None,
+                &data.fields,
+                is_skipped,
+                order,
+                &attr_names,
+                &eq_attr_names,
+            )?);
+        }
+        syn::Data::Enum(data) => {
+            let order = match parse_attrs::<ContainerAttribute>(
+                &input.attrs,
+                &attr_names,
+            )? {
+                Some(Spanning {
+                    item: Either::Left(skip),
+                    span,
+                }) => {
+                    return Err(syn::Error::new(
+                        span,
+                        format!(
+                            "`#[{}({})]` attribute can be placed only on enum variants or \
+                             fields",
+                            attr_names[0],
+                            skip.name(),
+                        ),
+                    ));
+                }
+                Some(Spanning {
+                    item: Either::Right(order),
+                    span,
+                }) => Some(Spanning::new(order, span)),
+                None => None,
+            };
+
+            for variant in &data.variants {
+                let variant_attr =
+                    parse_attrs::<ContainerAttribute>(&variant.attrs, &attr_names)?;
+                let is_skipped =
+                    attr::Skip::is_present(&variant.attrs, &eq_attr_names)?
+                        || matches!(variant_attr.as_deref(), Some(Either::Left(_)));
+                let fields_order = variant_attr.and_then(|attr| match attr.item {
+                    Either::Left(_) => None,
+                    Either::Right(order) => Some(Spanning::new(order, attr.span)),
+                });
+
+                variants.push(VariantComparison::parse(
+                    Some(&variant.ident),
+                    &variant.fields,
+                    is_skipped,
+                    fields_order,
+                    &attr_names,
+                    &eq_attr_names,
+                )?);
+            }
+
+            let ranked = if let Some(order) = order {
+                let idents = data.variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+                order.resolve(&idents, &HashSet::default(), "variant")?
+            } else {
+                (0..variants.len()).collect()
+            };
+            for (rank, n) in ranked.into_iter().enumerate() {
+                variants[n].rank = rank;
+            }
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                format!("`{trait_name}` cannot be derived for unions"),
+            ))
+        }
+    }
+
+    Ok(StructuralExpansion {
+        self_ty: (&input.ident, &input.generics),
+        variants,
+        is_enum: matches!(input.data, syn::Data::Enum(_)),
+        total,
+    }
+    .into_token_stream())
+}
+
+/// Parses the provided [`syn::Attribute`]s, considering all the provided attribute `names` as the
+/// same single attribute.
+fn parse_attrs<A: attr::ParseMultiple>(
+    attrs: &[syn::Attribute],
+    names: &[syn::Ident],
+) -> syn::Result<Option<Spanning<A>>> {
+    names.iter().try_fold(None, |merged, name| {
+        A::merge_opt_attrs(merged, A::parse_attrs(attrs, name)?, name)
+    })
+}
+
+/// Representation of a [`PartialOrd`]/[`Ord`] derive macro struct container or enum variant
+/// attribute.
+///
+/// ```rust,ignore
+/// #[ord(skip)]
+/// #[ord(ignore)]
+/// #[ord(order(<field>, <field>, ...))]
+/// ```
+type ContainerAttribute = Either<attr::Skip, OrderAttribute>;
+
+/// Representation of a [`PartialOrd`]/[`Ord`] derive macro field attribute.
+///
+/// ```rust,ignore
+/// #[ord(skip)]
+/// #[ord(ignore)]
+/// #[ord(reverse)]
+/// #[ord(by = <path>)]
+/// #[ord(reverse, by = <path>)]
+/// ```
+type FieldAttribute = Either<attr::Skip, ComparisonAttribute>;
+
+/// Representation of a [`PartialOrd`]/[`Ord`] derive macro attribute, specifying an explicit order
+/// of fields or variants to be compared in.
+///
+/// ```rust,ignore
+/// #[ord(order(<field>, <field>, ...))]
+/// #[ord(order(<variant>, <variant>, ...))]
+/// ```
+struct OrderAttribute(Punctuated<syn::Member, Token![,]>);
+
+impl Parse for OrderAttribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let path = input.parse::<syn::Path>()?;
+        if !path.is_ident("order") {
+            return Err(syn::Error::new(
+                path.span(),
+                "only `skip`/`ignore` or `order(...)` allowed here",
+            ));
+        }
+        let content;
+        _ = parenthesized!(content in input);
+        Ok(Self(
+            content.parse_terminated(syn::Member::parse, Token![,])?,
+        ))
+    }
+}
+
+impl attr::ParseMultiple for OrderAttribute {}
+
+impl Spanning<OrderAttribute> {
+    /// Resolves this [`OrderAttribute`] against the provided `idents` of fields or variants,
+    /// returning their indices in the order they should be compared.
+    ///
+    /// Fields or variants not mentioned in this [`OrderAttribute`] go after the mentioned ones, in
+    /// their declaration order.
+    fn resolve(
+        &self,
+        idents: &[impl ToString],
+        skipped: &HashSet<usize>,
+        kind: &str,
+    ) -> syn::Result<Vec<usize>> {
+        let mut ordered = Vec::with_capacity(idents.len());
+        for member in &self.item.0 {
+            let n = match member {
+                syn::Member::Named(ident) => {
+                    let name = ident.to_string();
+                    idents.iter().position(|i| i.to_string() == name)
+                }
+                syn::Member::Unnamed(index) => {
+                    let n = index.index as usize;
+                    (n < idents.len() && idents[n].to_string() == n.to_string())
+                        .then_some(n)
+                }
+            }
+            .ok_or_else(|| {
+                syn::Error::new(
+                    member.span(),
+                    format!("unknown {kind} `{}`", member.to_token_stream()),
+                )
+            })?;
+            if skipped.contains(&n) {
+                return Err(syn::Error::new(
+                    member.span(),
+                    format!(
+                        "{kind} `{}` is skipped, so cannot be ordered",
+                        member.to_token_stream(),
+                    ),
+                ));
+            }
+            if ordered.contains(&n) {
+                return Err(syn::Error::new(
+                    member.span(),
+                    format!(
+                        "{kind} `{}` is specified more than once",
+                        member.to_token_stream(),
+                    ),
+                ));
+            }
+            ordered.push(n);
+        }
+        for n in 0..idents.len() {
+            if !ordered.contains(&n) && !skipped.contains(&n) {
+                ordered.push(n);
+            }
+        }
+        Ok(ordered)
+    }
+}
+
+/// Representation of a [`PartialOrd`]/[`Ord`] derive macro field attribute, specifying how the
+/// field should be compared.
+///
+/// ```rust,ignore
+/// #[ord(reverse)]
+/// #[ord(by = <path>)]
+/// #[ord(reverse, by = <path>)]
+/// ```
+#[derive(Default)]
+struct ComparisonAttribute {
+    /// Indicator whether the field should be compared in the reversed order.
+    reverse: bool,
+
+    /// Path to a custom `fn(&T, &T) -> Ordering` function to compare the field with.
+    by: Option<syn::Path>,
+}
+
+impl Parse for ComparisonAttribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut attr = Self::default();
+        loop {
+            let path = input.parse::<syn::Path>()?;
+            if path.is_ident("reverse") {
+                if attr.reverse {
+                    return Err(syn::Error::new(
+                        path.span(),
+                        "`reverse` is specified more than once",
+                    ));
+                }
+                attr.reverse = true;
+            } else if path.is_ident("by") {
+                if attr.by.is_some() {
+                    return Err(syn::Error::new(
+                        path.span(),
+                        "`by` is specified more than once",
+                    ));
+                }
+                _ = input.parse::<Token![=]>()?;
+                attr.by = Some(input.parse()?);
+            } else {
+                return Err(syn::Error::new(
+                    path.span(),
+                    "only `skip`/`ignore`, `reverse` or `by = <path>` allowed here",
+                ));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            _ = input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+        }
+        Ok(attr)
+    }
+}
+
+impl attr::ParseMultiple for ComparisonAttribute {}
+
+/// [`syn::Field`] to be compared in a [`StructuralExpansion`].
+struct FieldComparison {
+    /// Index of the [`syn::Field`] in its [`syn::Fields`].
+    index: usize,
+
+    /// [`ComparisonAttribute`] of the [`syn::Field`], if any.
+    attr: ComparisonAttribute,
+}
+
+/// Comparison of an enum variant or a struct in a [`StructuralExpansion`].
+struct VariantComparison<'i> {
+    /// [`syn::Ident`] of the enum variant, if any.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    ident: Option<&'i syn::Ident>,
+
+    /// [`syn::Fields`] of the enum variant/struct.
+    fields: &'i syn::Fields,
+
+    /// Indices of the [`syn::Fields`] excluded from the comparison.
+    skipped_fields: HashSet<usize>,
+
+    /// [`syn::Fields`] to be compared, in their comparison order.
+    compared_fields: Vec<FieldComparison>,
+
+    /// Rank of the enum variant, defining its order relatively to other enum variants.
+    rank: usize,
+}
+
+impl<'i> VariantComparison<'i> {
+    /// Parses a [`VariantComparison`] out of the provided enum variant or struct parts.
+    fn parse(
+        ident: Option<&'i syn::Ident>,
+        fields: &'i syn::Fields,
+        is_skipped: bool,
+        order: Option<Spanning<OrderAttribute>>,
+        attr_names: &[syn::Ident],
+        eq_attr_names: &[syn::Ident],
+    ) -> syn::Result<Self> {
+        let mut skipped_fields = HashSet::default();
+        let mut attrs = vec![];
+        for (n, field) in fields.iter().enumerate() {
+            let attr = parse_attrs::<FieldAttribute>(&field.attrs, attr_names)?
+                .map(Spanning::into_inner);
+            if is_skipped
+                || matches!(attr, Some(Either::Left(_)))
+                || attr::Skip::is_present(&field.attrs, eq_attr_names)?
+            {
+                _ = skipped_fields.insert(n);
+                attrs.push(None);
+            } else {
+                attrs.push(attr.and_then(|attr| match attr {
+                    Either::Left(_) => None,
+                    Either::Right(cmp) => Some(cmp),
+                }));
+            }
+        }
+
+        let order = if let Some(order) = order {
+            let members = fields
+                .iter()
+                .enumerate()
+                .map(|(n, field)| {
+                    field
+                        .ident
+                        .as_ref()
+                        .map_or_else(|| n.to_string(), ToString::to_string)
+                })
+                .collect::<Vec<_>>();
+            order.resolve(&members, &skipped_fields, "field")?
+        } else {
+            (0..fields.len())
+                .filter(|n| !skipped_fields.contains(n))
+                .collect()
+        };
+
+        let compared_fields = order
+            .into_iter()
+            .map(|index| FieldComparison {
+                index,
+                attr: attrs[index].take().unwrap_or_default(),
+            })
+            .collect();
+
+        Ok(Self {
+            ident,
+            fields,
+            skipped_fields,
+            compared_fields,
+            rank: 0,
+        })
+    }
+}
+
+/// Expansion of a macro for generating a structural [`PartialOrd`] or [`Ord`] implementation of an
+/// enum or a struct.
+struct StructuralExpansion<'i> {
+    /// [`syn::Ident`] and [`syn::Generics`] of the enum/struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    self_ty: (&'i syn::Ident, &'i syn::Generics),
+
+    /// [`VariantComparison`]s of the enum/struct, in their declaration order.
+    variants: Vec<VariantComparison<'i>>,
+
+    /// Indicator whether this expansion is for an enum.
+    is_enum: bool,
+
+    /// Indicator whether this expansion is for an [`Ord`] rather than a [`PartialOrd`].
+    total: bool,
+}
+
+impl StructuralExpansion<'_> {
+    /// Generates the expression of the [`Ordering::Equal`] result of the implemented method.
+    ///
+    /// [`Ordering::Equal`]: std::cmp::Ordering::Equal
+    fn equal(&self) -> TokenStream {
+        if self.total {
+            quote! { derive_more::core::cmp::Ordering::Equal }
+        } else {
+            quote! {
+                derive_more::core::option::Option::Some(derive_more::core::cmp::Ordering::Equal)
+            }
+        }
+    }
+
+    /// Generates the expression comparing the provided [`FieldComparison`].
+    fn field_cmp(&self, field: &FieldComparison) -> TokenStream {
+        let mut lhs = format_ident!("__self_{}", field.index);
+        let mut rhs = format_ident!("__other_{}", field.index);
+        if field.attr.reverse {
+            (lhs, rhs) = (rhs, lhs);
+        }
+
+        match (&field.attr.by, self.total) {
+            (Some(by), true) => quote! { #by(#lhs, #rhs) },
+            (Some(by), false) => quote! {
+                derive_more::core::option::Option::Some(#by(#lhs, #rhs))
+            },
+            (None, true) => quote! { derive_more::core::cmp::Ord::cmp(#lhs, #rhs) },
+            (None, false) => quote! {
+                derive_more::core::cmp::PartialOrd::partial_cmp(#lhs, #rhs)
+            },
+        }
+    }
+
+    /// Generates the expression comparing all the fields of the provided [`VariantComparison`]
+    /// lexicographically, if there is any field to compare.
+    fn fields_cmp(&self, variant: &VariantComparison<'_>) -> Option<TokenStream> {
+        let equal = self.equal();
+        variant
+            .compared_fields
+            .iter()
+            .rev()
+            .fold(None, |next, field| {
+                let cmp = self.field_cmp(field);
+                Some(match next {
+                    None => cmp,
+                    Some(next) => quote! {
+                        match #cmp {
+                            #equal => #next,
+                            __cmp => __cmp,
+                        }
+                    },
+                })
+            })
+    }
+
+    /// Generates body of the [`PartialOrd::partial_cmp()`]/[`Ord::cmp()`] method implementation for
+    /// this [`StructuralExpansion`].
+    fn body(&self) -> TokenStream {
+        // Special case: empty enum.
+        if self.is_enum && self.variants.is_empty() {
+            return quote! { match *self {} };
+        }
+
+        let match_arms = self
+            .variants
+            .iter()
+            .filter_map(|variant| {
+                let fields_cmp = self.fields_cmp(variant)?;
+
+                let ident = variant.ident.map(|ident| quote! { :: #ident });
+                let self_pattern = variant
+                    .fields
+                    .non_exhaustive_arm_pattern("__self_", &variant.skipped_fields);
+                let other_pattern = variant
+                    .fields
+                    .non_exhaustive_arm_pattern("__other_", &variant.skipped_fields);
+
+                Some(quote! {
+                    (Self #ident #self_pattern, Self #ident #other_pattern) => #fields_cmp,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let ranks_arm = (self.variants.len() > 1).then(|| {
+            let rank_arms = self.variants.iter().map(|variant| {
+                let ident = variant.ident;
+                let rank = Literal::usize_unsuffixed(variant.rank);
+                quote! { Self::#ident { .. } => #rank, }
+            });
+            let mut cmp = quote! {
+                derive_more::core::cmp::Ord::cmp(&__rank(self), &__rank(__other))
+            };
+            if !self.total {
+                cmp = quote! { derive_more::core::option::Option::Some(#cmp) };
+            }
+
+            quote! {
+                _ => {
+                    let __rank = |__v: &Self| -> usize {
+                        match __v {
+                            #( #rank_arms )*
+                        }
+                    };
+                    #cmp
+                }
+            }
+        });
+
+        if match_arms.is_empty() && ranks_arm.is_none() {
+            return self.equal();
+        }
+
+        quote! {
+            match (self, __other) {
+                #( #match_arms )*
+                #ranks_arm
+            }
+        }
+    }
+}
+
+impl ToTokens for StructuralExpansion<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ty = self.self_ty.0;
+        let (_, ty_generics, _) = self.self_ty.1.split_for_impl();
+
+        let trait_ty = if self.total {
+            format_ident!("Ord")
+        } else {
+            format_ident!("PartialOrd")
+        };
+
+        let generics_search = GenericsSearch::from(self.self_ty.1);
+        let mut generics = self.self_ty.1.clone();
+        if !generics.params.is_empty() {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(if self.total {
+                    parse_quote! {
+                        Self: derive_more::core::cmp::Eq + derive_more::core::cmp::PartialOrd
+                    }
+                } else {
+                    parse_quote! { Self: derive_more::core::cmp::PartialEq }
+                });
+        }
+        {
+            let self_ty: syn::Type = parse_quote! { Self };
+            let implementor_ty: syn::Type = parse_quote! { #ty #ty_generics };
+            for variant in &self.variants {
+                // Fields compared by a custom function don't require the trait to be implemented
+                // at all.
+                for field_ty in variant
+                    .compared_fields
+                    .iter()
+                    .filter(|field| field.attr.by.is_none())
+                    .map(|field| &variant.fields.iter().nth(field.index).unwrap().ty)
+                {
+                    if generics_search.any_in(field_ty)
+                        && !field_ty.contains_type_structurally(&self_ty)
+                        && !field_ty.contains_type_structurally(&implementor_ty)
+                    {
+                        generics.make_where_clause().predicates.push(parse_quote! {
+                            #field_ty: derive_more::core::cmp::#trait_ty
+                        });
+                    }
+                }
+            }
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let body = self.body();
+        let method = if self.total {
+            quote! {
+                #[inline]
+                fn cmp(&self, __other: &Self) -> derive_more::core::cmp::Ordering {
+                    #body
+                }
+            }
+        } else {
+            quote! {
+                #[inline]
+                fn partial_cmp(
+                    &self,
+                    __other: &Self,
+                ) -> derive_more::core::option::Option<derive_more::core::cmp::Ordering> {
+                    #body
+                }
+            }
+        };
+
+        quote! {
+            #[allow(private_bounds)]
+            #[automatically_derived]
+            impl #impl_generics derive_more::core::cmp::#trait_ty for #ty #ty_generics
+                 #where_clause
+            {
+                #method
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
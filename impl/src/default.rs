@@ -0,0 +1,182 @@
+//! Implementation of a [`Default`] derive macro.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned as _,
+};
+
+use crate::utils::{
+    attr::{self, ParseMultiple as _},
+    structural_inclusion::TypeExt as _,
+    GenericsSearch, Spanning,
+};
+
+/// Expands a [`Default`] derive macro.
+pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStream> {
+    let attr_name = format_ident!("default");
+
+    if let Some(attr) = input.attrs.iter().find(|a| a.path().is_ident(&attr_name)) {
+        return Err(syn::Error::new(
+            attr.span(),
+            "`#[default]` attribute can be placed only on enum variants or fields",
+        ));
+    }
+
+    let (variant, fields) = match &input.data {
+        syn::Data::Struct(data) => (None, &data.fields),
+        syn::Data::Enum(data) => {
+            let mut default = None;
+            for variant in &data.variants {
+                if let Some(attr) =
+                    attr::Empty::parse_attrs(&variant.attrs, &attr_name)?
+                {
+                    if default.is_some() {
+                        return Err(syn::Error::new(
+                            attr.span,
+                            "only single enum variant can be marked with `#[default]` attribute",
+                        ));
+                    }
+                    default = Some(variant);
+                }
+            }
+            let variant = default.ok_or_else(|| {
+                syn::Error::new(
+                    data.enum_token.span(),
+                    "`#[default]` attribute is required on an enum variant to derive `Default`",
+                )
+            })?;
+            (Some(&variant.ident), &variant.fields)
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "`Default` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let fields = fields
+        .iter()
+        .map(|field| {
+            Ok((
+                field,
+                FieldAttribute::parse_attrs(&field.attrs, &attr_name)?
+                    .map(Spanning::into_inner),
+            ))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(Expansion {
+        self_ty: (&input.ident, &input.generics),
+        variant,
+        fields,
+    }
+    .into_token_stream())
+}
+
+/// Representation of a [`Default`] derive macro field attribute, specifying the default value
+/// expression of the field.
+///
+/// ```rust,ignore
+/// #[default(<expr>)]
+/// ```
+struct FieldAttribute(syn::Expr);
+
+impl Parse for FieldAttribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        input.parse().map(Self)
+    }
+}
+
+impl attr::ParseMultiple for FieldAttribute {
+    fn parse_attr_with<P: attr::Parser>(
+        attr: &syn::Attribute,
+        parser: &P,
+    ) -> syn::Result<Self> {
+        if matches!(attr.meta, syn::Meta::List(_)) {
+            attr.parse_args_with(|ps: ParseStream<'_>| parser.parse(ps))
+        } else {
+            Err(syn::Error::new(
+                attr.span(),
+                "only `#[default(<expr>)]` attribute is allowed here",
+            ))
+        }
+    }
+}
+
+/// Expansion of a macro for generating a [`Default`] implementation of an enum or a struct.
+struct Expansion<'i> {
+    /// [`syn::Ident`] and [`syn::Generics`] of the enum/struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    self_ty: (&'i syn::Ident, &'i syn::Generics),
+
+    /// [`syn::Ident`] of the enum variant marked with `#[default]`, or [`None`] for a struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    variant: Option<&'i syn::Ident>,
+
+    /// [`syn::Field`]s to be initialized, along with their explicitly specified default value
+    /// expressions (if any).
+    fields: Vec<(&'i syn::Field, Option<FieldAttribute>)>,
+}
+
+impl ToTokens for Expansion<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ty = self.self_ty.0;
+        let (_, ty_generics, _) = self.self_ty.1.split_for_impl();
+
+        let generics_search = GenericsSearch::from(self.self_ty.1);
+        let mut generics = self.self_ty.1.clone();
+        {
+            let self_ty: syn::Type = parse_quote! { Self };
+            let implementor_ty: syn::Type = parse_quote! { #ty #ty_generics };
+            for field_ty in self
+                .fields
+                .iter()
+                .filter(|(_, expr)| expr.is_none())
+                .map(|(field, _)| &field.ty)
+            {
+                if generics_search.any_in(field_ty)
+                    && !field_ty.contains_type_structurally(&self_ty)
+                    && !field_ty.contains_type_structurally(&implementor_ty)
+                {
+                    generics.make_where_clause().predicates.push(parse_quote! {
+                        #field_ty: derive_more::core::default::Default
+                    });
+                }
+            }
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let variant = self.variant.map(|variant| quote! { :: #variant });
+        let fields = self.fields.iter().enumerate().map(|(n, (field, expr))| {
+            let member = field
+                .ident
+                .clone()
+                .map_or_else(|| syn::Member::Unnamed(n.into()), syn::Member::Named);
+            let expr = expr.as_ref().map_or_else(
+                || quote! { derive_more::core::default::Default::default() },
+                |FieldAttribute(expr)| expr.to_token_stream(),
+            );
+            quote! { #member: #expr }
+        });
+
+        quote! {
+            #[allow(private_bounds)]
+            #[automatically_derived]
+            impl #impl_generics derive_more::core::default::Default for #ty #ty_generics
+                 #where_clause
+            {
+                #[inline]
+                fn default() -> Self {
+                    Self #variant { #( #fields ),* }
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
@@ -0,0 +1,229 @@
+//! Implementation of a [`Discriminant`] derive macro.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::utils::{
+    attr::{self, ParseMultiple as _},
+    Spanning,
+};
+
+/// Expands a [`Discriminant`] derive macro.
+pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStream> {
+    let attr_name = format_ident!("discriminant");
+
+    let data = match &input.data {
+        syn::Data::Enum(data) => data,
+        syn::Data::Struct(data) => {
+            return Err(syn::Error::new(
+                data.struct_token.span(),
+                "`Discriminant` can be derived only for enums",
+            ))
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "`Discriminant` can be derived only for enums",
+            ))
+        }
+    };
+    if let Some(attr) = data
+        .variants
+        .iter()
+        .flat_map(|v| &v.attrs)
+        .find(|a| a.path().is_ident(&attr_name))
+    {
+        return Err(syn::Error::new(
+            attr.span(),
+            "`#[discriminant(...)]` attribute cannot be placed on enum variants",
+        ));
+    }
+
+    let ContainerAttributes { name, derives } =
+        ContainerAttributes::parse_attrs(&input.attrs, &attr_name)?
+            .map(Spanning::into_inner)
+            .unwrap_or_default();
+    // These are always derived on the kind enum, so mentioning them explicitly would lead to
+    // conflicting implementations.
+    if let Some((path, trait_name)) = derives.iter().find_map(|path| {
+        let ident = &path.segments.last()?.ident;
+        ["Clone", "Copy", "Eq", "PartialEq"]
+            .into_iter()
+            .find(|t| ident == t)
+            .map(|t| (path, t))
+    }) {
+        return Err(syn::Error::new(
+            path.span(),
+            format!(
+                "`{trait_name}` is always derived for the generated enum, so cannot be \
+                 specified in `#[{attr_name}(derive(...))]` attribute",
+            ),
+        ));
+    }
+
+    let ty = &input.ident;
+    let vis = &input.vis;
+    let kind_ty = name.unwrap_or_else(|| format_ident!("{ty}Kind"));
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let variants = data.variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+    let kind_doc = format!("Kind of a `{ty}` value, representing its variant only.");
+    let variants_docs = variants
+        .iter()
+        .map(|v| format!("Kind of a `{ty}::{v}` variant."));
+
+    let (kind_body, is_kind_body) = if variants.is_empty() {
+        (quote! { match *self {} }, quote! { match *self {} })
+    } else {
+        (
+            quote! {
+                match self {
+                    #( Self::#variants { .. } => #kind_ty::#variants, )*
+                }
+            },
+            quote! {
+                derive_more::core::matches!(
+                    (self, kind),
+                    #( (Self::#variants { .. }, #kind_ty::#variants) )|*
+                )
+            },
+        )
+    };
+
+    Ok(quote! {
+        #[doc = #kind_doc]
+        #[derive(
+            derive_more::core::clone::Clone,
+            derive_more::core::marker::Copy,
+            derive_more::core::cmp::Eq,
+            derive_more::core::cmp::PartialEq,
+            #( #derives ),*
+        )]
+        #vis enum #kind_ty {
+            #(
+                #[doc = #variants_docs]
+                #variants,
+            )*
+        }
+
+        #[allow(deprecated)] // omit warnings on deprecated variants
+        #[automatically_derived]
+        impl #impl_generics #ty #ty_generics #where_clause {
+            /// Returns the kind of this value, representing its variant only.
+            #[inline]
+            #[must_use]
+            #vis const fn kind(&self) -> #kind_ty {
+                #kind_body
+            }
+
+            /// Checks whether this value is of the provided `kind`.
+            #[inline]
+            #[must_use]
+            #vis const fn is_kind(&self, kind: #kind_ty) -> bool {
+                #is_kind_body
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics derive_more::core::convert::From<&#ty #ty_generics> for #kind_ty
+             #where_clause
+        {
+            #[inline]
+            fn from(value: &#ty #ty_generics) -> Self {
+                value.kind()
+            }
+        }
+    })
+}
+
+/// Representation of a [`Discriminant`] derive macro enum attribute.
+///
+/// ```rust,ignore
+/// #[discriminant(name = <ident>)]
+/// #[discriminant(derive(<path>, ...))]
+/// ```
+///
+/// Multiple `#[discriminant(derive(...))]` arguments are merged together, while the `name` one can
+/// be specified only once.
+#[derive(Default)]
+struct ContainerAttributes {
+    /// Name of the generated kind enum, if overridden.
+    name: Option<syn::Ident>,
+
+    /// Paths of the derive macros to be placed on the generated kind enum.
+    derives: Vec<syn::Path>,
+}
+
+impl Parse for ContainerAttributes {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        mod ident {
+            use syn::custom_keyword;
+
+            custom_keyword!(derive);
+            custom_keyword!(name);
+        }
+
+        let mut attrs = Self::default();
+        loop {
+            let ahead = input.lookahead1();
+            if ahead.peek(ident::name) {
+                let span = input.parse::<ident::name>()?.span;
+                _ = input.parse::<token::Eq>()?;
+                if attrs.name.replace(input.parse()?).is_some() {
+                    return Err(syn::Error::new(
+                        span,
+                        "only single `name` argument is allowed",
+                    ));
+                }
+            } else if ahead.peek(ident::derive) {
+                _ = input.parse::<ident::derive>()?;
+                let content;
+                syn::parenthesized!(content in input);
+                attrs.derives.extend(
+                    Punctuated::<syn::Path, token::Comma>::parse_terminated(&content)?,
+                );
+            } else {
+                return Err(ahead.error());
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            _ = input.parse::<token::Comma>()?;
+            if input.is_empty() {
+                break;
+            }
+        }
+        Ok(attrs)
+    }
+}
+
+impl attr::ParseMultiple for ContainerAttributes {
+    fn merge_attrs(
+        prev: Spanning<Self>,
+        new: Spanning<Self>,
+        name: &syn::Ident,
+    ) -> syn::Result<Spanning<Self>> {
+        let span = prev.span.join(new.span).unwrap_or(prev.span);
+        let new_span = new.span;
+        let mut prev = prev.into_inner();
+        let new = new.into_inner();
+
+        if prev.name.is_some() && new.name.is_some() {
+            return Err(syn::Error::new(
+                new_span,
+                format!("multiple `#[{name}(name = ...)]` arguments aren't allowed"),
+            ));
+        }
+        prev.name = prev.name.or(new.name);
+        prev.derives.extend(new.derives);
+
+        Ok(Spanning::new(prev, span))
+    }
+}
@@ -88,15 +88,20 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenSt
 /// #[<attribute>("<fmt-literal>", <fmt-args>)]
 /// #[<attribute>(bound(<where-predicates>))]
 /// #[<attribute>(rename_all = "<casing>")]
+/// #[<attribute>(no_pad)]
 /// ```
 ///
 /// `#[<attribute>("...")]` and `#[<attribute>(rename_all = "...")]` can be specified only once,
 /// while multiple `#[<attribute>(bound(...))]` are allowed.
 #[derive(Debug, Default)]
-struct ContainerAttributes {
+pub(crate) struct ContainerAttributes {
     /// [`attr::RenameAll`] for case conversion.
     rename_all: Option<attr::RenameAll>,
 
+    /// Indicator whether literal-only outputs should be written as is, ignoring the width, fill,
+    /// alignment and precision of a [`fmt::Formatter`].
+    no_pad: bool,
+
     /// Common [`ContainerAttributes`].
     ///
     /// [`ContainerAttributes`]: super::ContainerAttributes
@@ -111,6 +116,7 @@ impl Parse for ContainerAttributes {
             custom_keyword!(bounds);
             custom_keyword!(bound);
             custom_keyword!(rename_all);
+            custom_keyword!(no_pad);
         }
 
         // We do check `FmtAttribute::check_legacy_fmt` eagerly here, because `.lookahead1()` won't
@@ -134,6 +140,12 @@ impl Parse for ContainerAttributes {
                 rename_all: Some(input.parse()?),
                 ..Self::default()
             })
+        } else if ahead.peek(ident::no_pad) {
+            let _ = input.parse::<ident::no_pad>()?;
+            Ok(Self {
+                no_pad: true,
+                ..Self::default()
+            })
         } else {
             Err(ahead.error())
         }
@@ -165,6 +177,7 @@ impl attr::ParseMultiple for ContainerAttributes {
                 format!("multiple `#[{name}(rename_all=\"...\")]` attributes aren't allowed"),
             ));
         }
+        prev.no_pad |= new.no_pad;
         prev.common = super::ContainerAttributes::merge_attrs(
             Spanning::new(prev.common, prev_span),
             Spanning::new(new.common, new_span),
@@ -179,6 +192,23 @@ impl attr::ParseMultiple for ContainerAttributes {
     }
 }
 
+#[cfg(feature = "into_static_str")]
+impl ContainerAttributes {
+    /// Returns the [`attr::RenameAll`] of these [`ContainerAttributes`], if any.
+    pub(crate) fn rename_all(&self) -> Option<attr::RenameAll> {
+        self.rename_all
+    }
+
+    /// Returns the format string literal of these [`ContainerAttributes`] (if any), along with the
+    /// string it's always formatted into, if it consists of a literal only.
+    pub(crate) fn fmt_literal(&self) -> Option<(&LitStr, Option<String>)> {
+        self.common
+            .fmt
+            .as_ref()
+            .map(|fmt| (&fmt.lit, fmt.literal_only()))
+    }
+}
+
 /// Type alias for an expansion context:
 /// - [`ContainerAttributes`].
 /// - Type parameters. Slice of [`syn::Ident`].
@@ -286,6 +316,7 @@ fn expand_enum(
             if let Some(rename_all) = container_attrs.rename_all {
                 attrs.rename_all.get_or_insert(rename_all);
             }
+            attrs.no_pad |= container_attrs.no_pad;
 
             let v = Expansion {
                 shared_attr: container_attrs.common.fmt.as_ref(),
@@ -339,10 +370,13 @@ fn expand_union(
         )
     })?;
 
-    Ok((
-        attrs.common.bounds.0.clone().into_iter().collect(),
-        quote! { derive_more::core::write!(__derive_more_f, #fmt) },
-    ))
+    let body = if let Some(lit) = fmt.literal_only().filter(|_| !attrs.no_pad) {
+        quote! { __derive_more_f.pad(#lit) }
+    } else {
+        quote! { derive_more::core::write!(__derive_more_f, #fmt) }
+    };
+
+    Ok((attrs.common.bounds.0.clone().into_iter().collect(), body))
 }
 
 /// Helper struct to generate [`Display::fmt()`] implementation body and trait
@@ -417,6 +451,10 @@ impl Expansion<'_> {
                     fmt.transparent_call_on_fields(self.fields)
                 {
                     quote! { derive_more::core::fmt::#trait_ident::fmt(#expr, __derive_more_f) }
+                } else if let Some(lit) =
+                    fmt.literal_only().filter(|_| !self.attrs.no_pad)
+                {
+                    quote! { __derive_more_f.pad(#lit) }
                 } else {
                     let deref_args = fmt.additional_deref_args(self.fields);
 
@@ -434,8 +472,10 @@ impl Expansion<'_> {
 
                         if shared_attr_is_wrapping {
                             quote! { #ident_str }
-                        } else {
+                        } else if self.attrs.no_pad {
                             quote! { __derive_more_f.write_str(#ident_str) }
+                        } else {
+                            quote! { __derive_more_f.pad(#ident_str) }
                         }
                     } else if self.fields.len() == 1 {
                         let field = self
@@ -479,6 +519,10 @@ impl Expansion<'_> {
                     shared_fmt.transparent_call_on_fields(self.fields)
                 {
                     quote! { derive_more::core::fmt::#trait_ident::fmt(#expr, __derive_more_f) }
+                } else if let Some(lit) =
+                    shared_fmt.literal_only().filter(|_| !self.attrs.no_pad)
+                {
+                    quote! { __derive_more_f.pad(#lit) }
                 } else {
                     quote! {
                         derive_more::core::write!(__derive_more_f, #shared_fmt, #(#deref_args),*)
@@ -266,6 +266,28 @@ impl FmtAttribute {
         })
     }
 
+    #[cfg(feature = "display")]
+    /// Returns the string this [`FmtAttribute`] is always formatted into, if it consists of a
+    /// literal only (contains no placeholders and no arguments), otherwise [`None`].
+    fn literal_only(&self) -> Option<String> {
+        if !self.args.is_empty() {
+            return None;
+        }
+
+        let lit = self.lit.value();
+        let mut out = String::with_capacity(lit.len());
+        let mut chars = lit.chars();
+        while let Some(c) = chars.next() {
+            // Any non-escaped brace means either a placeholder or an invalid format string, which
+            // should be reported by the compiler.
+            if matches!(c, '{' | '}') && chars.next() != Some(c) {
+                return None;
+            }
+            out.push(c);
+        }
+        Some(out)
+    }
+
     #[cfg(feature = "display")]
     /// Checks whether this [`FmtAttribute`] contains an argument with the provided `name` (either
     /// in its direct [`FmtArgument`]s or inside [`Placeholder`]s).
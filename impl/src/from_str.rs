@@ -4,9 +4,9 @@
 use std::str::FromStr;
 use std::{collections::HashMap, iter};
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
-use syn::{parse::Parse, parse_quote, spanned::Spanned as _};
+use syn::{ext::IdentExt as _, parse::Parse, parse_quote, spanned::Spanned as _};
 
 use crate::utils::{
     attr::{self, ParseMultiple as _},
@@ -16,12 +16,41 @@ use crate::utils::{
 /// Expands a [`FromStr`] derive macro.
 pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStream> {
     match &input.data {
-        syn::Data::Struct(data) => Ok(if data.fields.is_empty() {
-            FlatExpansion::try_from(input)?.into_token_stream()
-        } else {
-            ForwardExpansion::try_from(input)?.into_token_stream()
-        }),
-        syn::Data::Enum(_) => Ok(FlatExpansion::try_from(input)?.into_token_stream()),
+        syn::Data::Struct(data) => Ok(
+            if input.attrs.iter().any(is_format_attr)
+                || input.attrs.iter().any(is_display_attr)
+            {
+                FormatExpansion::try_from(input)?.into_token_stream()
+            } else if data.fields.is_empty() {
+                FlatExpansion::try_from(input)?.into_token_stream()
+            } else {
+                ForwardExpansion::try_from(input)?.into_token_stream()
+            },
+        ),
+        syn::Data::Enum(data) => {
+            if let Some(attr) = input.attrs.iter().find(|a| is_format_attr(a)) {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "`#[from_str(\"...\")]` attribute cannot be placed on enums, only on their \
+                     variants",
+                ));
+            }
+            // `#[display("...")]` literals are reused for parsing only when opted in explicitly.
+            let is_display = input.attrs.iter().any(is_display_attr);
+            Ok(
+                if is_display && display_literal(&input.attrs).is_some()
+                    || data.variants.iter().any(|v| {
+                        !v.fields.is_empty()
+                            || v.attrs.iter().any(is_format_attr)
+                            || is_display && display_literal(&v.attrs).is_some()
+                    })
+                {
+                    FormatExpansion::try_from(input)?.into_token_stream()
+                } else {
+                    FlatExpansion::try_from(input)?.into_token_stream()
+                },
+            )
+        }
         syn::Data::Union(data) => Err(syn::Error::new(
             data.union_token.span(),
             "`FromStr` cannot be derived for unions",
@@ -139,13 +168,8 @@ struct FlatExpansion<'i> {
         Option<attr::RenameAll>,
     )>,
 
-    /// [`FlatExpansion::matches`] grouped by its similar representation for detecting whether their
-    /// case-insensitivity should be disabled.
-    similar_matches: HashMap<String, Vec<&'i syn::Ident>>,
-
-    /// Optional [`attr::RenameAll`] indicating the case conversion to be applied to all the matched
-    /// values (enum variants or struct itself).
-    rename_all: Option<attr::RenameAll>,
+    /// [`NameMatching`] of the [`FlatExpansion::matches`].
+    names: NameMatching<'i>,
 
     /// Optional [`attr::Error`] enabling conversion into a custom error type.
     custom_error: Option<attr::Error>,
@@ -191,68 +215,26 @@ impl<'i> TryFrom<&'i syn::DeriveInput> for FlatExpansion<'i> {
             }
         };
 
-        let FlatContainerAttributes {
+        let ContainerAttributes {
             rename_all,
             error: custom_error,
-        } = FlatContainerAttributes::parse_attrs(&input.attrs, attr_ident)?
+            ..
+        } = ContainerAttributes::parse_attrs(&input.attrs, attr_ident)?
             .map(Spanning::into_inner)
             .unwrap_or_default();
 
-        let mut similar_matches = <HashMap<_, Vec<_>>>::new();
-        if rename_all.is_none() {
-            for (ident, _, renaming) in &matches {
-                let name = ident.to_string();
-                let lowercased = name.to_lowercase();
-                if let Some(rename) = renaming {
-                    let renamed_lowercased = rename.convert_case(&name);
-                    if renamed_lowercased != lowercased {
-                        similar_matches
-                            .entry(renamed_lowercased)
-                            .or_default()
-                            .push(*ident);
-                    }
-                }
-                similar_matches.entry(lowercased).or_default().push(*ident);
-            }
-        }
-
-        let mut exact_matches = <HashMap<String, Vec<String>>>::new();
-        for (ident, _, renaming) in &matches {
-            let name = ident.to_string();
-            let exact = if let Some(default_renaming) = &rename_all {
-                renaming
-                    .as_ref()
-                    .unwrap_or(default_renaming)
-                    .convert_case(&name)
-            } else if let Some(renaming) = renaming {
-                renaming.convert_case(&name)
-            } else {
-                let lowercased = name.to_lowercase();
-                if similar_matches[&lowercased].len() > 1 {
-                    name.clone()
-                } else {
-                    lowercased
-                }
-            };
-            exact_matches.entry(exact).or_default().push(name);
-        }
-        if let Some((string, variants)) =
-            exact_matches.into_iter().find(|(_, vs)| vs.len() > 1)
-        {
-            return Err(syn::Error::new(
-                input.ident.span(),
-                format!(
-                    "`{}` variants cannot have the same \"{string}\" string representation",
-                    variants.join("`, `"),
-                ),
-            ));
-        }
+        let names = NameMatching::new(
+            &input.ident,
+            matches
+                .iter()
+                .map(|(ident, _, renaming)| (*ident, *renaming)),
+            rename_all,
+        )?;
 
         Ok(Self {
             self_ty: (&input.ident, &input.generics),
             matches,
-            similar_matches,
-            rename_all,
+            names,
             custom_error,
         })
     }
@@ -267,10 +249,11 @@ impl ToTokens for FlatExpansion<'_> {
         let ty_name = ty.to_string();
 
         let scrutinee_lowercased = self
+            .names
             .rename_all
             .is_none()
             .then(|| quote! { .to_lowercase().as_str() });
-        let match_arms = if let Some(default_renaming) = self.rename_all {
+        let match_arms = if let Some(default_renaming) = self.names.rename_all {
             self.matches
                 .iter()
                 .map(|(ident, value, renaming)| {
@@ -294,7 +277,7 @@ impl ToTokens for FlatExpansion<'_> {
                         quote! { _ if s == #exact_name => #constructor, }
                     } else {
                         let lowercased = name.to_lowercase();
-                        let exact_guard = (self.similar_matches[&lowercased].len() > 1)
+                        let exact_guard = (self.names.similar[&lowercased].len() > 1)
                             .then(|| quote! { if s == #name });
 
                         quote! { #lowercased #exact_guard => #constructor, }
@@ -333,6 +316,91 @@ impl ToTokens for FlatExpansion<'_> {
     }
 }
 
+/// Rule of matching values (enum variants or struct itself) by their names, shared by a
+/// [`FlatExpansion`] and unit enum variants of a [`FormatExpansion`].
+struct NameMatching<'i> {
+    /// Matched values grouped by their similar representation for detecting whether their
+    /// case-insensitivity should be disabled.
+    similar: HashMap<String, Vec<&'i syn::Ident>>,
+
+    /// Optional [`attr::RenameAll`] indicating the case conversion to be applied to all the matched
+    /// values.
+    rename_all: Option<attr::RenameAll>,
+}
+
+impl<'i> NameMatching<'i> {
+    /// Creates a new [`NameMatching`] of the provided values along with their value-specific
+    /// [`attr::RenameAll`]s, checking that their string representations are unique.
+    fn new(
+        ty: &syn::Ident,
+        values: impl IntoIterator<Item = (&'i syn::Ident, Option<attr::RenameAll>)>,
+        rename_all: Option<attr::RenameAll>,
+    ) -> syn::Result<Self> {
+        let values = values.into_iter().collect::<Vec<_>>();
+
+        let mut similar = <HashMap<_, Vec<_>>>::new();
+        if rename_all.is_none() {
+            for (ident, renaming) in &values {
+                let name = ident.to_string();
+                let lowercased = name.to_lowercase();
+                if let Some(rename) = renaming {
+                    let renamed_lowercased = rename.convert_case(&name);
+                    if renamed_lowercased != lowercased {
+                        similar.entry(renamed_lowercased).or_default().push(*ident);
+                    }
+                }
+                similar.entry(lowercased).or_default().push(*ident);
+            }
+        }
+        let this = Self {
+            similar,
+            rename_all,
+        };
+
+        let mut exact_matches = <HashMap<String, Vec<String>>>::new();
+        for (ident, renaming) in values {
+            let (exact, _) = this.repr(ident, renaming);
+            exact_matches
+                .entry(exact)
+                .or_default()
+                .push(ident.to_string());
+        }
+        if let Some((string, variants)) =
+            exact_matches.into_iter().find(|(_, vs)| vs.len() > 1)
+        {
+            return Err(syn::Error::new(
+                ty.span(),
+                format!(
+                    "`{}` variants cannot have the same \"{string}\" string representation",
+                    variants.join("`, `"),
+                ),
+            ));
+        }
+
+        Ok(this)
+    }
+
+    /// Returns the string representation of the provided value, along with the indicator whether
+    /// it should be matched case-insensitively (being lowercased then).
+    fn repr(
+        &self,
+        ident: &syn::Ident,
+        renaming: Option<attr::RenameAll>,
+    ) -> (String, bool) {
+        let name = ident.to_string();
+        if let Some(renaming) = renaming.or(self.rename_all) {
+            (renaming.convert_case(&name), false)
+        } else {
+            let lowercased = name.to_lowercase();
+            if self.similar[&lowercased].len() > 1 {
+                (name, false)
+            } else {
+                (lowercased, true)
+            }
+        }
+    }
+}
+
 /// Extension of [`syn::Fields`] used by this expansion.
 trait FieldsExt {
     /// Generates a `name`d constructor with the provided `values` assigned to these
@@ -465,19 +533,579 @@ impl<L: FieldsExt, R: FieldsExt> FieldsExt for Either<&L, &R> {
     }
 }
 
-/// Representation of possible [`FromStr`] derive macro attributes placed on an enum or a struct for
-/// a [`FlatExpansion`].
+/// Expansion of a macro for generating a [`FromStr`] implementation of an enum or a struct, parsing
+/// its fields according to a format string.
+struct FormatExpansion<'i> {
+    /// [`syn::Ident`] and [`syn::Generics`] of the enum/struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    self_ty: (&'i syn::Ident, &'i syn::Generics),
+
+    /// [`FormatValue`]s (enum variants or struct itself) to be tried in order.
+    values: Vec<FormatValue<'i>>,
+
+    /// Optional [`attr::Error`] enabling conversion into a custom error type.
+    custom_error: Option<attr::Error>,
+}
+
+impl<'i> TryFrom<&'i syn::DeriveInput> for FormatExpansion<'i> {
+    type Error = syn::Error;
+
+    fn try_from(input: &'i syn::DeriveInput) -> syn::Result<Self> {
+        let attr_ident = &format_ident!("from_str");
+
+        let ContainerAttributes {
+            fmt,
+            display,
+            rename_all,
+            error: custom_error,
+        } = ContainerAttributes::parse_attrs(&input.attrs, attr_ident)?
+            .map(Spanning::into_inner)
+            .unwrap_or_default();
+
+        let values = match &input.data {
+            syn::Data::Struct(data) => {
+                if rename_all.is_some() {
+                    return Err(syn::Error::new(
+                        input.ident.span(),
+                        "`#[from_str(rename_all = \"...\")]` attribute cannot be combined with \
+                         a format string",
+                    ));
+                }
+                let fmt = match (fmt, display) {
+                    (Some(fmt), _) => fmt,
+                    (None, Some(span)) => {
+                        display_literal(&input.attrs).ok_or_else(|| {
+                            syn::Error::new(
+                                span,
+                                "`#[from_str(display)]` attribute requires a \
+                                 `#[display(\"...\")]` attribute with a single format string \
+                                 literal",
+                            )
+                        })?
+                    }
+                    (None, None) => {
+                        return Err(syn::Error::new(
+                            input.ident.span(),
+                            "`#[from_str(\"...\")]` attribute is required",
+                        ))
+                    }
+                };
+                vec![FormatValue::parse(None, &data.fields, &fmt)?]
+            }
+            syn::Data::Enum(data) => {
+                let container_display =
+                    display.and_then(|_| display_literal(&input.attrs));
+                let variants = data
+                    .variants
+                    .iter()
+                    .map(|variant| {
+                        let VariantAttributes {
+                            fmt,
+                            rename_all: renaming,
+                        } = VariantAttributes::parse_attrs(&variant.attrs, attr_ident)?
+                            .map(Spanning::into_inner)
+                            .unwrap_or_default();
+                        let fmt = fmt.or_else(|| {
+                            display.and_then(|_| {
+                                variant_display_literal(container_display.as_ref(), variant)
+                            })
+                        });
+                        if fmt.is_some() && renaming.is_some() {
+                            return Err(syn::Error::new(
+                                variant.ident.span(),
+                                "`#[from_str(rename_all = \"...\")]` attribute cannot be \
+                                 combined with a format string",
+                            ));
+                        }
+                        Ok((variant, fmt, renaming))
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+
+                // Unit variants without any format string are matched by their names in the
+                // same way as in a `FlatExpansion`.
+                let names = NameMatching::new(
+                    &input.ident,
+                    variants
+                        .iter()
+                        .filter(|(v, fmt, _)| fmt.is_none() && v.fields.is_empty())
+                        .map(|(v, _, renaming)| (&v.ident, *renaming)),
+                    rename_all,
+                )?;
+
+                variants
+                    .into_iter()
+                    .map(|(variant, fmt, renaming)| {
+                        if let Some(fmt) = fmt {
+                            FormatValue::parse(
+                                Some(&variant.ident),
+                                &variant.fields,
+                                &fmt,
+                            )
+                        } else if variant.fields.is_empty() {
+                            let (repr, case_insensitive) =
+                                names.repr(&variant.ident, renaming);
+                            Ok(FormatValue::exact(
+                                Some(&variant.ident),
+                                &variant.fields,
+                                repr,
+                                case_insensitive,
+                            ))
+                        } else {
+                            Err(syn::Error::new(
+                                variant.fields.span(),
+                                "`#[from_str(\"...\")]` attribute is required for enum \
+                                 variants with fields",
+                            ))
+                        }
+                    })
+                    .collect::<syn::Result<_>>()?
+            }
+            syn::Data::Union(_) => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "expected an enum or a struct for format `FromStr` derive",
+                ))
+            }
+        };
+
+        Ok(Self {
+            self_ty: (&input.ident, &input.generics),
+            values,
+            custom_error,
+        })
+    }
+}
+
+impl ToTokens for FormatExpansion<'_> {
+    /// Expands a format [`FromStr`] implementations for an enum or a struct.
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ty = self.self_ty.0;
+        let ty_name = ty.to_string();
+
+        let generics_search = GenericsSearch::from(self.self_ty.1);
+        let mut generics = self.self_ty.1.clone();
+        for field_ty in self
+            .values
+            .iter()
+            .flat_map(|v| v.fields.iter().map(|f| &f.ty))
+        {
+            if generics_search.any_in(field_ty) {
+                generics.make_where_clause().predicates.push(parse_quote! {
+                    #field_ty: derive_more::core::str::FromStr
+                });
+            }
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let values = self.values.iter().map(|v| v.parsing(&ty_name));
+        let mutability = self
+            .values
+            .iter()
+            .any(|v| !v.placeholders.is_empty())
+            .then(|| quote! { mut });
+
+        let default_error = quote! { __error };
+        let mut error_ty = quote! { derive_more::FromStrError };
+        let mut error_val = default_error.clone();
+        if let Some(custom_error) = &self.custom_error {
+            error_ty = custom_error.ty.to_token_stream();
+            error_val = custom_error.conv.as_ref().map_or_else(
+                || quote! { derive_more::core::convert::Into::into(#default_error) },
+                |conv| quote! { (#conv)(#default_error) },
+            );
+        }
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics derive_more::core::str::FromStr for #ty #ty_generics #where_clause {
+                type Err = #error_ty;
+
+                fn from_str(s: &str) -> derive_more::core::result::Result<
+                    Self, <Self as derive_more::core::str::FromStr>::Err,
+                > {
+                    let #mutability __error =
+                        derive_more::core::option::Option::<derive_more::FromStrError>::None;
+                    #( #values )*
+                    let __error = __error.unwrap_or(derive_more::FromStrError::new(#ty_name));
+                    derive_more::core::result::Result::Err(#error_val)
+                }
+            }
+        }.to_tokens(tokens);
+    }
+}
+
+/// Enum variant or struct parsed according to a format string in a [`FormatExpansion`].
+struct FormatValue<'i> {
+    /// [`syn::Ident`] of the enum variant, or [`None`] for a struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    variant: Option<&'i syn::Ident>,
+
+    /// [`syn::Fields`] of the enum variant/struct.
+    fields: &'i syn::Fields,
+
+    /// Literal segments of the format string surrounding its placeholders.
+    ///
+    /// Always contains exactly one element more than [`FormatValue::placeholders`].
+    literals: Vec<String>,
+
+    /// Indices of the [`FormatValue::fields`] in the order of their placeholders in the format
+    /// string.
+    placeholders: Vec<usize>,
+
+    /// Indicator whether the input string should be matched case-insensitively (against the
+    /// lowercased single literal).
+    case_insensitive: bool,
+}
+
+impl<'i> FormatValue<'i> {
+    /// Creates a new [`FormatValue`] matching the provided string representation, either exactly
+    /// or case-insensitively.
+    fn exact(
+        variant: Option<&'i syn::Ident>,
+        fields: &'i syn::Fields,
+        repr: String,
+        case_insensitive: bool,
+    ) -> Self {
+        Self {
+            variant,
+            fields,
+            literals: vec![repr],
+            placeholders: vec![],
+            case_insensitive,
+        }
+    }
+
+    /// Parses a [`FormatValue`] from the provided format string literal.
+    ///
+    /// Every of the [`syn::Fields`] must be referred exactly once in the format string, either by
+    /// its name, or by its `_<index>` for unnamed ones. Any two placeholders must be separated by
+    /// a non-empty literal.
+    fn parse(
+        variant: Option<&'i syn::Ident>,
+        fields: &'i syn::Fields,
+        fmt: &syn::LitStr,
+    ) -> syn::Result<Self> {
+        let error = |msg: String| syn::Error::new(fmt.span(), msg);
+
+        let names = fields
+            .iter()
+            .enumerate()
+            .map(|(n, f)| {
+                f.ident
+                    .as_ref()
+                    .map_or_else(|| format!("_{n}"), |i| i.unraw().to_string())
+            })
+            .collect::<Vec<_>>();
+
+        let mut literals = vec![String::new()];
+        let mut placeholders = vec![];
+
+        let fmt = fmt.value();
+        let mut chars = fmt.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' | '}' if chars.peek() == Some(&c) => {
+                    _ = chars.next();
+                    literals
+                        .last_mut()
+                        .unwrap_or_else(|| unreachable!())
+                        .push(c);
+                }
+                '}' => {
+                    return Err(error(
+                        "unmatched `}` in format string, use `}}` to escape it".into(),
+                    ))
+                }
+                '{' => {
+                    let mut placeholder = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => return Err(error(
+                                "unmatched `{` in format string, use `{{` to escape it"
+                                    .into(),
+                            )),
+                        }
+                    }
+                    let name = placeholder.trim();
+                    if name.contains(':') {
+                        return Err(error(format!(
+                            "formatting parameters are not supported in `FromStr` format \
+                             string: `{{{name}}}`",
+                        )));
+                    }
+                    let Some(n) = names.iter().position(|n| n == name) else {
+                        return Err(error(if name.is_empty() {
+                            "positional placeholders are not supported in `FromStr` format \
+                             string, use field names instead, like `{x}` or `{_0}`"
+                                .into()
+                        } else {
+                            format!("unknown field `{name}` in format string")
+                        }));
+                    };
+                    if placeholders.contains(&n) {
+                        return Err(error(format!(
+                            "field `{name}` is used more than once in format string",
+                        )));
+                    }
+                    if let Some(&prev) = placeholders.last() {
+                        if literals.last().is_some_and(String::is_empty) {
+                            return Err(error(format!(
+                                "placeholders `{{{}}}` and `{{{name}}}` must be separated by \
+                                 a literal in format string",
+                                names[prev],
+                            )));
+                        }
+                    }
+                    placeholders.push(n);
+                    literals.push(String::new());
+                }
+                c => literals
+                    .last_mut()
+                    .unwrap_or_else(|| unreachable!())
+                    .push(c),
+            }
+        }
+
+        if let Some(name) = names
+            .iter()
+            .enumerate()
+            .find_map(|(n, name)| (!placeholders.contains(&n)).then_some(name))
+        {
+            return Err(error(format!("field `{name}` is missing in format string")));
+        }
+
+        Ok(Self {
+            variant,
+            fields,
+            literals,
+            placeholders,
+            case_insensitive: false,
+        })
+    }
+
+    /// Generates code trying to parse the input string `s` into this [`FormatValue`], returning
+    /// on success, or recording the first field parsing error into the `__error` variable.
+    fn parsing(&self, ty_name: &str) -> TokenStream {
+        let variant = self.variant.map(|variant| quote! { :: #variant });
+        let variant_name = self.variant.map_or_else(
+            || quote! { derive_more::core::option::Option::None },
+            |variant| {
+                let name = variant.to_string();
+                quote! { derive_more::core::option::Option::Some(#name) }
+            },
+        );
+
+        let members = self.fields.iter().enumerate().map(|(n, field)| {
+            let member = field
+                .ident
+                .clone()
+                .map_or_else(|| syn::Member::Unnamed(n.into()), syn::Member::Named);
+            let value = format_ident!("__field_{n}");
+            quote! { #member: #value }
+        });
+        let mut parsing = quote! {
+            return derive_more::core::result::Result::Ok(Self #variant { #( #members ),* });
+        };
+
+        for (i, &n) in self.placeholders.iter().enumerate().rev() {
+            let field = self.fields.iter().nth(n).unwrap_or_else(|| unreachable!());
+            let field_ty = &field.ty;
+            let field_name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| format!("_{n}"), |i| i.unraw().to_string());
+            let raw = format_ident!("__raw_{i}");
+            let value = format_ident!("__field_{n}");
+            parsing = quote! {
+                match <#field_ty as derive_more::core::str::FromStr>::from_str(#raw) {
+                    derive_more::core::result::Result::Ok(#value) => { #parsing }
+                    derive_more::core::result::Result::Err(__source) => if __error.is_none() {
+                        use derive_more::__private::{
+                            ExtractFieldSource as _, ExtractNoFieldSource as _,
+                        };
+
+                        __error = derive_more::core::option::Option::Some(
+                            derive_more::FromStrError::new_field(
+                                #ty_name,
+                                #variant_name,
+                                #field_name,
+                                derive_more::__private::FieldError(__source)
+                                    .__extract_field_source(),
+                            ),
+                        );
+                    },
+                }
+            };
+        }
+
+        let Some(&last) = self.placeholders.len().checked_sub(1).as_ref() else {
+            let literal = &self.literals[0];
+            return if self.case_insensitive {
+                quote! { if s.to_lowercase() == #literal { #parsing } }
+            } else {
+                quote! { if s == #literal { #parsing } }
+            };
+        };
+
+        let literal = &self.literals[last + 1];
+        let raw = format_ident!("__raw_{last}");
+        parsing = if literal.is_empty() {
+            quote! { { let #raw = s; #parsing } }
+        } else {
+            quote! {
+                if let derive_more::core::option::Option::Some(#raw) = s.strip_suffix(#literal) {
+                    #parsing
+                }
+            }
+        };
+        for i in (0..last).rev() {
+            let literal = &self.literals[i + 1];
+            let raw = format_ident!("__raw_{i}");
+            parsing = quote! {
+                if let derive_more::core::option::Option::Some((#raw, s)) =
+                    s.split_once(#literal)
+                {
+                    #parsing
+                }
+            };
+        }
+        let prefix = &self.literals[0];
+        if !prefix.is_empty() {
+            parsing = quote! {
+                if let derive_more::core::option::Option::Some(s) = s.strip_prefix(#prefix) {
+                    #parsing
+                }
+            };
+        }
+        parsing
+    }
+}
+
+/// Checks whether the provided [`syn::Attribute`] is a `#[from_str("...")]` one, specifying a
+/// format string.
+fn is_format_attr(attr: &syn::Attribute) -> bool {
+    attr.path().is_ident("from_str")
+        && attr
+            .parse_args_with(|input: syn::parse::ParseStream<'_>| {
+                let is_fmt = input.peek(syn::LitStr);
+                _ = input.parse::<TokenStream>()?;
+                Ok(is_fmt)
+            })
+            .unwrap_or_default()
+}
+
+/// Checks whether the provided [`syn::Attribute`] is a `#[from_str(display)]` one, opting in
+/// reusing `#[display("...")]` attributes for parsing.
+fn is_display_attr(attr: &syn::Attribute) -> bool {
+    attr.path().is_ident("from_str")
+        && attr
+            .parse_args::<syn::Ident>()
+            .is_ok_and(|ident| ident == "display")
+}
+
+/// Looks for a `#[display("...")]` attribute consisting of a single format string literal only,
+/// so it can be reused for parsing.
+fn display_literal(attrs: &[syn::Attribute]) -> Option<syn::LitStr> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("display"))
+        .find_map(|attr| attr.parse_args::<syn::LitStr>().ok())
+}
+
+/// Infers the format string literal of the provided enum `variant` in the same way a `Display`
+/// derive does, considering both its own `#[display("...")]` attribute and the `container` one
+/// placed on the enum.
+///
+/// If the `container` format string contains a `{_variant}` placeholder, then it's replaced with
+/// the variant's own format string (or with its name for a unit variant, or with its field for
+/// a single-field variant). Otherwise, the `container` format string is used only for variants
+/// without their own one.
+fn variant_display_literal(
+    container: Option<&syn::LitStr>,
+    variant: &syn::Variant,
+) -> Option<syn::LitStr> {
+    let own = display_literal(&variant.attrs);
+    let Some(container) = container else {
+        return own;
+    };
+
+    let inner = own.as_ref().map(syn::LitStr::value).or_else(|| {
+        let mut fields = variant.fields.iter();
+        match (fields.next(), fields.next()) {
+            (None, _) => Some(variant.ident.unraw().to_string()),
+            (Some(field), None) => Some(
+                field
+                    .ident
+                    .as_ref()
+                    .map_or_else(|| "{_0}".into(), |i| format!("{{{}}}", i.unraw())),
+            ),
+            (Some(_), Some(_)) => None,
+        }
+    });
+
+    let fmt = container.value();
+    let mut wrapped = String::with_capacity(fmt.len());
+    let mut has_variant = false;
+    let mut chars = fmt.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' if chars.peek() == Some(&c) => {
+                _ = chars.next();
+                wrapped.extend([c, c]);
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        break;
+                    }
+                    placeholder.push(c);
+                }
+                if placeholder.trim() == "_variant" {
+                    has_variant = true;
+                    wrapped.push_str(inner.as_deref()?);
+                } else {
+                    wrapped.push('{');
+                    wrapped.push_str(&placeholder);
+                    wrapped.push('}');
+                }
+            }
+            c => wrapped.push(c),
+        }
+    }
+
+    if has_variant {
+        Some(syn::LitStr::new(&wrapped, container.span()))
+    } else {
+        own.or_else(|| Some(container.clone()))
+    }
+}
+
+/// Representation of possible [`FromStr`] derive macro attributes placed on an enum or a struct.
 ///
 /// ```rust,ignore
+/// #[<attribute>("<format-literal>")]
+/// #[<attribute>(display)]
 /// #[<attribute>(rename_all = "<casing>")]
 /// #[<attribute>(error(<ty>))]
 /// #[<attribute>(error(<ty>, <conv>))]
 /// ```
 ///
-/// Both `#[<attribute>(rename_all = "<casing>")]` and `#[<attribute>(error(<ty>[, <conv>]))]` can
-/// be specified only once.
+/// Each of them can be specified only once, while `#[<attribute>("<format-literal>")]` and
+/// `#[<attribute>(display)]` cannot be combined.
 #[derive(Default)]
-struct FlatContainerAttributes {
+struct ContainerAttributes {
+    /// Format string literal for a [`FormatExpansion`] of a struct.
+    fmt: Option<syn::LitStr>,
+
+    /// [`Span`] of the `display` argument, if any, reusing `#[display("...")]` format string
+    /// literals for a [`FormatExpansion`].
+    display: Option<Span>,
+
     /// [`attr::RenameAll`] for case conversion.
     rename_all: Option<attr::RenameAll>,
 
@@ -485,17 +1113,28 @@ struct FlatContainerAttributes {
     error: Option<attr::Error>,
 }
 
-impl Parse for FlatContainerAttributes {
+impl Parse for ContainerAttributes {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         mod ident {
             use syn::custom_keyword;
 
+            custom_keyword!(display);
             custom_keyword!(error);
             custom_keyword!(rename_all);
         }
 
         let ahead = input.lookahead1();
-        if ahead.peek(ident::error) {
+        if ahead.peek(syn::LitStr) {
+            Ok(Self {
+                fmt: Some(input.parse()?),
+                ..Default::default()
+            })
+        } else if ahead.peek(ident::display) {
+            Ok(Self {
+                display: Some(input.parse::<ident::display>()?.span),
+                ..Default::default()
+            })
+        } else if ahead.peek(ident::error) {
             Ok(Self {
                 error: Some(input.parse()?),
                 ..Default::default()
@@ -511,7 +1150,7 @@ impl Parse for FlatContainerAttributes {
     }
 }
 
-impl attr::ParseMultiple for FlatContainerAttributes {
+impl attr::ParseMultiple for ContainerAttributes {
     fn merge_attrs(
         prev: Spanning<Self>,
         new: Spanning<Self>,
@@ -526,6 +1165,32 @@ impl attr::ParseMultiple for FlatContainerAttributes {
             item: new,
         } = new;
 
+        if new.fmt.is_some() && prev.fmt.is_some() {
+            return Err(syn::Error::new(
+                new_span,
+                format!("multiple `#[{name}(\"...\")]` attributes aren't allowed"),
+            ));
+        }
+
+        if new.display.is_some() && prev.display.is_some() {
+            return Err(syn::Error::new(
+                new_span,
+                format!("multiple `#[{name}(display)]` attributes aren't allowed"),
+            ));
+        }
+
+        if new.fmt.is_some() && prev.display.is_some()
+            || new.display.is_some() && prev.fmt.is_some()
+        {
+            return Err(syn::Error::new(
+                new_span,
+                format!(
+                    "`#[{name}(display)]` attribute cannot be combined with \
+                     `#[{name}(\"...\")]` one",
+                ),
+            ));
+        }
+
         if new
             .rename_all
             .and_then(|n| prev.rename_all.replace(n))
@@ -546,6 +1211,8 @@ impl attr::ParseMultiple for FlatContainerAttributes {
             ));
         }
 
+        prev.fmt = prev.fmt.or(new.fmt);
+        prev.display = prev.display.or(new.display);
         prev.error = prev.error.or(new.error);
 
         Ok(Spanning::new(
@@ -554,3 +1221,78 @@ impl attr::ParseMultiple for FlatContainerAttributes {
         ))
     }
 }
+
+/// Representation of possible [`FromStr`] derive macro attributes placed on an enum variant for
+/// a [`FormatExpansion`].
+///
+/// ```rust,ignore
+/// #[<attribute>("<format-literal>")]
+/// #[<attribute>(rename_all = "<casing>")]
+/// ```
+///
+/// Each of them can be specified only once.
+#[derive(Default)]
+struct VariantAttributes {
+    /// Format string literal to parse the enum variant with.
+    fmt: Option<syn::LitStr>,
+
+    /// [`attr::RenameAll`] for case conversion of a unit enum variant.
+    rename_all: Option<attr::RenameAll>,
+}
+
+impl Parse for VariantAttributes {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::LitStr) {
+            Ok(Self {
+                fmt: Some(input.parse()?),
+                ..Default::default()
+            })
+        } else {
+            Ok(Self {
+                rename_all: Some(input.parse()?),
+                ..Default::default()
+            })
+        }
+    }
+}
+
+impl attr::ParseMultiple for VariantAttributes {
+    fn merge_attrs(
+        prev: Spanning<Self>,
+        new: Spanning<Self>,
+        name: &syn::Ident,
+    ) -> syn::Result<Spanning<Self>> {
+        let Spanning {
+            span: prev_span,
+            item: mut prev,
+        } = prev;
+        let Spanning {
+            span: new_span,
+            item: new,
+        } = new;
+
+        if new.fmt.is_some() && prev.fmt.is_some() {
+            return Err(syn::Error::new(
+                new_span,
+                format!("multiple `#[{name}(\"...\")]` attributes aren't allowed"),
+            ));
+        }
+        if new
+            .rename_all
+            .and_then(|n| prev.rename_all.replace(n))
+            .is_some()
+        {
+            return Err(syn::Error::new(
+                new_span,
+                format!("multiple `#[{name}(rename_all=\"...\")]` attributes aren't allowed"),
+            ));
+        }
+
+        prev.fmt = prev.fmt.or(new.fmt);
+
+        Ok(Spanning::new(
+            prev,
+            prev_span.join(new_span).unwrap_or(prev_span),
+        ))
+    }
+}
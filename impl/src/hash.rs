@@ -0,0 +1,245 @@
+//! Implementation of a [`Hash`] derive macro.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, spanned::Spanned as _};
+
+use crate::utils::{
+    attr::{self, ParseMultiple as _},
+    pattern_matching::FieldsExt as _,
+    structural_inclusion::TypeExt as _,
+    GenericsSearch, HashSet,
+};
+
+/// Expands a [`Hash`] derive macro.
+pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStream> {
+    let attr_name = format_ident!("hash");
+    // Fields ignored by `PartialEq`/`Eq` derives must be ignored in hashing too, otherwise the
+    // `k1 == k2 => hash(k1) == hash(k2)` property is violated. Additional `#[hash(skip)]`
+    // attributes only hash a subset of the compared fields, so never violate it.
+    let eq_attr_names = [format_ident!("partial_eq"), format_ident!("eq")];
+
+    let mut variants = vec![];
+    let mut variants_count = 1;
+
+    match &input.data {
+        syn::Data::Struct(data) => {
+            variants.push(VariantHashing::parse(
+                None,
+                &input.attrs,
+                &data.fields,
+                &attr_name,
+                &eq_attr_names,
+            )?);
+        }
+        syn::Data::Enum(data) => {
+            if let Some(attr) = attr::Skip::parse_attrs(&input.attrs, &attr_name)? {
+                return Err(syn::Error::new(
+                    attr.span,
+                    format!(
+                        "`#[{attr_name}({})]` attribute can be placed only on enum variants or \
+                         fields",
+                        attr.item.name(),
+                    ),
+                ));
+            }
+
+            variants_count = data.variants.len();
+            for variant in &data.variants {
+                variants.push(VariantHashing::parse(
+                    Some(&variant.ident),
+                    &variant.attrs,
+                    &variant.fields,
+                    &attr_name,
+                    &eq_attr_names,
+                )?);
+            }
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "`Hash` cannot be derived for unions",
+            ))
+        }
+    }
+
+    Ok(StructuralExpansion {
+        self_ty: (&input.ident, &input.generics),
+        variants,
+        variants_count,
+        is_enum: matches!(input.data, syn::Data::Enum(_)),
+    }
+    .into_token_stream())
+}
+
+/// Indices of [`syn::Field`]s marked with an [`attr::Skip`].
+type SkippedFields = HashSet<usize>;
+
+/// Hashing of a single enum variant or a struct in a [`StructuralExpansion`].
+struct VariantHashing<'i> {
+    /// [`syn::Ident`] of the enum variant, or [`None`] for a struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    ident: Option<&'i syn::Ident>,
+
+    /// [`syn::Fields`] of the enum variant/struct.
+    fields: &'i syn::Fields,
+
+    /// Indices of the [`syn::Fields`] not participating in hashing.
+    skipped_fields: SkippedFields,
+}
+
+impl<'i> VariantHashing<'i> {
+    /// Parses a [`VariantHashing`] from the provided enum variant or struct definition.
+    fn parse(
+        ident: Option<&'i syn::Ident>,
+        attrs: &[syn::Attribute],
+        fields: &'i syn::Fields,
+        attr_name: &syn::Ident,
+        eq_attr_names: &[syn::Ident],
+    ) -> syn::Result<Self> {
+        let is_skipped = attr::Skip::is_present(attrs, eq_attr_names)?
+            || attr::Skip::parse_attrs(attrs, attr_name)?.is_some();
+
+        let mut skipped_fields = SkippedFields::default();
+        for (n, field) in fields.iter().enumerate() {
+            if is_skipped
+                || attr::Skip::is_present(&field.attrs, eq_attr_names)?
+                || attr::Skip::parse_attrs(&field.attrs, attr_name)?.is_some()
+            {
+                _ = skipped_fields.insert(n);
+            }
+        }
+
+        Ok(Self {
+            ident,
+            fields,
+            skipped_fields,
+        })
+    }
+
+    /// Indicates whether this [`VariantHashing`] has no fields to be hashed.
+    fn is_empty(&self) -> bool {
+        self.skipped_fields.len() == self.fields.len()
+    }
+}
+
+/// Expansion of a macro for generating a structural [`Hash`] implementation of an enum or a
+/// struct.
+struct StructuralExpansion<'i> {
+    /// [`syn::Ident`] and [`syn::Generics`] of the enum/struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    self_ty: (&'i syn::Ident, &'i syn::Generics),
+
+    /// [`VariantHashing`]s of the enum/struct to be hashed in this [`StructuralExpansion`].
+    variants: Vec<VariantHashing<'i>>,
+
+    /// Number of the original enum variants.
+    variants_count: usize,
+
+    /// Indicator whether this expansion is for an enum.
+    is_enum: bool,
+}
+
+impl StructuralExpansion<'_> {
+    /// Generates body of the [`Hash::hash()`] method implementation for this
+    /// [`StructuralExpansion`].
+    fn body(&self) -> TokenStream {
+        // Special case: empty enum.
+        if self.is_enum && self.variants_count == 0 {
+            return quote! { match *self {} };
+        }
+
+        let hash_path = quote! { derive_more::core::hash::Hash::hash };
+
+        let discriminant_hash = (self.variants_count > 1).then(|| {
+            quote! {
+                #hash_path(&derive_more::core::mem::discriminant(self), __state);
+            }
+        });
+
+        let match_arms = self
+            .variants
+            .iter()
+            .filter(|v| !v.is_empty())
+            .map(|v| {
+                let variant = v.ident.map(|variant| quote! { :: #variant });
+                let self_pattern = v
+                    .fields
+                    .non_exhaustive_arm_pattern("__self_", &v.skipped_fields);
+                let hashes = (0..v.fields.len())
+                    .filter(|num| !v.skipped_fields.contains(num))
+                    .map(|num| {
+                        let self_val = format_ident!("__self_{num}");
+                        quote! { #hash_path(#self_val, __state); }
+                    });
+
+                quote! {
+                    Self #variant #self_pattern => { #( #hashes )* }
+                }
+            })
+            .collect::<Vec<_>>();
+        let match_expr = (!match_arms.is_empty()).then(|| {
+            let no_fields_arm =
+                (match_arms.len() != self.variants.len()).then(|| quote! { _ => {} });
+
+            quote! {
+                match self {
+                    #( #match_arms , )*
+                    #no_fields_arm
+                }
+            }
+        });
+
+        quote! {
+            #discriminant_hash
+            #match_expr
+        }
+    }
+}
+
+impl ToTokens for StructuralExpansion<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ty = self.self_ty.0;
+        let (_, ty_generics, _) = self.self_ty.1.split_for_impl();
+
+        let generics_search = GenericsSearch::from(self.self_ty.1);
+        let mut generics = self.self_ty.1.clone();
+        {
+            let self_ty: syn::Type = parse_quote! { Self };
+            let implementor_ty: syn::Type = parse_quote! { #ty #ty_generics };
+            for v in &self.variants {
+                for field_ty in v.fields.iter().enumerate().filter_map(|(n, field)| {
+                    (!v.skipped_fields.contains(&n)).then_some(&field.ty)
+                }) {
+                    if generics_search.any_in(field_ty)
+                        && !field_ty.contains_type_structurally(&self_ty)
+                        && !field_ty.contains_type_structurally(&implementor_ty)
+                    {
+                        generics.make_where_clause().predicates.push(parse_quote! {
+                            #field_ty: derive_more::core::hash::Hash
+                        });
+                    }
+                }
+            }
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let body = self.body();
+
+        quote! {
+            #[allow(private_bounds)]
+            #[automatically_derived]
+            impl #impl_generics derive_more::core::hash::Hash for #ty #ty_generics
+                 #where_clause
+            {
+                #[inline]
+                fn hash<__H: derive_more::core::hash::Hasher>(&self, __state: &mut __H) {
+                    #body
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
@@ -1,10 +1,71 @@
-use crate::utils::{add_where_clauses_for_new_ident, SingleFieldData, State};
+use crate::utils::{
+    add_where_clauses_for_new_ident, index_enum_variants, typed_index_fields,
+    typed_index_generics, IndexEnumVariants, SingleFieldData, State, TypedIndexField,
+};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse::Result, DeriveInput};
+use syn::{parse::Result, Data, DataEnum, DeriveInput};
 
 /// Provides the hook to expand `#[derive(Index)]` into an implementation of `Index`
 pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
+    match &input.data {
+        Data::Enum(data) => expand_enum(input, data, trait_name),
+        _ => expand_struct(input, trait_name),
+    }
+}
+
+fn expand_enum(
+    input: &DeriveInput,
+    data: &DataEnum,
+    trait_name: &'static str,
+) -> Result<TokenStream> {
+    let index_type = format_ident!("__IdxT");
+    let state = State::with_field_ignore(input, trait_name, trait_name.to_lowercase())?;
+    let IndexEnumVariants {
+        output,
+        predicates,
+        arms,
+    } = index_enum_variants(&state, data, &index_type)?;
+
+    let trait_path = &state.trait_path;
+    let trait_path_with_params = quote! { #trait_path<#index_type> };
+    let enum_name = &input.ident;
+    let match_arms = arms.iter().map(|(matcher, casted_trait)| {
+        quote! { #matcher => #casted_trait::index(__0, idx) }
+    });
+
+    let new_generics = add_where_clauses_for_new_ident(
+        &input.generics,
+        &[],
+        &index_type,
+        quote! { where #(#predicates),* },
+        true,
+    );
+
+    let (impl_generics, _, where_clause) = new_generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[allow(deprecated)] // omit warnings on deprecated fields/variants
+        #[allow(unreachable_code)] // omit warnings for `!` and other unreachable types
+        #[automatically_derived]
+        impl #impl_generics #trait_path_with_params for #enum_name #ty_generics #where_clause {
+            type Output = #output;
+
+            #[inline]
+            fn index(&self, idx: #index_type) -> &Self::Output {
+                match self {
+                    #(#match_arms),*
+                }
+            }
+        }
+    })
+}
+
+fn expand_struct(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
+    if let Some(fields) = typed_index_fields(input, "index")? {
+        return Ok(expand_typed(input, &fields));
+    }
+
     let index_type = format_ident!("__IdxT");
     let mut state =
         State::with_field_ignore(input, trait_name, trait_name.to_lowercase())?;
@@ -45,3 +106,45 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
         }
     })
 }
+
+/// Expands `Index` implementations for the fields specified by `#[index(<types>)]` attributes,
+/// generating a separate implementation for each of the index types.
+fn expand_typed(input: &DeriveInput, fields: &[TypedIndexField<'_>]) -> TokenStream {
+    let input_type = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut impls = TokenStream::new();
+    for TypedIndexField {
+        field,
+        member,
+        index_types,
+    } in fields
+    {
+        let field_type = &field.ty;
+        for index_type in index_types {
+            let (index_type, generics) = typed_index_generics(
+                &input.generics,
+                field_type,
+                index_type,
+                quote! { derive_more::core::ops::Index },
+            );
+            let trait_path = quote! { derive_more::core::ops::Index<#index_type> };
+            let casted_trait = quote! { <#field_type as #trait_path> };
+            let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+            impls.extend(quote! {
+                #[allow(deprecated)] // omit warnings on deprecated fields
+                #[automatically_derived]
+                impl #impl_generics #trait_path for #input_type #ty_generics #where_clause {
+                    type Output = #casted_trait::Output;
+
+                    #[inline]
+                    fn index(&self, idx: #index_type) -> &Self::Output {
+                        #casted_trait::index(&#member, idx)
+                    }
+                }
+            });
+        }
+    }
+    impls
+}
@@ -1,10 +1,67 @@
-use crate::utils::{add_where_clauses_for_new_ident, SingleFieldData, State};
+use crate::utils::{
+    add_where_clauses_for_new_ident, index_enum_variants, typed_index_fields,
+    typed_index_generics, IndexEnumVariants, SingleFieldData, State, TypedIndexField,
+};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse::Result, DeriveInput};
+use syn::{parse::Result, Data, DataEnum, DeriveInput};
 
 /// Provides the hook to expand `#[derive(IndexMut)]` into an implementation of `IndexMut`
 pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
+    match &input.data {
+        Data::Enum(data) => expand_enum(input, data, trait_name),
+        _ => expand_struct(input, trait_name),
+    }
+}
+
+fn expand_enum(
+    input: &DeriveInput,
+    data: &DataEnum,
+    trait_name: &'static str,
+) -> Result<TokenStream> {
+    let index_type = format_ident!("__IdxT");
+    let state = State::with_field_ignore(input, trait_name, "index_mut".into())?;
+    let IndexEnumVariants {
+        predicates, arms, ..
+    } = index_enum_variants(&state, data, &index_type)?;
+
+    let trait_path = &state.trait_path;
+    let trait_path_with_params = quote! { #trait_path<#index_type> };
+    let enum_name = &input.ident;
+    let match_arms = arms.iter().map(|(matcher, casted_trait)| {
+        quote! { #matcher => #casted_trait::index_mut(__0, idx) }
+    });
+
+    let new_generics = add_where_clauses_for_new_ident(
+        &input.generics,
+        &[],
+        &index_type,
+        quote! { where #(#predicates),* },
+        true,
+    );
+
+    let (impl_generics, _, where_clause) = new_generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[allow(deprecated)] // omit warnings on deprecated fields/variants
+        #[allow(unreachable_code)] // omit warnings for `!` and other unreachable types
+        #[automatically_derived]
+        impl #impl_generics #trait_path_with_params for #enum_name #ty_generics #where_clause {
+            #[inline]
+            fn index_mut(&mut self, idx: #index_type) -> &mut Self::Output {
+                match self {
+                    #(#match_arms),*
+                }
+            }
+        }
+    })
+}
+
+fn expand_struct(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
+    if let Some(fields) = typed_index_fields(input, "index_mut")? {
+        return Ok(expand_typed(input, &fields));
+    }
+
     let index_type = format_ident!("__IdxT");
     let mut state = State::with_field_ignore(input, trait_name, "index_mut".into())?;
     state.add_trait_path_type_param(quote! { #index_type });
@@ -42,3 +99,43 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
         }
     })
 }
+
+/// Expands `IndexMut` implementations for the fields specified by `#[index_mut(<types>)]`
+/// attributes, generating a separate implementation for each of the index types.
+fn expand_typed(input: &DeriveInput, fields: &[TypedIndexField<'_>]) -> TokenStream {
+    let input_type = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut impls = TokenStream::new();
+    for TypedIndexField {
+        field,
+        member,
+        index_types,
+    } in fields
+    {
+        let field_type = &field.ty;
+        for index_type in index_types {
+            let (index_type, generics) = typed_index_generics(
+                &input.generics,
+                field_type,
+                index_type,
+                quote! { derive_more::core::ops::IndexMut },
+            );
+            let trait_path = quote! { derive_more::core::ops::IndexMut<#index_type> };
+            let casted_trait = quote! { <#field_type as #trait_path> };
+            let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+            impls.extend(quote! {
+                #[allow(deprecated)] // omit warnings on deprecated fields
+                #[automatically_derived]
+                impl #impl_generics #trait_path for #input_type #ty_generics #where_clause {
+                    #[inline]
+                    fn index_mut(&mut self, idx: #index_type) -> &mut Self::Output {
+                        #casted_trait::index_mut(&mut #member, idx)
+                    }
+                }
+            });
+        }
+    }
+    impls
+}
@@ -0,0 +1,129 @@
+//! Implementation of an [`IntoStaticStr`] derive macro.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt as _, spanned::Spanned as _};
+
+use crate::{
+    fmt::display::ContainerAttributes as DisplayAttributes,
+    utils::{attr::ParseMultiple as _, Spanning},
+};
+
+/// Expands an [`IntoStaticStr`] derive macro.
+pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStream> {
+    let attr_name = format_ident!("display");
+
+    let data = match &input.data {
+        syn::Data::Enum(data) => data,
+        syn::Data::Struct(data) => {
+            return Err(syn::Error::new(
+                data.struct_token.span(),
+                "`IntoStaticStr` can be derived only for enums",
+            ))
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "`IntoStaticStr` can be derived only for enums",
+            ))
+        }
+    };
+    if let Some(variant) = data.variants.iter().find(|v| !v.fields.is_empty()) {
+        return Err(syn::Error::new(
+            variant.fields.span(),
+            "`IntoStaticStr` can be derived only for enums with no fields",
+        ));
+    }
+
+    let container = DisplayAttributes::parse_attrs(&input.attrs, &attr_name)?
+        .map(Spanning::into_inner)
+        .unwrap_or_default();
+    if let Some((lit, _)) = container.fmt_literal() {
+        return Err(syn::Error::new(
+            lit.span(),
+            "`IntoStaticStr` cannot be derived for enums with a shared \
+             `#[display(\"...\")]` format",
+        ));
+    }
+
+    let variants = data.variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+    let strs = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let attr = DisplayAttributes::parse_attrs(&variant.attrs, &attr_name)?
+                .map(Spanning::into_inner)
+                .unwrap_or_default();
+            if let Some((lit, literal)) = attr.fmt_literal() {
+                return literal.ok_or_else(|| {
+                    syn::Error::new(
+                        lit.span(),
+                        "`IntoStaticStr` requires `#[display(\"...\")]` format string to have \
+                         no placeholders or arguments",
+                    )
+                });
+            }
+            let name = variant.ident.unraw().to_string();
+            Ok(match attr.rename_all().or(container.rename_all()) {
+                Some(renaming) => renaming.convert_case(&name),
+                None => name,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let as_str_body = if variants.is_empty() {
+        quote! { match *self {} }
+    } else {
+        quote! {
+            match self {
+                #( Self::#variants => #strs, )*
+            }
+        }
+    };
+
+    Ok(quote! {
+        #[allow(deprecated)] // omit warnings on deprecated variants
+        #[automatically_derived]
+        impl #impl_generics #ty #ty_generics #where_clause {
+            /// Returns the string representation of this value, the same as its `Display`.
+            #[inline]
+            #[must_use]
+            pub const fn as_str(&self) -> &'static str {
+                #as_str_body
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics derive_more::core::convert::From<#ty #ty_generics> for &'static str
+             #where_clause
+        {
+            #[inline]
+            fn from(value: #ty #ty_generics) -> Self {
+                value.as_str()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics derive_more::core::convert::From<&#ty #ty_generics> for &'static str
+             #where_clause
+        {
+            #[inline]
+            fn from(value: &#ty #ty_generics) -> Self {
+                value.as_str()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics derive_more::core::convert::AsRef<str> for #ty #ty_generics
+             #where_clause
+        {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+    })
+}
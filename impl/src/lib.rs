@@ -10,16 +10,28 @@ use syn::parse::Error as ParseError;
 
 mod utils;
 
+#[cfg(feature = "accessors")]
+mod accessors;
 #[cfg(feature = "as_ref")]
 mod r#as;
-#[cfg(feature = "eq")]
+#[cfg(feature = "builder")]
+mod builder;
+#[cfg(feature = "checked_ops")]
+mod checked_ops;
+#[cfg(feature = "clone")]
+mod clone;
+#[cfg(any(feature = "eq", feature = "ord"))]
 mod cmp;
 #[cfg(feature = "constructor")]
 mod constructor;
+#[cfg(feature = "default_derive")]
+mod default;
 #[cfg(feature = "deref")]
 mod deref;
 #[cfg(feature = "deref_mut")]
 mod deref_mut;
+#[cfg(feature = "discriminant")]
+mod discriminant;
 #[cfg(feature = "error")]
 mod error;
 #[cfg(any(feature = "debug", feature = "display"))]
@@ -28,6 +40,8 @@ mod fmt;
 mod from;
 #[cfg(feature = "from_str")]
 mod from_str;
+#[cfg(feature = "hash")]
+mod hash;
 #[cfg(feature = "index")]
 mod index;
 #[cfg(feature = "index_mut")]
@@ -36,6 +50,8 @@ mod index_mut;
 mod into;
 #[cfg(feature = "into_iterator")]
 mod into_iterator;
+#[cfg(feature = "into_static_str")]
+mod into_static_str;
 #[cfg(feature = "is_variant")]
 mod is_variant;
 #[cfg(feature = "not")]
@@ -59,6 +75,10 @@ mod try_into;
 mod try_unwrap;
 #[cfg(feature = "unwrap")]
 mod unwrap;
+#[cfg(feature = "variant_array")]
+mod variant_array;
+#[cfg(feature = "variant_name")]
+mod variant_name;
 
 // This trait describes the possible return types of
 // the derives. A derive can generally be infallible and
@@ -141,13 +161,45 @@ create_derive!(
     bitxor_assign,
 );
 
+create_derive!("accessors", accessors, Getters, getters_derive, getter);
+create_derive!("accessors", accessors, Setters, setters_derive, setter);
+
 create_derive!("as_ref", r#as::r#mut, AsMut, as_mut_derive, as_mut);
 create_derive!("as_ref", r#as::r#ref, AsRef, as_ref_derive, as_ref);
 
+create_derive!("builder", builder, Builder, builder_derive, builder);
+
+create_derive!(
+    "checked_ops",
+    checked_ops,
+    CheckedOps,
+    checked_ops_derive,
+    checked_ops,
+);
+create_derive!(
+    "checked_ops",
+    checked_ops,
+    SaturatingOps,
+    saturating_ops_derive,
+    saturating_ops,
+);
+create_derive!(
+    "checked_ops",
+    checked_ops,
+    WrappingOps,
+    wrapping_ops_derive,
+    wrapping_ops,
+);
+
+create_derive!("clone", clone, Clone, clone_derive, clone);
+create_derive!("clone", clone, Copy, copy_derive, copy);
+
 create_derive!("constructor", constructor, Constructor, constructor_derive);
 
 create_derive!("debug", fmt::debug, Debug, debug_derive, debug);
 
+create_derive!("default_derive", default, Default, default_derive, default,);
+
 create_derive!("deref", deref, Deref, deref_derive, deref);
 
 create_derive!(
@@ -158,6 +210,14 @@ create_derive!(
     deref_mut,
 );
 
+create_derive!(
+    "discriminant",
+    discriminant,
+    Discriminant,
+    discriminant_derive,
+    discriminant
+);
+
 create_derive!("display", fmt::display, Display, display_derive, display);
 create_derive!("display", fmt::display, Binary, binary_derive, binary);
 create_derive!("display", fmt::display, Octal, octal_derive, octal);
@@ -200,12 +260,24 @@ create_derive!(
     partial_eq,
 );
 
+create_derive!("ord", cmp::ord, Ord, ord_derive, ord, partial_ord);
+create_derive!(
+    "ord",
+    cmp::ord,
+    PartialOrd,
+    partial_ord_derive,
+    partial_ord,
+    ord,
+);
+
 create_derive!("error", error, Error, error_derive, error);
 
 create_derive!("from", from, From, from_derive, from);
 
 create_derive!("from_str", from_str, FromStr, from_str_derive, from_str);
 
+create_derive!("hash", hash, Hash, hash_derive, hash);
+
 create_derive!("index", index, Index, index_derive, index);
 
 create_derive!(
@@ -226,6 +298,14 @@ create_derive!(
     into_iterator,
 );
 
+create_derive!(
+    "into_static_str",
+    into_static_str,
+    IntoStaticStr,
+    into_static_str_derive,
+    display,
+);
+
 create_derive!(
     "is_variant",
     is_variant,
@@ -276,8 +356,8 @@ create_derive!(
     shl_assign,
 );
 
-create_derive!("not", not_like, Not, not_derive);
-create_derive!("not", not_like, Neg, neg_derive);
+create_derive!("not", not_like, Not, not_derive, not);
+create_derive!("not", not_like, Neg, neg_derive, neg);
 
 create_derive!("sum", sum_like, Sum, sum_derive);
 create_derive!("sum", sum_like, Product, product_derive);
@@ -295,3 +375,18 @@ create_derive!(
 );
 
 create_derive!("unwrap", unwrap, Unwrap, unwrap_derive, unwrap);
+
+create_derive!(
+    "variant_array",
+    variant_array,
+    VariantArray,
+    variant_array_derive
+);
+
+create_derive!(
+    "variant_name",
+    variant_name,
+    VariantName,
+    variant_name_derive,
+    variant_name,
+);
@@ -1,21 +1,32 @@
 use crate::utils::{
-    add_extra_type_param_bound_op_output, named_to_vec, unnamed_to_vec,
+    add_extra_type_param_bound_op_output, named_to_vec, unnamed_to_vec, Primitive,
 };
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 use std::iter;
-use syn::{Data, DataEnum, DeriveInput, Field, Fields, Ident, Index};
+use syn::{
+    parse::ParseStream, spanned::Spanned as _, Data, DataEnum, DeriveInput, Error,
+    Field, Fields, Ident, Index, Result, Token, Type,
+};
+
+pub fn expand(input: &DeriveInput, trait_name: &str) -> Result<TokenStream> {
+    if trait_name == "Neg" {
+        check_no_unsigned_fields(input)?;
+    }
 
-pub fn expand(input: &DeriveInput, trait_name: &str) -> TokenStream {
     let trait_ident = format_ident!("{trait_name}");
     let method_name = trait_name.to_lowercase();
     let method_ident = format_ident!("{method_name}");
     let input_type = &input.ident;
 
+    let const_fn = parse_const_attr(input, &method_ident)?
+        .map(|span| const_fn(input, trait_name, &method_ident, span))
+        .transpose()?;
+
     let generics = add_extra_type_param_bound_op_output(&input.generics, &trait_ident);
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let (output_type, block) = match input.data {
+    let (output_type, mut block) = match input.data {
         Data::Struct(ref data_struct) => match data_struct.fields {
             Fields::Unnamed(ref fields) => (
                 quote! { #input_type #ty_generics },
@@ -34,7 +45,13 @@ pub fn expand(input: &DeriveInput, trait_name: &str) -> TokenStream {
         _ => panic!("Only structs and enums can use derive({trait_name})"),
     };
 
-    quote! {
+    if const_fn.is_some() {
+        block = quote! { Self::#method_ident(self) };
+    }
+
+    Ok(quote! {
+        #const_fn
+
         #[allow(deprecated)] // omit warnings on deprecated fields/variants
         #[allow(unreachable_code)] // omit warnings for `!` and other unreachable types
         #[automatically_derived]
@@ -47,7 +64,137 @@ pub fn expand(input: &DeriveInput, trait_name: &str) -> TokenStream {
                 #block
             }
         }
+    })
+}
+
+/// Parses the `#[<method>(const)]` attribute of the provided `input`, if any, returning its
+/// [`Span`].
+fn parse_const_attr(input: &DeriveInput, attr_name: &Ident) -> Result<Option<Span>> {
+    let mut span = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(attr_name))
+    {
+        attr.parse_args_with(|input: ParseStream<'_>| {
+            if !input.peek(Token![const]) {
+                return Err(Error::new(
+                    input.span(),
+                    "unknown attribute argument, expected `const`",
+                ));
+            }
+            span = Some(input.parse::<Token![const]>()?.span);
+            Ok(())
+        })?;
+    }
+    Ok(span)
+}
+
+/// Generates an inherent `const fn` equivalent of the trait method for the provided `input`
+/// struct, which is re-used by the trait implementation.
+fn const_fn(
+    input: &DeriveInput,
+    trait_name: &str,
+    method_ident: &Ident,
+    span: Span,
+) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            span,
+            format!(
+                "`#[{method_ident}(const)]` attribute can be placed only on structs"
+            ),
+        ));
+    };
+    // Floating-point negation is allowed in `const fn` only since Rust 1.82, which is above the
+    // MSRV.
+    let (is_allowed, expected): (fn(Primitive) -> bool, _) = if trait_name == "Neg" {
+        (
+            |p| p == Primitive::SignedInt,
+            "primitive signed integer types",
+        )
+    } else {
+        (
+            |p| p != Primitive::Float,
+            "primitive integer or `bool` types",
+        )
+    };
+    for field in &data.fields {
+        if !Primitive::of(&field.ty).is_some_and(is_allowed) {
+            return Err(Error::new(
+                field.ty.span(),
+                format!(
+                    "`#[{method_ident}(const)]` attribute requires all the fields to be of \
+                     {expected}",
+                ),
+            ));
+        }
+    }
+
+    let input_type = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let operator = if trait_name == "Neg" {
+        quote! { - }
+    } else {
+        quote! { ! }
+    };
+    let exprs = data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => quote! { #ident: #operator self.#ident },
+            None => {
+                let i = Index::from(i);
+                quote! { #operator self.#i }
+            }
+        });
+    let value = match &data.fields {
+        Fields::Named(_) => quote! { Self { #(#exprs),* } },
+        _ => quote! { Self(#(#exprs),*) },
+    };
+    let doc = format!("`const` equivalent of the `{trait_name}` trait implementation.");
+
+    Ok(quote! {
+        #[allow(deprecated)] // omit warnings on deprecated fields
+        #[automatically_derived]
+        impl #impl_generics #input_type #ty_generics #where_clause {
+            #[doc = #doc]
+            #[inline]
+            #[must_use]
+            #[track_caller]
+            pub const fn #method_ident(self) -> Self {
+                #value
+            }
+        }
+    })
+}
+
+/// Checks that none of the fields of the provided `input` has an unsigned integer type, as such
+/// fields cannot be negated, and would only produce a confusing trait bound failure otherwise.
+fn check_no_unsigned_fields(input: &DeriveInput) -> Result<()> {
+    let fields: Vec<&Field> = match &input.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data.variants.iter().flat_map(|v| &v.fields).collect(),
+        Data::Union(_) => vec![],
+    };
+    for field in fields {
+        if let (Type::Path(ty), Some(Primitive::UnsignedInt)) =
+            (&field.ty, Primitive::of(&field.ty))
+        {
+            // `Primitive` is recognized either by its name, or by a `primitive` module path ending
+            // with it.
+            let name = &ty.path.segments.last().unwrap().ident;
+            return Err(Error::new(
+                field.ty.span(),
+                format!(
+                    "`Neg` cannot be derived for types with unsigned integer fields, as \
+                     `{name}` cannot be negated",
+                ),
+            ));
+        }
     }
+    Ok(())
 }
 
 fn tuple_content<T: ToTokens>(
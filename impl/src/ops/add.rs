@@ -3,12 +3,23 @@
 #[cfg(doc)]
 use std::ops;
 
-use proc_macro2::TokenStream;
-use quote::{format_ident, ToTokens as _};
-use syn::spanned::Spanned as _;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned as _,
+    token,
+};
 
-use super::{SkippedFields, StructuralExpansion};
-use crate::utils::attr::{self, ParseMultiple as _};
+use super::{
+    typed_expansions, ConstExpansion, EnumOutput, SkippedFields, StructuralExpansion,
+    TypedAttribute, TypedExpansion,
+};
+use crate::utils::{
+    attr::{self, ParseMultiple as _},
+    Either, Spanning,
+};
 
 /// Expands an [`ops::Add`]-like derive macro.
 ///
@@ -22,18 +33,107 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenSt
     let trait_name = normalize_trait_name(trait_name);
     let attr_name = format_ident!("{}", trait_name_to_attribute_name(trait_name));
 
+    let (typed_attrs, attrs) = TypedAttribute::split_attrs(&input.attrs, &attr_name)?;
+    let typed = typed_expansions(
+        input,
+        trait_name,
+        trait_name_to_method_name(trait_name),
+        &attr_name,
+        typed_attrs,
+    )?;
+
+    let container = match Either::<attr::Skip, ContainerAttribute>::parse_attrs(
+        &attrs, &attr_name,
+    )? {
+        Some(Spanning {
+            span,
+            item: Either::Left(skip),
+        }) => {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "`#[{attr_name}({})]` attribute can be placed only on {} fields",
+                    skip.name(),
+                    if matches!(input.data, syn::Data::Enum(_)) {
+                        "enum"
+                    } else {
+                        "struct"
+                    },
+                ),
+            ));
+        }
+        Some(Spanning {
+            item: Either::Right(attr),
+            ..
+        }) => attr,
+        None => ContainerAttribute::default(),
+    };
+
+    let forward = container
+        .forward
+        .map(|span| {
+            if let Some(by_ref) = container.by_ref {
+                return Err(syn::Error::new(
+                    by_ref,
+                    "`ref` argument cannot be combined with `forward`",
+                ));
+            }
+            if let Some(is_const) = container.is_const {
+                return Err(syn::Error::new(
+                    is_const,
+                    "`const` argument cannot be combined with `forward`",
+                ));
+            }
+            if !typed.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "`forward` argument cannot be combined with \
+                         `#[{attr_name}(<rhs>, output = <output>)]` attributes",
+                    ),
+                ));
+            }
+            match &input.data {
+                syn::Data::Struct(data) if data.fields.len() == 1 => {
+                    let field = data.fields.iter().next().unwrap_or_else(|| unreachable!());
+                    Ok(ForwardExpansion {
+                        trait_ty: format_ident!("{trait_name}"),
+                        method_ident: format_ident!(
+                            "{}",
+                            trait_name_to_method_name(trait_name),
+                        ),
+                        self_ty: (&input.ident, &input.generics),
+                        member: field
+                            .ident
+                            .clone()
+                            .map_or_else(|| syn::Member::from(0), syn::Member::from),
+                        field_ty: &field.ty,
+                    })
+                }
+                _ => Err(syn::Error::new(
+                    span,
+                    format!(
+                        "`#[{attr_name}(forward)]` attribute can be placed only on structs with \
+                         a single field",
+                    ),
+                )),
+            }
+        })
+        .transpose()?;
+
     let mut variants = vec![];
+    let mut enum_output = None;
     match &input.data {
         syn::Data::Struct(data) => {
-            if let Some(skip) = attr::Skip::parse_attrs(&input.attrs, &attr_name)? {
+            if let Some(output) = container.output {
                 return Err(syn::Error::new(
-                    skip.span,
+                    output.span,
                     format!(
-                        "`#[{attr_name}({})]` attribute can be placed only on struct fields",
-                        skip.item.name(),
+                        "`#[{attr_name}(output = ...)]` attribute can be placed only on enums",
                     ),
                 ));
-            } else if matches!(data.fields, syn::Fields::Unit) {
+            }
+            if matches!(data.fields, syn::Fields::Unit) {
                 return Err(syn::Error::new(
                     data.struct_token.span(),
                     format!("`{trait_name}` cannot be derived for unit structs"),
@@ -57,15 +157,17 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenSt
             variants.push((None, &data.fields, skipped_fields));
         }
         syn::Data::Enum(data) => {
-            if let Some(skip) = attr::Skip::parse_attrs(&input.attrs, &attr_name)? {
+            if let Some(is_const) = container.is_const {
                 return Err(syn::Error::new(
-                    skip.span,
-                    format!(
-                        "`#[{attr_name}({})]` attribute can be placed only on enum fields",
-                        skip.item.name(),
-                    ),
+                    is_const,
+                    format!("`#[{attr_name}(const)]` attribute can be placed only on structs"),
                 ));
             }
+            enum_output = Some(
+                container
+                    .output
+                    .map_or_else(EnumOutput::default, Spanning::into_inner),
+            );
             for variant in &data.variants {
                 if let Some(skip) = attr::Skip::parse_attrs(&variant.attrs, &attr_name)?
                 {
@@ -105,14 +207,280 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenSt
         }
     }
 
-    Ok(StructuralExpansion {
-        trait_ty: format_ident!("{trait_name}"),
-        method_ident: format_ident!("{}", trait_name_to_method_name(trait_name)),
-        self_ty: (&input.ident, &input.generics),
-        variants,
-        is_enum: matches!(input.data, syn::Data::Enum(_)),
+    if let Some(forward) = forward {
+        return Ok(forward.into_token_stream());
+    }
+
+    let const_fn = container
+        .is_const
+        .map(|span| {
+            if !typed.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "`const` argument cannot be combined with \
+                         `#[{attr_name}(<rhs>, output = <output>)]` attributes",
+                    ),
+                ));
+            }
+            let (_, _, skipped_fields) = variants[0].clone();
+            ConstExpansion::new(
+                input,
+                trait_name,
+                trait_name_to_method_name(trait_name),
+                &attr_name,
+                span,
+                skipped_fields,
+                false,
+            )
+        })
+        .transpose()?;
+
+    let structural =
+        (!typed.iter().any(TypedExpansion::is_self_rhs)).then(|| StructuralExpansion {
+            trait_ty: format_ident!("{trait_name}"),
+            method_ident: format_ident!("{}", trait_name_to_method_name(trait_name)),
+            self_ty: (&input.ident, &input.generics),
+            variants,
+            enum_output,
+            by_ref: container.by_ref.is_some(),
+            is_const: const_fn.is_some(),
+        });
+
+    Ok(quote! {
+        #const_fn
+        #structural
+        #( #typed )*
+    })
+}
+
+/// Expansion of a macro for generating a forwarding [`ops::Add`]-like trait implementation for a
+/// single-field struct, accepting any `Rhs` type the field type accepts.
+struct ForwardExpansion<'i> {
+    /// [`syn::Ident`] of the implemented trait.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    trait_ty: syn::Ident,
+
+    /// [`syn::Ident`] of the implemented method in trait.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    method_ident: syn::Ident,
+
+    /// [`syn::Ident`] and [`syn::Generics`] of the implementor struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    self_ty: (&'i syn::Ident, &'i syn::Generics),
+
+    /// [`syn::Member`] of the single field of the implementor struct.
+    member: syn::Member,
+
+    /// [`syn::Type`] of the single field of the implementor struct.
+    field_ty: &'i syn::Type,
+}
+
+impl ForwardExpansion<'_> {
+    /// Returns the [`syn::TypeParam`] of the implementor struct, if the field type is exactly it.
+    fn field_type_param(&self) -> Option<&syn::TypeParam> {
+        let syn::Type::Path(ty) = self.field_ty else {
+            return None;
+        };
+        if ty.qself.is_some() {
+            return None;
+        }
+        let ident = ty.path.get_ident()?;
+        self.self_ty
+            .1
+            .type_params()
+            .find(|param| param.ident == *ident)
+    }
+}
+
+impl ToTokens for ForwardExpansion<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let trait_ty = &self.trait_ty;
+        let method_ident = &self.method_ident;
+        let member = &self.member;
+        let field_ty = self.field_ty;
+
+        let ty = self.self_ty.0;
+        let (_, ty_generics, _) = self.self_ty.1.split_for_impl();
+        let rhs_ty: syn::TypeParam = parse_quote! { __derive_more_Rhs };
+        let trait_path = quote! { derive_more::core::ops::#trait_ty<#rhs_ty> };
+
+        let mut generics = self.self_ty.1.clone();
+        generics.params.push(rhs_ty.clone().into());
+
+        // If the field type is a type parameter, then the result is re-wrapped into the struct
+        // with this type parameter replaced by the resulting type of the operation.
+        let (output_ty, output_path) = if let Some(param) = self.field_type_param() {
+            let param_ident = &param.ident;
+            let result_ty: syn::Type = parse_quote! {
+                <#field_ty as #trait_path>::Output
+            };
+
+            let where_clause = generics.make_where_clause();
+            where_clause
+                .predicates
+                .push(parse_quote! { #field_ty: #trait_path });
+            let bounds = param.bounds.iter().chain(
+                self.self_ty
+                    .1
+                    .where_clause
+                    .iter()
+                    .flat_map(|clause| &clause.predicates)
+                    .filter_map(|pred| match pred {
+                        syn::WherePredicate::Type(pred)
+                            if matches!(
+                                &pred.bounded_ty,
+                                syn::Type::Path(ty) if ty.qself.is_none()
+                                    && ty.path.is_ident(param_ident),
+                            ) =>
+                        {
+                            Some(&pred.bounds)
+                        }
+                        _ => None,
+                    })
+                    .flatten(),
+            );
+            for bound in bounds {
+                where_clause
+                    .predicates
+                    .push(parse_quote! { #result_ty: #bound });
+            }
+
+            let args = self.self_ty.1.params.iter().map(|param| match param {
+                syn::GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+                syn::GenericParam::Type(param) if param.ident == *param_ident => {
+                    result_ty.to_token_stream()
+                }
+                syn::GenericParam::Type(syn::TypeParam { ident, .. })
+                | syn::GenericParam::Const(syn::ConstParam { ident, .. }) => {
+                    ident.to_token_stream()
+                }
+            });
+            (quote! { #ty < #( #args ),* > }, ty.to_token_stream())
+        } else {
+            generics.make_where_clause().predicates.push(parse_quote! {
+                #field_ty: derive_more::core::ops::#trait_ty<#rhs_ty, Output = #field_ty>
+            });
+            (quote! { Self }, quote! { Self })
+        };
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        quote! {
+            #[allow(private_bounds)]
+            #[automatically_derived]
+            impl #impl_generics #trait_path for #ty #ty_generics #where_clause {
+                type Output = #output_ty;
+
+                #[inline]
+                #[track_caller]
+                fn #method_ident(self, __rhs: #rhs_ty) -> Self::Output {
+                    #output_path {
+                        #member: derive_more::core::ops::#trait_ty::#method_ident(
+                            self.#member,
+                            __rhs,
+                        ),
+                    }
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+/// Representation of an [`ops::Add`]-like derive macro container attribute.
+///
+/// ```rust,ignore
+/// #[<attribute>(forward)]
+/// #[<attribute>(ref)]
+/// #[<attribute>(const)]
+/// #[<attribute>(output = <policy>)]
+/// ```
+///
+/// Multiple arguments can be specified in a single attribute, separated by commas.
+#[derive(Default)]
+struct ContainerAttribute {
+    /// [`Span`] of the `forward` argument, if any, making the implementation accepting any `Rhs`
+    /// type the single field does.
+    forward: Option<Span>,
+
+    /// [`Span`] of the `ref` argument, if any, additionally implementing the trait for references.
+    by_ref: Option<Span>,
+
+    /// [`Span`] of the `const` argument, if any, additionally generating an inherent `const fn`
+    /// equivalent of the trait method.
+    is_const: Option<Span>,
+
+    /// [`EnumOutput`] policy of handling failures, if specified.
+    output: Option<Spanning<EnumOutput>>,
+}
+
+impl Parse for ContainerAttribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut attr = Self::default();
+        loop {
+            if input.peek(token::Ref) {
+                attr.by_ref = Some(input.parse::<token::Ref>()?.span);
+            } else if input.peek(token::Const) {
+                attr.is_const = Some(input.parse::<token::Const>()?.span);
+            } else if input
+                .fork()
+                .parse::<syn::Path>()
+                .is_ok_and(|path| path.is_ident("forward"))
+            {
+                attr.forward = Some(input.parse::<syn::Path>()?.span());
+            } else {
+                let span = input.span();
+                let output = input.parse::<EnumOutput>()?;
+                if attr.output.is_some() {
+                    return Err(syn::Error::new(
+                        span,
+                        "only single `output = ...` argument is allowed",
+                    ));
+                }
+                attr.output = Some(Spanning::new(output, span));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            _ = input.parse::<token::Comma>()?;
+            if input.is_empty() {
+                break;
+            }
+        }
+        Ok(attr)
+    }
+}
+
+impl attr::ParseMultiple for ContainerAttribute {
+    fn merge_attrs(
+        prev: Spanning<Self>,
+        new: Spanning<Self>,
+        name: &syn::Ident,
+    ) -> syn::Result<Spanning<Self>> {
+        let span = prev.span.join(new.span).unwrap_or(prev.span);
+        let (prev, new) = (prev.item, new.item);
+        if let (Some(_), Some(output)) = (&prev.output, &new.output) {
+            return Err(syn::Error::new(
+                output.span,
+                format!(
+                    "only single `#[{name}(output = ...)]` attribute is allowed here"
+                ),
+            ));
+        }
+        Ok(Spanning::new(
+            Self {
+                forward: prev.forward.or(new.forward),
+                by_ref: prev.by_ref.or(new.by_ref),
+                is_const: prev.is_const.or(new.is_const),
+                output: prev.output.or(new.output),
+            },
+            span,
+        ))
     }
-    .into_token_stream())
 }
 
 /// Matches the provided derive macro `name` to appropriate actual trait name.
@@ -7,7 +7,9 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, ToTokens as _};
 use syn::spanned::Spanned as _;
 
-use super::{AssignStructuralExpansion, SkippedFields};
+use super::{
+    enum_variants, AssignEnumAttribute, AssignStructuralExpansion, SkippedFields,
+};
 use crate::utils::attr::{self, ParseMultiple as _};
 
 /// Expands an [`ops::AddAssign`]-like derive macro.
@@ -23,6 +25,7 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenSt
     let attr_name = format_ident!("{}", trait_name_to_attribute_name(trait_name));
 
     let mut variants = vec![];
+    let mut enum_policy = None;
     match &input.data {
         syn::Data::Struct(data) => {
             if let Some(skip) = attr::Skip::parse_attrs(&input.attrs, &attr_name)? {
@@ -57,10 +60,16 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenSt
             variants.push((None, &data.fields, skipped_fields));
         }
         syn::Data::Enum(data) => {
-            return Err(syn::Error::new(
-                data.enum_token.span(),
-                format!("`{trait_name}` cannot be derived for enums"),
-            ));
+            let (policy, forward) =
+                AssignEnumAttribute::parse_policy(input, data, trait_name, &attr_name)?;
+            if let Some(span) = forward {
+                return Err(syn::Error::new(
+                    span,
+                    format!("`#[{attr_name}(forward)]` attribute is not supported"),
+                ));
+            }
+            enum_policy = Some(policy);
+            variants = enum_variants(data, trait_name, &attr_name)?;
         }
         syn::Data::Union(data) => {
             return Err(syn::Error::new(
@@ -75,7 +84,7 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenSt
         method_ident: format_ident!("{}", trait_name_to_method_name(trait_name)),
         self_ty: (&input.ident, &input.generics),
         variants,
-        is_enum: false,
+        enum_policy,
     }
     .into_token_stream())
 }
@@ -11,16 +11,36 @@ pub(crate) mod mul;
 #[cfg(feature = "mul_assign")]
 pub(crate) mod mul_assign;
 
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "mul",
+    feature = "mul_assign",
+))]
+use proc_macro2::Span;
 use proc_macro2::TokenStream;
+#[cfg(any(feature = "add", feature = "mul"))]
+use quote::quote_spanned;
 use quote::{format_ident, quote, ToTokens};
+#[cfg(any(feature = "add_assign", feature = "mul_assign"))]
+use syn::ext::IdentExt as _;
 use syn::parse_quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned as _,
+    token,
+};
 
-#[cfg(doc)]
+#[cfg(any(doc, feature = "add_assign", feature = "mul", feature = "mul_assign"))]
 use crate::utils::attr;
+#[cfg(any(feature = "add_assign", feature = "mul_assign"))]
+use crate::utils::attr::ParseMultiple as _;
 use crate::utils::{
     pattern_matching::FieldsExt as _, structural_inclusion::TypeExt as _,
     GenericsSearch, HashSet,
 };
+#[cfg(any(feature = "add", feature = "mul"))]
+use crate::utils::{Primitive, Spanning};
 
 /// Indices of [`syn::Field`]s marked with an [`attr::Skip`].
 type SkippedFields = HashSet<usize>;
@@ -47,14 +67,20 @@ struct AssignStructuralExpansion<'i> {
     /// [`syn::Fields`] of the enum/struct to be used in this [`AssignStructuralExpansion`].
     variants: Vec<(Option<&'i syn::Ident>, &'i syn::Fields, SkippedFields)>,
 
-    /// Indicator whether this expansion is for an enum.
-    is_enum: bool,
+    /// [`AssignEnumPolicy`] of handling mismatched variants, if this expansion is for an enum.
+    enum_policy: Option<AssignEnumPolicy>,
 }
 
 #[cfg(any(feature = "add_assign", feature = "mul_assign"))]
 impl AssignStructuralExpansion<'_> {
-    /// Generates body of the method implementation for this [`StructuralExpansion`].
+    /// Generates body of the method implementation for this [`AssignStructuralExpansion`].
     fn body(&self) -> TokenStream {
+        // Special case: empty enum.
+        if self.enum_policy.is_some() && self.variants.is_empty() {
+            return quote! { match __rhs {} };
+        }
+
+        let method_name = self.method_ident.to_string();
         let method_path = {
             let trait_ty = &self.trait_ty;
             let method_ident = &self.method_ident;
@@ -85,15 +111,29 @@ impl AssignStructuralExpansion<'_> {
             })
             .collect::<Vec<_>>();
 
-        let wrong_variant_arm = (self.is_enum && match_arms.len() > 1).then(|| {
-            quote! { _ => {} }
-        });
+        let error = quote! { derive_more::WrongVariantError::new(#method_name) };
+        let wrong_variant_arm = (match_arms.len() > 1)
+            .then_some(self.enum_policy)
+            .flatten()
+            .map(|policy| match policy {
+                AssignEnumPolicy::Panic => quote! {
+                    _ => derive_more::core::panic!("{}", #error),
+                },
+                AssignEnumPolicy::Try => quote! {
+                    _ => return derive_more::core::result::Result::Err(
+                        derive_more::BinaryError::Mismatch(#error)
+                    ),
+                },
+            });
+        let ok = matches!(self.enum_policy, Some(AssignEnumPolicy::Try))
+            .then(|| quote! { derive_more::core::result::Result::Ok(()) });
 
         quote! {
             match (self, __rhs) {
                 #( #match_arms )*
                 #wrong_variant_arm
             }
+            #ok
         }
     }
 }
@@ -129,16 +169,39 @@ impl ToTokens for AssignStructuralExpansion<'_> {
 
         let body = self.body();
 
-        quote! {
-            #[allow(private_bounds)]
-            #[automatically_derived]
-            impl #impl_generics derive_more::core::ops:: #trait_ty for #implementor_ty
-                 #where_clause
-            {
-                #[inline]
-                #[track_caller]
-                fn #method_ident(&mut self, __rhs: Self) {
-                    #body
+        if matches!(self.enum_policy, Some(AssignEnumPolicy::Try)) {
+            let try_method_ident = format_ident!("try_{method_ident}");
+            let doc = format!(
+                "Fallible `{trait_ty}::{method_ident}()`, returning an error instead of \
+                 panicking if the enum variants of the operands mismatch.",
+            );
+
+            quote! {
+                #[allow(private_bounds)]
+                #[automatically_derived]
+                impl #impl_generics #implementor_ty #where_clause {
+                    #[doc = #doc]
+                    #[inline]
+                    pub fn #try_method_ident(
+                        &mut self,
+                        __rhs: Self,
+                    ) -> derive_more::core::result::Result<(), derive_more::BinaryError> {
+                        #body
+                    }
+                }
+            }
+        } else {
+            quote! {
+                #[allow(private_bounds)]
+                #[automatically_derived]
+                impl #impl_generics derive_more::core::ops:: #trait_ty for #implementor_ty
+                     #where_clause
+                {
+                    #[inline]
+                    #[track_caller]
+                    fn #method_ident(&mut self, __rhs: Self) {
+                        #body
+                    }
                 }
             }
         }
@@ -146,6 +209,176 @@ impl ToTokens for AssignStructuralExpansion<'_> {
     }
 }
 
+#[cfg(any(feature = "add_assign", feature = "mul_assign"))]
+/// Policy of handling mismatched variants in an [`AssignStructuralExpansion`] for an enum.
+#[derive(Clone, Copy, Debug)]
+enum AssignEnumPolicy {
+    /// Implementing the trait, panicking on mismatched variants.
+    Panic,
+
+    /// Generating a fallible inherent `try_<method>()` method instead of implementing the trait.
+    Try,
+}
+
+#[cfg(any(feature = "add_assign", feature = "mul_assign"))]
+/// Representation of an `AddAssign`-like derive macro enum attribute.
+///
+/// ```rust,ignore
+/// #[<attribute>(panic)]
+/// #[<attribute>(try)]
+/// #[<attribute>(forward, panic)]
+/// #[<attribute>(forward, try)]
+/// ```
+struct AssignEnumAttribute {
+    /// [`Span`] of the `forward` argument, if any.
+    forward: Option<Span>,
+
+    /// Specified [`AssignEnumPolicy`].
+    policy: AssignEnumPolicy,
+}
+
+#[cfg(any(feature = "add_assign", feature = "mul_assign"))]
+impl Parse for AssignEnumAttribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut forward = None;
+        let mut policy = None;
+        loop {
+            let ident = input.call(syn::Ident::parse_any)?;
+            let span = ident.span();
+            let parsed = match ident.to_string().as_str() {
+                "forward" => {
+                    forward = Some(span);
+                    None
+                }
+                "panic" => Some(AssignEnumPolicy::Panic),
+                "try" => Some(AssignEnumPolicy::Try),
+                _ => {
+                    return Err(syn::Error::new(
+                        span,
+                        "unknown attribute argument, expected `panic` or `try`",
+                    ))
+                }
+            };
+            if let Some(parsed) = parsed {
+                if policy.replace(parsed).is_some() {
+                    return Err(syn::Error::new(
+                        span,
+                        "only single `panic` or `try` argument is allowed",
+                    ));
+                }
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            _ = input.parse::<token::Comma>()?;
+            if input.is_empty() {
+                break;
+            }
+        }
+        let policy = policy.ok_or_else(|| {
+            syn::Error::new(
+                input.span(),
+                "either `panic` or `try` argument is expected for enums",
+            )
+        })?;
+        Ok(Self { forward, policy })
+    }
+}
+
+#[cfg(any(feature = "add_assign", feature = "mul_assign"))]
+impl attr::ParseMultiple for AssignEnumAttribute {}
+
+#[cfg(any(feature = "add_assign", feature = "mul_assign"))]
+impl AssignEnumAttribute {
+    /// Parses the [`AssignEnumPolicy`] from an [`AssignEnumAttribute`] placed on the provided
+    /// enum, requiring it to be specified explicitly.
+    ///
+    /// Also, returns the [`Span`] of the `forward` argument, if any.
+    fn parse_policy(
+        input: &syn::DeriveInput,
+        data: &syn::DataEnum,
+        trait_name: &str,
+        attr_name: &syn::Ident,
+    ) -> syn::Result<(AssignEnumPolicy, Option<Span>)> {
+        let attr = Self::parse_attrs(&input.attrs, attr_name)?.ok_or_else(|| {
+            syn::Error::new(
+                data.enum_token.span(),
+                format!(
+                    "`{trait_name}` can be derived for enums only with an explicit \
+                     `#[{attr_name}(panic)]` or `#[{attr_name}(try)]` attribute, specifying \
+                     how to handle mismatched variants",
+                ),
+            )
+        })?;
+        Ok((attr.policy, attr.forward))
+    }
+}
+
+#[cfg(any(feature = "add_assign", feature = "mul_assign"))]
+/// Collects the variants of the provided enum along with their [`SkippedFields`] to be used in an
+/// [`AssignStructuralExpansion`].
+fn enum_variants<'i>(
+    data: &'i syn::DataEnum,
+    trait_name: &str,
+    attr_name: &syn::Ident,
+) -> syn::Result<Vec<(Option<&'i syn::Ident>, &'i syn::Fields, SkippedFields)>> {
+    let mut variants = vec![];
+    for variant in &data.variants {
+        if let Some(skip) = attr::Skip::parse_attrs(&variant.attrs, attr_name)? {
+            return Err(syn::Error::new(
+                skip.span,
+                format!(
+                    "`#[{attr_name}({})]` attribute can be placed only on variant fields",
+                    skip.item.name(),
+                ),
+            ));
+        }
+        let mut skipped_fields = SkippedFields::default();
+        for (n, field) in variant.fields.iter().enumerate() {
+            if attr::Skip::parse_attrs(&field.attrs, attr_name)?.is_some() {
+                _ = skipped_fields.insert(n);
+            }
+        }
+        if !matches!(variant.fields, syn::Fields::Unit)
+            && variant.fields.len() == skipped_fields.len()
+        {
+            return Err(syn::Error::new(
+                variant.span(),
+                format!(
+                    "`{trait_name}` cannot be derived for enum with all the fields being \
+                     skipped in its variants",
+                ),
+            ));
+        }
+        variants.push((Some(&variant.ident), &variant.fields, skipped_fields));
+    }
+    Ok(variants)
+}
+
+#[cfg(any(feature = "mul", feature = "mul_assign"))]
+/// Extracts the shift amount types from the provided `#[<attr_name>(<types>)]` attribute (spanned
+/// with the provided [`Span`]) of a [`ops::Shl`]-like derive macro, ensuring at least one is
+/// specified.
+///
+/// [`ops::Shl`]: std::ops::Shl
+fn shift_types(
+    types: attr::Types,
+    span: Span,
+    attr_name: &syn::Ident,
+) -> syn::Result<Vec<syn::Type>> {
+    if types.0.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "at least one shift amount type is expected in `#[{attr_name}(...)]` \
+                 attribute",
+            ),
+        ));
+    }
+    Ok(types.0.into_iter().collect())
+}
+
 #[cfg(any(feature = "add", feature = "mul"))]
 /// Expansion of a macro for generating a structural trait implementation with a `self` method
 /// receiver for an enum or a struct.
@@ -168,19 +401,38 @@ struct StructuralExpansion<'i> {
     /// [`syn::Fields`] of the enum/struct to be used in this [`StructuralExpansion`].
     variants: Vec<(Option<&'i syn::Ident>, &'i syn::Fields, SkippedFields)>,
 
-    /// Indicator whether this expansion is for an enum.
-    is_enum: bool,
+    /// [`EnumOutput`] policy of handling failures, if this expansion is for an enum.
+    enum_output: Option<EnumOutput>,
+
+    /// Indicator whether the trait should be additionally implemented for references.
+    by_ref: bool,
+
+    /// Indicator whether the trait implementation should delegate to the inherent `const fn`
+    /// generated by a [`ConstExpansion`].
+    is_const: bool,
 }
 
 #[cfg(any(feature = "add", feature = "mul"))]
 impl StructuralExpansion<'_> {
     /// Generates body of the method implementation for this [`StructuralExpansion`].
-    fn body(&self) -> TokenStream {
+    ///
+    /// If `by_ref` is `true`, then the body is generated for the implementation with both `self`
+    /// and `__rhs` being references.
+    fn body(&self, by_ref: bool) -> TokenStream {
         // TODO: Try remove once MSRV is bumped up.
         // Special case: empty enum.
-        if self.is_enum && self.variants.is_empty() {
-            return quote! { match self {} };
+        if self.enum_output.is_some() && self.variants.is_empty() {
+            return if by_ref {
+                quote! { match *self {} }
+            } else {
+                quote! { match self {} }
+            };
         }
+        let self_path = if by_ref {
+            self.self_ty.0.to_token_stream()
+        } else {
+            quote! { Self }
+        };
 
         let method_name = self.method_ident.to_string();
         let method_path = {
@@ -198,34 +450,55 @@ impl StructuralExpansion<'_> {
                 let self_pat = all_fields.exhaustive_arm_pattern("__self_");
                 let rhs_pat = all_fields.exhaustive_arm_pattern("__rhs_");
 
-                let expr = if matches!(all_fields, syn::Fields::Unit) {
-                    quote! {
+                let expr = match (self.enum_output, all_fields) {
+                    (Some(EnumOutput::Result), syn::Fields::Unit) => quote! {
                         derive_more::core::result::Result::Err(derive_more::BinaryError::Unit(
                             derive_more::UnitError::new(#method_name)
                         ))
-                    }
-                } else {
-                    let fields_expr = all_fields.arm_expr(&method_path, skipped_fields);
-                    if self.is_enum {
-                        quote! { derive_more::core::result::Result::Ok(Self #variant #fields_expr) }
-                    } else {
-                        quote! { Self #variant #fields_expr }
+                    },
+                    (output, _) => {
+                        let fields_expr =
+                            all_fields.arm_expr(&method_path, skipped_fields, by_ref);
+                        let value = quote! { #self_path #variant #fields_expr };
+                        match output {
+                            Some(EnumOutput::Result) => {
+                                quote! { derive_more::core::result::Result::Ok(#value) }
+                            }
+                            Some(EnumOutput::Option) => {
+                                quote! { derive_more::core::option::Option::Some(#value) }
+                            }
+                            Some(EnumOutput::Panic) | None => value,
+                        }
                     }
                 };
 
                 quote! {
-                    (Self #variant #self_pat, Self #variant #rhs_pat) => #expr,
+                    (#self_path #variant #self_pat, #self_path #variant #rhs_pat) => #expr,
                 }
             })
             .collect::<Vec<_>>();
 
-        let wrong_variant_arm = (self.is_enum && match_arms.len() > 1).then(|| {
-            quote! {
-                _ => derive_more::core::result::Result::Err(derive_more::BinaryError::Mismatch(
-                    derive_more::WrongVariantError::new(#method_name)
-                )),
-            }
-        });
+        let wrong_variant_arm = (match_arms.len() > 1)
+            .then_some(self.enum_output)
+            .flatten()
+            .map(|output| {
+                let error =
+                    quote! { derive_more::WrongVariantError::new(#method_name) };
+                let expr = match output {
+                    EnumOutput::Result => quote! {
+                        derive_more::core::result::Result::Err(
+                            derive_more::BinaryError::Mismatch(#error)
+                        )
+                    },
+                    EnumOutput::Panic => {
+                        quote! { derive_more::core::panic!("{}", #error) }
+                    }
+                    EnumOutput::Option => {
+                        quote! { derive_more::core::option::Option::None }
+                    }
+                };
+                quote! { _ => #expr, }
+            });
 
         quote! {
             match (self, __rhs) {
@@ -247,31 +520,62 @@ impl ToTokens for StructuralExpansion<'_> {
         let implementor_ty: syn::Type = parse_quote! { #ty #ty_generics };
         let self_ty: syn::Type = parse_quote! { Self };
 
-        let output_ty = if self.is_enum {
-            parse_quote! { derive_more::core::result::Result<#self_ty, derive_more::BinaryError> }
-        } else {
-            self_ty.clone()
+        let output_ty = |self_ty: &syn::Type| -> syn::Type {
+            match self.enum_output {
+                Some(EnumOutput::Result) => parse_quote! {
+                    derive_more::core::result::Result<#self_ty, derive_more::BinaryError>
+                },
+                Some(EnumOutput::Option) => {
+                    parse_quote! { derive_more::core::option::Option<#self_ty> }
+                }
+                Some(EnumOutput::Panic) | None => self_ty.clone(),
+            }
         };
 
         let generics_search = GenericsSearch::from(self.self_ty.1);
         let mut generics = self.self_ty.1.clone();
+        let mut ref_generics = self.self_ty.1.clone();
         for (_, all_fields, skipped_fields) in &self.variants {
-            for field_ty in all_fields.iter().enumerate().filter_map(|(n, field)| {
-                (!skipped_fields.contains(&n)).then_some(&field.ty)
-            }) {
-                if generics_search.any_in(field_ty)
-                    && !field_ty.contains_type_structurally(&self_ty)
-                    && !field_ty.contains_type_structurally(&implementor_ty)
+            for (n, field) in all_fields.iter().enumerate() {
+                let field_ty = &field.ty;
+                if !generics_search.any_in(field_ty)
+                    || field_ty.contains_type_structurally(&self_ty)
+                    || field_ty.contains_type_structurally(&implementor_ty)
                 {
+                    continue;
+                }
+                if skipped_fields.contains(&n) {
+                    ref_generics
+                        .make_where_clause()
+                        .predicates
+                        .push(parse_quote! {
+                            #field_ty: derive_more::core::clone::Clone
+                        });
+                } else {
                     generics.make_where_clause().predicates.push(parse_quote! {
                         #field_ty: derive_more::core::ops:: #trait_ty <Output = #field_ty>
                     });
+                    ref_generics
+                        .make_where_clause()
+                        .predicates
+                        .push(parse_quote! {
+                            for<'__derive_more_a> &'__derive_more_a #field_ty:
+                                derive_more::core::ops:: #trait_ty <
+                                    &'__derive_more_a #field_ty,
+                                    Output = #field_ty,
+                                >
+                        });
                 }
             }
         }
         let (impl_generics, _, where_clause) = generics.split_for_impl();
 
-        let body = self.body();
+        let output = output_ty(&self_ty);
+        let body = if self.is_const {
+            quote! { Self::#method_ident(self, __rhs) }
+        } else {
+            self.body(false)
+        };
 
         quote! {
             #[allow(private_bounds)]
@@ -279,7 +583,7 @@ impl ToTokens for StructuralExpansion<'_> {
             impl #impl_generics derive_more::core::ops:: #trait_ty for #implementor_ty
                  #where_clause
             {
-                type Output = #output_ty;
+                type Output = #output;
 
                 #[inline]
                 #[track_caller]
@@ -289,6 +593,126 @@ impl ToTokens for StructuralExpansion<'_> {
             }
         }
         .to_tokens(tokens);
+
+        if !self.by_ref {
+            return;
+        }
+
+        let output = output_ty(&implementor_ty);
+        let body = self.body(true);
+        let lhs_lt: syn::Lifetime = parse_quote! { '__derive_more_lhs };
+        let rhs_lt: syn::Lifetime = parse_quote! { '__derive_more_rhs };
+        let with_lifetimes = |lifetimes: &[&syn::Lifetime]| {
+            let mut generics = ref_generics.clone();
+            for lt in lifetimes.iter().rev() {
+                generics.params.insert(0, parse_quote! { #lt });
+            }
+            generics
+        };
+        let ref_ref_generics = with_lifetimes(&[&lhs_lt, &rhs_lt]);
+        let (ref_ref_impl_generics, _, ref_where_clause) =
+            ref_ref_generics.split_for_impl();
+        let val_ref_generics = with_lifetimes(&[&rhs_lt]);
+        let (val_ref_impl_generics, _, _) = val_ref_generics.split_for_impl();
+        let ref_val_generics = with_lifetimes(&[&lhs_lt]);
+        let (ref_val_impl_generics, _, _) = ref_val_generics.split_for_impl();
+
+        quote! {
+            #[allow(private_bounds)]
+            #[automatically_derived]
+            impl #ref_ref_impl_generics derive_more::core::ops:: #trait_ty<&#rhs_lt #implementor_ty>
+                 for &#lhs_lt #implementor_ty #ref_where_clause
+            {
+                type Output = #output;
+
+                #[inline]
+                #[track_caller]
+                fn #method_ident(self, __rhs: &#rhs_lt #implementor_ty) -> Self::Output {
+                    #body
+                }
+            }
+
+            #[allow(private_bounds)]
+            #[automatically_derived]
+            impl #val_ref_impl_generics derive_more::core::ops:: #trait_ty<&#rhs_lt #implementor_ty>
+                 for #implementor_ty #ref_where_clause
+            {
+                type Output = #output;
+
+                #[inline]
+                #[track_caller]
+                fn #method_ident(self, __rhs: &#rhs_lt #implementor_ty) -> Self::Output {
+                    derive_more::core::ops:: #trait_ty :: #method_ident(&self, __rhs)
+                }
+            }
+
+            #[allow(private_bounds)]
+            #[automatically_derived]
+            impl #ref_val_impl_generics derive_more::core::ops:: #trait_ty<#implementor_ty>
+                 for &#lhs_lt #implementor_ty #ref_where_clause
+            {
+                type Output = #output;
+
+                #[inline]
+                #[track_caller]
+                fn #method_ident(self, __rhs: #implementor_ty) -> Self::Output {
+                    derive_more::core::ops:: #trait_ty :: #method_ident(self, &__rhs)
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+#[cfg(any(feature = "add", feature = "mul"))]
+/// Policy of handling failures (mismatched or unit variants) in a [`StructuralExpansion`] for an
+/// enum, determining its `Output` type.
+///
+/// ```rust,ignore
+/// #[<attribute>(output = result)]
+/// #[<attribute>(output = panic)]
+/// #[<attribute>(output = option)]
+/// ```
+#[cfg_attr(not(feature = "add"), allow(dead_code))] // only `Result` is used by `Mul`-like derives
+#[derive(Clone, Copy, Debug, Default)]
+enum EnumOutput {
+    /// Returning a [`Result`] with a `derive_more::BinaryError`.
+    #[default]
+    Result,
+
+    /// Returning `Self` directly, panicking on mismatched variants.
+    ///
+    /// Unit variants of the same kind result in themselves.
+    Panic,
+
+    /// Returning an [`Option`], being [`None`] on mismatched variants.
+    ///
+    /// Unit variants of the same kind result in themselves.
+    Option,
+}
+
+#[cfg(feature = "add")]
+impl Parse for EnumOutput {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let path = input.parse::<syn::Path>()?;
+        if !path.is_ident("output") {
+            return Err(syn::Error::new(
+                path.span(),
+                "unknown attribute argument, expected `forward`, `ref`, `const` or `output = ...`",
+            ));
+        }
+        _ = input.parse::<token::Eq>()?;
+
+        let policy = input.parse::<syn::Ident>()?;
+        match policy.to_string().as_str() {
+            "result" => Ok(Self::Result),
+            "panic" => Ok(Self::Panic),
+            "option" => Ok(Self::Option),
+            _ => Err(syn::Error::new(
+                policy.span(),
+                "unknown output policy, expected one of: `result`, `panic`, `option`",
+            )),
+        }
     }
 }
 
@@ -297,10 +721,14 @@ impl ToTokens for StructuralExpansion<'_> {
 trait StructuralExpansionFieldsExt {
     /// Generates a resulting expression with these [`syn::Fields`] in a matched arm of a `match`
     /// expression, by applying the specified method.
+    ///
+    /// If `by_ref` is `true`, then the matched values are references, so the skipped fields are
+    /// cloned.
     fn arm_expr(
         &self,
         method: &syn::Path,
         skipped_indices: &SkippedFields,
+        by_ref: bool,
     ) -> TokenStream;
 }
 
@@ -310,13 +738,22 @@ impl StructuralExpansionFieldsExt for syn::Fields {
         &self,
         method_path: &syn::Path,
         skipped_indices: &SkippedFields,
+        by_ref: bool,
     ) -> TokenStream {
+        let skipped = |self_val: syn::Ident| {
+            if by_ref {
+                quote! { derive_more::core::clone::Clone::clone(#self_val) }
+            } else {
+                self_val.into_token_stream()
+            }
+        };
         match self {
             Self::Named(fields) => {
                 let fields = fields.named.iter().enumerate().map(|(num, field)| {
                     let name = &field.ident;
                     let self_val = format_ident!("__self_{num}");
                     if skipped_indices.contains(&num) {
+                        let self_val = skipped(self_val);
                         quote! { #name: #self_val }
                     } else {
                         let rhs_val = format_ident!("__rhs_{num}");
@@ -329,7 +766,7 @@ impl StructuralExpansionFieldsExt for syn::Fields {
                 let fields = (0..fields.unnamed.len()).map(|num| {
                     let self_val = format_ident!("__self_{num}");
                     if skipped_indices.contains(&num) {
-                        quote! { #self_val }
+                        skipped(self_val)
                     } else {
                         let rhs_val = format_ident!("__rhs_{num}");
                         quote! { #method_path(#self_val, #rhs_val) }
@@ -341,3 +778,456 @@ impl StructuralExpansionFieldsExt for syn::Fields {
         }
     }
 }
+
+#[cfg(any(feature = "add", feature = "mul"))]
+/// Representation of an attribute specifying the `Rhs` and `Output` types of an additional trait
+/// implementation for a single-field struct.
+///
+/// ```rust,ignore
+/// #[<attribute>(<rhs>, output = <output>)]
+/// ```
+struct TypedAttribute {
+    /// `Rhs` type of the trait implementation.
+    rhs: syn::TypePath,
+
+    /// `Output` type of the trait implementation.
+    output: syn::TypePath,
+}
+
+#[cfg(any(feature = "add", feature = "mul"))]
+impl TypedAttribute {
+    /// Splits the provided [`syn::Attribute`]s into the parsed [`TypedAttribute`]s with the
+    /// provided `attr_name` and all the other ones.
+    ///
+    /// [`TypedAttribute`]s are distinguished by their `<type>, output = ...` form, so any other
+    /// attribute with the same `attr_name` (like `#[<attribute>(forward)]`) is left for further
+    /// parsing.
+    fn split_attrs(
+        attrs: &[syn::Attribute],
+        attr_name: &syn::Ident,
+    ) -> syn::Result<(Vec<Spanning<Self>>, Vec<syn::Attribute>)> {
+        use syn::parse::Parser as _;
+
+        let mut typed = vec![];
+        let mut rest = vec![];
+        for attr in attrs {
+            let is_typed = match &attr.meta {
+                syn::Meta::List(list) if attr.path().is_ident(attr_name) => {
+                    (|input: ParseStream<'_>| {
+                        let is_typed = input.parse::<syn::Type>().is_ok()
+                            && input.parse::<token::Comma>().is_ok()
+                            && input
+                                .parse::<syn::Path>()
+                                .is_ok_and(|path| path.is_ident("output"))
+                            && input.peek(token::Eq);
+                        _ = input.parse::<TokenStream>()?;
+                        Ok(is_typed)
+                    })
+                    .parse2(list.tokens.clone())
+                    .unwrap_or_default()
+                }
+                _ => false,
+            };
+            if is_typed {
+                typed.push(Spanning::new(attr.parse_args()?, attr.path().span()));
+            } else {
+                rest.push(attr.clone());
+            }
+        }
+        Ok((typed, rest))
+    }
+}
+
+#[cfg(any(feature = "add", feature = "mul"))]
+impl Parse for TypedAttribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let rhs = match input.parse()? {
+            syn::Type::Path(ty) if ty.qself.is_none() => ty,
+            ty => {
+                return Err(syn::Error::new(
+                    ty.span(),
+                    "`<rhs>` type is expected to be a path, like `Meters` or `f64`",
+                ))
+            }
+        };
+        _ = input.parse::<token::Comma>()?;
+
+        let path = input.parse::<syn::Path>()?;
+        if !path.is_ident("output") {
+            return Err(syn::Error::new(
+                path.span(),
+                "unknown attribute argument, expected `output = ...`",
+            ));
+        }
+        _ = input.parse::<token::Eq>()?;
+        let output = match input.parse()? {
+            syn::Type::Path(ty) if ty.qself.is_none() => ty,
+            ty => return Err(syn::Error::new(
+                ty.span(),
+                "`output` type is expected to be a path, like `SquareMeters` or `f64`",
+            )),
+        };
+        _ = input.parse::<Option<token::Comma>>()?;
+
+        Ok(Self { rhs, output })
+    }
+}
+
+#[cfg(any(feature = "add", feature = "mul"))]
+/// Builds [`TypedExpansion`]s for the provided [`TypedAttribute`]s placed on the `input`.
+///
+/// # Errors
+///
+/// If any [`TypedAttribute`] is placed on something other than a single-field struct.
+fn typed_expansions<'i>(
+    input: &'i syn::DeriveInput,
+    trait_name: &str,
+    method_name: &str,
+    attr_name: &syn::Ident,
+    attrs: Vec<Spanning<TypedAttribute>>,
+) -> syn::Result<Vec<TypedExpansion<'i>>> {
+    let Some(first) = attrs.first() else {
+        return Ok(vec![]);
+    };
+    let member = match &input.data {
+        syn::Data::Struct(data) if data.fields.len() == 1 => data
+            .fields
+            .iter()
+            .next()
+            .and_then(|field| field.ident.clone())
+            .map_or_else(|| syn::Member::from(0), syn::Member::from),
+        _ => {
+            return Err(syn::Error::new(
+                first.span,
+                format!(
+                    "`#[{attr_name}(<rhs>, output = <output>)]` attribute can be placed only on \
+                     structs with a single field",
+                ),
+            ))
+        }
+    };
+
+    Ok(attrs
+        .into_iter()
+        .map(|attr| TypedExpansion {
+            trait_ty: format_ident!("{trait_name}"),
+            method_ident: format_ident!("{method_name}"),
+            self_ty: (&input.ident, &input.generics),
+            member: member.clone(),
+            attr: attr.into_inner(),
+        })
+        .collect())
+}
+
+#[cfg(any(feature = "add", feature = "mul"))]
+/// Expansion of a macro for generating a trait implementation with the `Rhs` and `Output` types
+/// specified by a [`TypedAttribute`] for a single-field struct.
+struct TypedExpansion<'i> {
+    /// [`syn::Ident`] of the implemented trait.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    trait_ty: syn::Ident,
+
+    /// [`syn::Ident`] of the implemented method in trait.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    method_ident: syn::Ident,
+
+    /// [`syn::Ident`] and [`syn::Generics`] of the implementor struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    self_ty: (&'i syn::Ident, &'i syn::Generics),
+
+    /// [`syn::Member`] of the single field of the implementor struct.
+    member: syn::Member,
+
+    /// [`TypedAttribute`] specifying the `Rhs` and `Output` types.
+    attr: TypedAttribute,
+}
+
+#[cfg(any(feature = "add", feature = "mul"))]
+impl TypedExpansion<'_> {
+    /// Checks whether this [`TypedExpansion`] has the implementor struct as its `Rhs` type, thus
+    /// replacing the default trait implementation.
+    fn is_self_rhs(&self) -> bool {
+        self.is_self(&self.attr.rhs.path)
+    }
+
+    /// Checks whether the provided [`syn::Path`] refers to the implementor struct.
+    fn is_self(&self, path: &syn::Path) -> bool {
+        path.segments.len() == 1
+            && (path.segments[0].ident == "Self"
+                || path.segments[0].ident == *self.self_ty.0)
+    }
+}
+
+#[cfg(any(feature = "add", feature = "mul"))]
+impl ToTokens for TypedExpansion<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let trait_ty = &self.trait_ty;
+        let method_ident = &self.method_ident;
+        let member = &self.member;
+        let TypedAttribute { rhs, output } = &self.attr;
+
+        let ty = self.self_ty.0;
+        let (impl_generics, ty_generics, where_clause) =
+            self.self_ty.1.split_for_impl();
+
+        // Any other type than the implementor struct or a primitive numeric one is expected to be
+        // a tuple newtype, so it's destructured and constructed explicitly, making the compiler
+        // to point at the attribute with a clear error otherwise.
+        let constructor = |ty: &syn::TypePath| {
+            let mut path = ty.path.clone();
+            for segment in &mut path.segments {
+                if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments
+                {
+                    args.colon2_token = Some(token::PathSep::default());
+                }
+            }
+            path
+        };
+
+        let rhs_value = if self.is_self_rhs() {
+            quote! { __rhs.#member }
+        } else if Primitive::of_path(rhs).is_some_and(Primitive::is_numeric) {
+            quote! { __rhs }
+        } else {
+            let rhs_constructor = constructor(rhs);
+            quote_spanned! { rhs.span() =>
+                { let #rhs_constructor(__rhs) = __rhs; __rhs }
+            }
+        };
+        let value = quote! {
+            derive_more::core::ops::#trait_ty::#method_ident(self.#member, #rhs_value)
+        };
+        let body = if self.is_self(&output.path) {
+            quote! { Self { #member: #value } }
+        } else if Primitive::of_path(output).is_some_and(Primitive::is_numeric) {
+            value
+        } else {
+            let output_constructor = constructor(output);
+            quote_spanned! { output.span() => #output_constructor(#value) }
+        };
+
+        quote! {
+            #[allow(deprecated)] // omit warnings on deprecated fields
+            #[automatically_derived]
+            impl #impl_generics derive_more::core::ops:: #trait_ty<#rhs> for #ty #ty_generics
+                 #where_clause
+            {
+                type Output = #output;
+
+                #[inline]
+                #[track_caller]
+                fn #method_ident(self, __rhs: #rhs) -> Self::Output {
+                    #body
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+#[cfg(any(feature = "add", feature = "mul"))]
+/// Expansion of a macro for generating an inherent `const fn` equivalent of an [`ops::Add`]-like
+/// or an [`ops::Mul`]-like trait method for a struct with primitive numeric fields.
+///
+/// [`ops::Add`]: std::ops::Add
+/// [`ops::Mul`]: std::ops::Mul
+struct ConstExpansion<'i> {
+    /// [`syn::Ident`] of the implemented trait.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    trait_ty: syn::Ident,
+
+    /// [`syn::Ident`] of the generated method, named the same as the one in trait.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    method_ident: syn::Ident,
+
+    /// [`syn::Ident`] and [`syn::Generics`] of the implementor struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    self_ty: (&'i syn::Ident, &'i syn::Generics),
+
+    /// [`syn::Fields`] of the struct to be used in this [`ConstExpansion`].
+    fields: &'i syn::Fields,
+
+    /// Indices of the struct [`syn::Fields`] marked with an [`attr::Skip`].
+    skipped_fields: SkippedFields,
+
+    /// Type of the scalar right-hand side operand, if any.
+    ///
+    /// If [`None`], then the right-hand side operand is the struct itself, applied field-wise.
+    scalar_ty: Option<&'i syn::Type>,
+}
+
+#[cfg(any(feature = "add", feature = "mul"))]
+impl<'i> ConstExpansion<'i> {
+    /// Creates a new [`ConstExpansion`] for the provided `input`, checking that all its non-skipped
+    /// fields are of primitive integer types.
+    ///
+    /// If `scalar` is `true`, then all these fields are additionally checked to be of the same
+    /// type, used as the right-hand side operand. Otherwise, the skipped fields are checked to
+    /// have no drop glue, as the right-hand side operand is dropped in the `const fn`.
+    fn new(
+        input: &'i syn::DeriveInput,
+        trait_name: &str,
+        method_name: &str,
+        attr_name: &syn::Ident,
+        span: Span,
+        skipped_fields: SkippedFields,
+        scalar: bool,
+    ) -> syn::Result<Self> {
+        let syn::Data::Struct(data) = &input.data else {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "`#[{attr_name}(const)]` attribute can be placed only on structs"
+                ),
+            ));
+        };
+
+        let mut scalar_ty = None;
+        for (n, field) in data.fields.iter().enumerate() {
+            if skipped_fields.contains(&n) {
+                // Values with drop glue cannot be dropped in `const fn`, so only the types known
+                // not to have it are allowed.
+                let is_phantom = match &field.ty {
+                    syn::Type::Path(ty) => ty
+                        .path
+                        .segments
+                        .last()
+                        .is_some_and(|s| s.ident == "PhantomData"),
+                    _ => false,
+                };
+                if !scalar && !is_phantom && Primitive::of(&field.ty).is_none() {
+                    return Err(syn::Error::new(
+                        field.ty.span(),
+                        format!(
+                            "`#[{attr_name}(const)]` attribute requires all the skipped fields \
+                             to be of `bool`, primitive numeric or `PhantomData` types",
+                        ),
+                    ));
+                }
+                continue;
+            }
+            // Floating-point arithmetic is allowed in `const fn` only since Rust 1.82, which is
+            // above the MSRV.
+            if !Primitive::of(&field.ty).is_some_and(Primitive::is_integer) {
+                return Err(syn::Error::new(
+                    field.ty.span(),
+                    format!(
+                        "`#[{attr_name}(const)]` attribute requires all the fields to be of \
+                         primitive integer types",
+                    ),
+                ));
+            }
+            if scalar {
+                match scalar_ty {
+                    None => scalar_ty = Some(&field.ty),
+                    Some(ty) if *ty == field.ty => {}
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            field.ty.span(),
+                            format!(
+                                "`#[{attr_name}(const)]` attribute requires all the fields to be \
+                                 of the same type, used as the right-hand side operand",
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            trait_ty: format_ident!("{trait_name}"),
+            method_ident: format_ident!("{method_name}"),
+            self_ty: (&input.ident, &input.generics),
+            fields: &data.fields,
+            skipped_fields,
+            scalar_ty,
+        })
+    }
+
+    /// Returns the binary operator corresponding to the implemented trait.
+    fn operator(&self) -> TokenStream {
+        match self.trait_ty.to_string().as_str() {
+            "Add" => quote! { + },
+            "BitAnd" => quote! { & },
+            "BitOr" => quote! { | },
+            "BitXor" => quote! { ^ },
+            "Div" => quote! { / },
+            "Mul" => quote! { * },
+            "Rem" => quote! { % },
+            "Shl" => quote! { << },
+            "Shr" => quote! { >> },
+            "Sub" => quote! { - },
+            _ => unimplemented!(),
+        }
+    }
+}
+
+#[cfg(any(feature = "add", feature = "mul"))]
+impl ToTokens for ConstExpansion<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let method_ident = &self.method_ident;
+        let operator = self.operator();
+
+        let ty = self.self_ty.0;
+        let (impl_generics, ty_generics, where_clause) =
+            self.self_ty.1.split_for_impl();
+
+        let rhs_ty = self
+            .scalar_ty
+            .map_or_else(|| quote! { Self }, ToTokens::into_token_stream);
+        let self_pat = self.fields.exhaustive_arm_pattern("__self_");
+        let rhs_destruct = self.scalar_ty.is_none().then(|| {
+            let rhs_pat = self
+                .fields
+                .non_exhaustive_arm_pattern("__rhs_", &self.skipped_fields);
+            quote! { let Self #rhs_pat = __rhs; }
+        });
+        let fields_exprs = self.fields.iter().enumerate().map(|(num, field)| {
+            let self_val = format_ident!("__self_{num}");
+            let expr = if self.skipped_fields.contains(&num) {
+                quote! { #self_val }
+            } else if self.scalar_ty.is_some() {
+                quote! { #self_val #operator __rhs }
+            } else {
+                let rhs_val = format_ident!("__rhs_{num}");
+                quote! { #self_val #operator #rhs_val }
+            };
+            match &field.ident {
+                Some(name) => quote! { #name: #expr },
+                None => expr,
+            }
+        });
+        let value = match self.fields {
+            syn::Fields::Named(_) => quote! { Self { #( #fields_exprs , )* } },
+            syn::Fields::Unnamed(_) => quote! { Self( #( #fields_exprs , )* ) },
+            syn::Fields::Unit => quote! { Self },
+        };
+        let doc = format!(
+            "`const` equivalent of the `{}` trait implementation.",
+            self.trait_ty,
+        );
+
+        quote! {
+            #[allow(deprecated)] // omit warnings on deprecated fields
+            #[automatically_derived]
+            impl #impl_generics #ty #ty_generics #where_clause {
+                #[doc = #doc]
+                #[inline]
+                #[must_use]
+                #[track_caller]
+                pub const fn #method_ident(self, __rhs: #rhs_ty) -> Self {
+                    let Self #self_pat = self;
+                    #rhs_destruct
+                    #value
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
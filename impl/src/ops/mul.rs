@@ -3,15 +3,26 @@
 #[cfg(doc)]
 use std::ops;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
-use syn::{parse_quote, spanned::Spanned as _};
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    token,
+};
 
-use super::{SkippedFields, StructuralExpansion};
+use super::{
+    shift_types, typed_expansions, ConstExpansion, EnumOutput, SkippedFields,
+    StructuralExpansion, TypedAttribute, TypedExpansion,
+};
 use crate::utils::{
     attr::{self, ParseMultiple as _},
     pattern_matching::FieldsExt as _,
     structural_inclusion::TypeExt as _,
+    Either, Spanning,
 };
 
 /// Expands an [`ops::Mul`]-like derive macro.
@@ -38,13 +49,191 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenSt
             format!("`{trait_name}` cannot be derived for unions"),
         )),
         _ => {
-            if attr::Forward::parse_attrs(&input.attrs, &attr_name)?.is_some() {
-                expand_structural(input, trait_name, attr_name)
-                    .map(ToTokens::into_token_stream)
+            let (typed_attrs, attrs) =
+                TypedAttribute::split_attrs(&input.attrs, &attr_name)?;
+            let first_typed_span = typed_attrs.first().map(|attr| attr.span);
+            let typed = typed_expansions(
+                input,
+                trait_name,
+                trait_name_to_method_name(trait_name),
+                &attr_name,
+                typed_attrs,
+            )?;
+
+            let (container, shift_types) = if matches!(trait_name, "Shl" | "Shr") {
+                match Either::<ContainerAttribute, attr::Types>::parse_attrs(
+                    &attrs, &attr_name,
+                )? {
+                    Some(Spanning {
+                        item: Either::Left(container),
+                        ..
+                    }) => (container, None),
+                    Some(Spanning {
+                        item: Either::Right(types),
+                        span,
+                    }) => (
+                        ContainerAttribute::default(),
+                        Some(shift_types(types, span, &attr_name)?),
+                    ),
+                    None => (ContainerAttribute::default(), None),
+                }
             } else {
-                expand_scalar(input, trait_name, attr_name)
-                    .map(ToTokens::into_token_stream)
+                let container = ContainerAttribute::parse_attrs(&attrs, &attr_name)?
+                    .map(Spanning::into_inner)
+                    .unwrap_or_default();
+                (container, None)
+            };
+            let by_ref = container.by_ref.is_some();
+            let commutative = match container.commutative {
+                Some(types) if trait_name != "Mul" => {
+                    return Err(syn::Error::new(
+                        types.span,
+                        "`commutative(...)` argument is allowed only in `#[mul(...)]` \
+                         attribute",
+                    ));
+                }
+                Some(types) if container.forward.is_some() => {
+                    return Err(syn::Error::new(
+                        types.span,
+                        "`commutative(...)` argument cannot be combined with `forward`",
+                    ));
+                }
+                Some(types) => Some(CommutativeExpansion {
+                    self_ty: (&input.ident, &input.generics),
+                    types: types.into_inner(),
+                }),
+                None => None,
+            };
+
+            if let Some(span) = container.no_scalar {
+                let conflicting = [
+                    (container.forward, "forward"),
+                    (container.by_ref, "ref"),
+                    (container.is_const, "const"),
+                ]
+                .into_iter()
+                .find_map(|(arg, name)| arg.map(|_| name));
+                if let Some(name) = conflicting {
+                    return Err(syn::Error::new(
+                        span,
+                        format!(
+                            "`no_scalar` argument cannot be combined with `{name}`"
+                        ),
+                    ));
+                }
+                if typed.is_empty() {
+                    return Err(syn::Error::new(
+                        span,
+                        format!(
+                            "`no_scalar` argument is expected to be used along with \
+                             `#[{attr_name}(<rhs>, output = <output>)]` attributes",
+                        ),
+                    ));
+                }
+            } else if let (Some(span), None, None) =
+                (first_typed_span, container.forward, &shift_types)
+            {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "`#[{attr_name}(<rhs>, output = <output>)]` attribute conflicts with \
+                         the scalar `{trait_name}` implementation, generic over `Rhs`, so \
+                         it's expected to be opted out explicitly via \
+                         `#[{attr_name}(no_scalar)]`, or replaced with the structural one via \
+                         `#[{attr_name}(forward)]`",
+                    ),
+                ));
+            }
+            if let (Some(span), false) = (container.is_const, typed.is_empty()) {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "`const` argument cannot be combined with \
+                         `#[{attr_name}(<rhs>, output = <output>)]` attributes",
+                    ),
+                ));
             }
+            let method_name = trait_name_to_method_name(trait_name);
+
+            let untyped = if container.forward.is_some() {
+                let mut structural =
+                    expand_structural(input, trait_name, attr_name.clone(), by_ref)?;
+                if typed.iter().any(TypedExpansion::is_self_rhs) {
+                    TokenStream::new()
+                } else {
+                    let const_fn = container
+                        .is_const
+                        .map(|span| {
+                            let skipped_fields = structural
+                                .variants
+                                .first()
+                                .map(|(_, _, skipped)| skipped.clone())
+                                .unwrap_or_default();
+                            ConstExpansion::new(
+                                input,
+                                trait_name,
+                                method_name,
+                                &attr_name,
+                                span,
+                                skipped_fields,
+                                false,
+                            )
+                        })
+                        .transpose()?;
+                    structural.is_const = const_fn.is_some();
+                    quote! {
+                        #const_fn
+                        #structural
+                    }
+                }
+            } else if let Some(shift_types) = shift_types {
+                let mut expansions = TokenStream::new();
+                for rhs_ty in shift_types {
+                    expand_scalar(
+                        input,
+                        trait_name,
+                        attr_name.clone(),
+                        by_ref,
+                        Some(rhs_ty),
+                    )?
+                    .to_tokens(&mut expansions);
+                }
+                expansions
+            } else if container.no_scalar.is_none() {
+                let mut scalar =
+                    expand_scalar(input, trait_name, attr_name.clone(), by_ref, None)?;
+                let const_fn = container
+                    .is_const
+                    .map(|span| {
+                        ConstExpansion::new(
+                            input,
+                            trait_name,
+                            method_name,
+                            &attr_name,
+                            span,
+                            scalar.skipped_fields.clone(),
+                            true,
+                        )
+                    })
+                    .transpose()?;
+                if let Some(const_fn) = &const_fn {
+                    scalar.rhs_ty = const_fn.scalar_ty.cloned();
+                    scalar.is_const = true;
+                }
+                quote! {
+                    #const_fn
+                    #scalar
+                }
+            } else {
+                // Scalar implementation is opted out via `no_scalar` argument.
+                TokenStream::new()
+            };
+
+            Ok(quote! {
+                #untyped
+                #( #typed )*
+                #commutative
+            })
         }
     }
 }
@@ -54,6 +243,7 @@ fn expand_structural<'i>(
     input: &'i syn::DeriveInput,
     trait_name: &str,
     attr_name: syn::Ident,
+    by_ref: bool,
 ) -> syn::Result<StructuralExpansion<'i>> {
     let mut variants = vec![];
     match &input.data {
@@ -115,7 +305,9 @@ fn expand_structural<'i>(
         method_ident: format_ident!("{}", trait_name_to_method_name(trait_name)),
         self_ty: (&input.ident, &input.generics),
         variants,
-        is_enum: matches!(input.data, syn::Data::Enum(_)),
+        enum_output: matches!(input.data, syn::Data::Enum(_)).then(EnumOutput::default),
+        by_ref,
+        is_const: false,
     })
 }
 
@@ -124,6 +316,8 @@ fn expand_scalar<'i>(
     input: &'i syn::DeriveInput,
     trait_name: &str,
     attr_name: syn::Ident,
+    by_ref: bool,
+    rhs_ty: Option<syn::Type>,
 ) -> syn::Result<ScalarExpansion<'i>> {
     let mut skipped_fields = SkippedFields::default();
     let fields = match &input.data {
@@ -162,6 +356,9 @@ fn expand_scalar<'i>(
         self_ty: (&input.ident, &input.generics),
         fields,
         skipped_fields,
+        by_ref,
+        rhs_ty,
+        is_const: false,
     })
 }
 
@@ -188,6 +385,19 @@ struct ScalarExpansion<'i> {
 
     /// Indices of the struct [`syn::Fields`] marked with an [`attr::Skip`].
     skipped_fields: SkippedFields,
+
+    /// Indicator whether the trait should be additionally implemented for a reference.
+    by_ref: bool,
+
+    /// Concrete type of the right-hand side operand, if any.
+    ///
+    /// If [`None`], then the trait is implemented generically for any right-hand side operand
+    /// type, supported by the fields.
+    rhs_ty: Option<syn::Type>,
+
+    /// Indicator whether the trait implementation should delegate to the inherent `const fn`
+    /// generated by a [`ConstExpansion`].
+    is_const: bool,
 }
 
 impl ToTokens for ScalarExpansion<'_> {
@@ -199,35 +409,59 @@ impl ToTokens for ScalarExpansion<'_> {
         let (_, ty_generics, _) = self.self_ty.1.split_for_impl();
         let implementor_ty: syn::Type = parse_quote! { #ty #ty_generics };
         let self_ty: syn::Type = parse_quote! { Self };
-        let rhs_ty: syn::TypeParam = parse_quote! { __derive_more_Rhs };
+        let lhs_lt: syn::Lifetime = parse_quote! { '__derive_more_lhs };
 
         let mut generics = self.self_ty.1.clone();
-        generics.params.push(rhs_ty.clone().into());
+        let rhs_ty = self.rhs_ty.clone().unwrap_or_else(|| {
+            let rhs_ty: syn::TypeParam = parse_quote! { __derive_more_Rhs };
+            generics.params.push(rhs_ty.clone().into());
+            parse_quote! { #rhs_ty }
+        });
+        let mut ref_generics = generics.clone();
+        ref_generics.params.insert(0, parse_quote! { #lhs_lt });
         let mut used_fields_count = 0;
-        for field_ty in self.fields.iter().enumerate().filter_map(|(n, field)| {
-            (!self.skipped_fields.contains(&n)).then_some(&field.ty)
-        }) {
+        for (n, field) in self.fields.iter().enumerate() {
+            let field_ty = &field.ty;
+            if self.skipped_fields.contains(&n) {
+                ref_generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote! {
+                        #field_ty: derive_more::core::clone::Clone
+                    });
+                continue;
+            }
             if !field_ty.contains_type_structurally(&self_ty)
                 && !field_ty.contains_type_structurally(&implementor_ty)
             {
                 generics.make_where_clause().predicates.push(parse_quote! {
                     #field_ty: derive_more::core::ops:: #trait_ty <#rhs_ty, Output = #field_ty>
                 });
+                ref_generics.make_where_clause().predicates.push(parse_quote! {
+                    &#lhs_lt #field_ty:
+                        derive_more::core::ops:: #trait_ty <#rhs_ty, Output = #field_ty>
+                });
             }
             used_fields_count += 1;
         }
         if used_fields_count > 1 {
-            generics.make_where_clause().predicates.push(parse_quote! {
-                #rhs_ty: derive_more::core::marker::Copy
-            });
+            for generics in [&mut generics, &mut ref_generics] {
+                generics.make_where_clause().predicates.push(parse_quote! {
+                    #rhs_ty: derive_more::core::marker::Copy
+                });
+            }
         }
         let (impl_generics, _, where_clause) = generics.split_for_impl();
 
-        let body = {
-            let method_path =
-                parse_quote! { derive_more::core::ops::#trait_ty::#method_ident };
-            let self_pat = self.fields.exhaustive_arm_pattern("__self_");
-            let fields_expr = self.fields.arm_expr(&method_path, &self.skipped_fields);
+        let method_path =
+            parse_quote! { derive_more::core::ops::#trait_ty::#method_ident };
+        let self_pat = self.fields.exhaustive_arm_pattern("__self_");
+        let body = if self.is_const {
+            quote! { Self::#method_ident(self, __rhs) }
+        } else {
+            let fields_expr =
+                self.fields
+                    .arm_expr(&method_path, &self.skipped_fields, false);
 
             quote! {
                 match self {
@@ -252,6 +486,194 @@ impl ToTokens for ScalarExpansion<'_> {
             }
         }
         .to_tokens(tokens);
+
+        if !self.by_ref {
+            return;
+        }
+
+        let (ref_impl_generics, _, ref_where_clause) = ref_generics.split_for_impl();
+        let body = {
+            let fields_expr =
+                self.fields
+                    .arm_expr(&method_path, &self.skipped_fields, true);
+
+            quote! {
+                match self {
+                    #ty #self_pat => #ty #fields_expr,
+                }
+            }
+        };
+
+        quote! {
+            #[allow(private_bounds)]
+            #[automatically_derived]
+            impl #ref_impl_generics derive_more::core::ops:: #trait_ty<#rhs_ty>
+                 for &#lhs_lt #implementor_ty #ref_where_clause
+            {
+                type Output = #implementor_ty;
+
+                #[inline]
+                #[track_caller]
+                fn #method_ident(self, __rhs: #rhs_ty) -> Self::Output {
+                    #body
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+/// Representation of an [`ops::Mul`]-like derive macro container attribute.
+///
+/// ```rust,ignore
+/// #[<attribute>(forward)]
+/// #[<attribute>(ref)]
+/// #[<attribute>(const)]
+/// #[<attribute>(no_scalar)]
+/// #[mul(commutative(<type>, <type>, ...))]
+/// ```
+///
+/// Multiple arguments can be specified in a single attribute, separated by commas.
+#[derive(Default)]
+struct ContainerAttribute {
+    /// [`Span`] of the `forward` argument, if any, making the implementation structural.
+    forward: Option<Span>,
+
+    /// [`Span`] of the `ref` argument, if any, additionally implementing the trait for references.
+    by_ref: Option<Span>,
+
+    /// [`Span`] of the `const` argument, if any, additionally generating an inherent `const fn`
+    /// equivalent of the trait method.
+    is_const: Option<Span>,
+
+    /// [`Span`] of the `no_scalar` argument, if any, opting out of the scalar implementation in
+    /// favor of the typed ones.
+    no_scalar: Option<Span>,
+
+    /// Types to generate reversed [`ops::Mul`] implementations for, if any.
+    commutative: Option<Spanning<Vec<syn::Type>>>,
+}
+
+impl Parse for ContainerAttribute {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut attr = Self::default();
+        loop {
+            let ident = input.call(syn::Ident::parse_any)?;
+            let span = ident.span();
+            match ident.to_string().as_str() {
+                "forward" => attr.forward = Some(span),
+                "ref" => attr.by_ref = Some(span),
+                "const" => attr.is_const = Some(span),
+                "no_scalar" => attr.no_scalar = Some(span),
+                "commutative" => {
+                    let inner;
+                    _ = syn::parenthesized!(inner in input);
+                    let types =
+                        Punctuated::<syn::Type, token::Comma>::parse_terminated(
+                            &inner,
+                        )?;
+                    if types.is_empty() {
+                        return Err(syn::Error::new(
+                            span,
+                            "at least one type is expected in `commutative(...)`",
+                        ));
+                    }
+                    attr.commutative
+                        .get_or_insert_with(|| Spanning::new(vec![], span))
+                        .item
+                        .extend(types);
+                }
+                _ => return Err(syn::Error::new(
+                    span,
+                    "unknown attribute argument, expected `forward`, `ref`, `const`, \
+                         `no_scalar` or `commutative(...)`",
+                )),
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            _ = input.parse::<token::Comma>()?;
+            if input.is_empty() {
+                break;
+            }
+        }
+        Ok(attr)
+    }
+}
+
+impl attr::ParseMultiple for ContainerAttribute {
+    fn merge_attrs(
+        prev: Spanning<Self>,
+        new: Spanning<Self>,
+        _: &syn::Ident,
+    ) -> syn::Result<Spanning<Self>> {
+        let span = prev.span.join(new.span).unwrap_or(prev.span);
+        let (prev, new) = (prev.item, new.item);
+        let commutative = match (prev.commutative, new.commutative) {
+            (Some(mut prev), Some(new)) => {
+                prev.item.extend(new.item);
+                Some(prev)
+            }
+            (prev, new) => prev.or(new),
+        };
+        Ok(Spanning::new(
+            Self {
+                forward: prev.forward.or(new.forward),
+                by_ref: prev.by_ref.or(new.by_ref),
+                is_const: prev.is_const.or(new.is_const),
+                no_scalar: prev.no_scalar.or(new.no_scalar),
+                commutative,
+            },
+            span,
+        ))
+    }
+}
+
+/// Expansion of a macro for generating reversed [`ops::Mul`] trait implementations for the types
+/// specified in a [`ContainerAttribute`], delegating to the ones of the implementor struct.
+struct CommutativeExpansion<'i> {
+    /// [`syn::Ident`] and [`syn::Generics`] of the implementor struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    self_ty: (&'i syn::Ident, &'i syn::Generics),
+
+    /// Types to generate reversed [`ops::Mul`] implementations for.
+    types: Vec<syn::Type>,
+}
+
+impl ToTokens for CommutativeExpansion<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ty = self.self_ty.0;
+        let (_, ty_generics, _) = self.self_ty.1.split_for_impl();
+        let implementor_ty: syn::Type = parse_quote! { #ty #ty_generics };
+
+        for lhs_ty in &self.types {
+            let mul_ty = quote! { derive_more::core::ops::Mul<#lhs_ty> };
+
+            let mut generics = self.self_ty.1.clone();
+            generics.make_where_clause().predicates.push(parse_quote! {
+                #implementor_ty: #mul_ty
+            });
+            let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+            quote! {
+                #[allow(private_bounds)]
+                #[automatically_derived]
+                impl #impl_generics derive_more::core::ops::Mul<#implementor_ty> for #lhs_ty
+                     #where_clause
+                {
+                    type Output = <#implementor_ty as #mul_ty>::Output;
+
+                    #[inline]
+                    #[track_caller]
+                    fn mul(self, __rhs: #implementor_ty) -> Self::Output {
+                        <#implementor_ty as #mul_ty>::mul(__rhs, self)
+                    }
+                }
+            }
+            .to_tokens(tokens);
+        }
     }
 }
 
@@ -259,10 +681,14 @@ impl ToTokens for ScalarExpansion<'_> {
 trait ScalarExpansionFieldsExt {
     /// Generates a resulting expression with these [`syn::Fields`] in a matched arm of a `match`
     /// expression, by applying the specified method.
+    ///
+    /// If `by_ref` is `true`, then the matched values are references, so the skipped fields are
+    /// cloned.
     fn arm_expr(
         &self,
         method: &syn::Path,
         skipped_indices: &SkippedFields,
+        by_ref: bool,
     ) -> TokenStream;
 }
 
@@ -271,13 +697,22 @@ impl ScalarExpansionFieldsExt for syn::Fields {
         &self,
         method_path: &syn::Path,
         skipped_indices: &SkippedFields,
+        by_ref: bool,
     ) -> TokenStream {
+        let skipped = |self_val: syn::Ident| {
+            if by_ref {
+                quote! { derive_more::core::clone::Clone::clone(#self_val) }
+            } else {
+                self_val.into_token_stream()
+            }
+        };
         match self {
             Self::Named(fields) => {
                 let fields = fields.named.iter().enumerate().map(|(num, field)| {
                     let name = &field.ident;
                     let self_val = format_ident!("__self_{num}");
                     if skipped_indices.contains(&num) {
+                        let self_val = skipped(self_val);
                         quote! { #name: #self_val }
                     } else {
                         quote! { #name: #method_path(#self_val, __rhs) }
@@ -289,7 +724,7 @@ impl ScalarExpansionFieldsExt for syn::Fields {
                 let fields = (0..fields.unnamed.len()).map(|num| {
                     let self_val = format_ident!("__self_{num}");
                     if skipped_indices.contains(&num) {
-                        quote! { #self_val }
+                        skipped(self_val)
                     } else {
                         quote! { #method_path(#self_val, __rhs) }
                     }
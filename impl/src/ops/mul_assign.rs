@@ -7,11 +7,15 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use syn::{parse_quote, spanned::Spanned as _};
 
-use super::{AssignStructuralExpansion, SkippedFields};
+use super::{
+    enum_variants, shift_types, AssignEnumAttribute, AssignStructuralExpansion,
+    SkippedFields,
+};
 use crate::utils::{
     attr::{self, ParseMultiple as _},
     pattern_matching::FieldsExt as _,
     structural_inclusion::TypeExt as _,
+    Either, Spanning,
 };
 
 /// Expands an [`ops::MulAssign`]-like derive macro.
@@ -34,18 +38,62 @@ pub fn expand(input: &syn::DeriveInput, trait_name: &str) -> syn::Result<TokenSt
                     format!("`{trait_name}` cannot be derived for unit structs"),
                 ));
             }
-            if attr::Forward::parse_attrs(&input.attrs, &attr_name)?.is_some() {
-                expand_structural(input, trait_name, attr_name)
-                    .map(ToTokens::into_token_stream)
+            let attr = if matches!(trait_name, "ShlAssign" | "ShrAssign") {
+                Either::<attr::Forward, attr::Types>::parse_attrs(
+                    &input.attrs,
+                    &attr_name,
+                )?
             } else {
-                expand_scalar(input, trait_name, attr_name)
-                    .map(ToTokens::into_token_stream)
+                attr::Forward::parse_attrs(&input.attrs, &attr_name)?
+                    .map(|attr| attr.map(Either::Left))
+            };
+            match attr {
+                Some(Spanning {
+                    item: Either::Left(_),
+                    ..
+                }) => expand_structural(input, trait_name, attr_name)
+                    .map(ToTokens::into_token_stream),
+                Some(Spanning {
+                    item: Either::Right(types),
+                    span,
+                }) => {
+                    let mut expansions = TokenStream::new();
+                    for rhs_ty in shift_types(types, span, &attr_name)? {
+                        expand_scalar(
+                            input,
+                            trait_name,
+                            attr_name.clone(),
+                            Some(rhs_ty),
+                        )?
+                        .to_tokens(&mut expansions);
+                    }
+                    Ok(expansions)
+                }
+                None => expand_scalar(input, trait_name, attr_name, None)
+                    .map(ToTokens::into_token_stream),
             }
         }
-        syn::Data::Enum(data) => Err(syn::Error::new(
-            data.enum_token.span(),
-            format!("`{trait_name}` cannot be derived for enums"),
-        )),
+        syn::Data::Enum(data) => {
+            let (policy, forward) =
+                AssignEnumAttribute::parse_policy(input, data, trait_name, &attr_name)?;
+            if let Some(span) = forward {
+                return Err(syn::Error::new(
+                    span,
+                    format!("`#[{attr_name}(forward)]` attribute is not supported"),
+                ));
+            }
+            Ok(AssignStructuralExpansion {
+                trait_ty: format_ident!("{trait_name}"),
+                method_ident: format_ident!(
+                    "{}",
+                    trait_name_to_method_name(trait_name)
+                ),
+                self_ty: (&input.ident, &input.generics),
+                variants: enum_variants(data, trait_name, &attr_name)?,
+                enum_policy: Some(policy),
+            }
+            .into_token_stream())
+        }
         syn::Data::Union(data) => Err(syn::Error::new(
             data.union_token.span(),
             format!("`{trait_name}` cannot be derived for unions"),
@@ -85,7 +133,7 @@ fn expand_structural<'i>(
         method_ident: format_ident!("{}", trait_name_to_method_name(trait_name)),
         self_ty: (&input.ident, &input.generics),
         variants,
-        is_enum: false,
+        enum_policy: None,
     })
 }
 
@@ -94,6 +142,7 @@ fn expand_scalar<'i>(
     input: &'i syn::DeriveInput,
     trait_name: &str,
     attr_name: syn::Ident,
+    rhs_ty: Option<syn::Type>,
 ) -> syn::Result<ScalarExpansion<'i>> {
     let syn::Data::Struct(data) = &input.data else {
         unreachable!()
@@ -120,6 +169,7 @@ fn expand_scalar<'i>(
         self_ty: (&input.ident, &input.generics),
         fields: &data.fields,
         skipped_fields,
+        rhs_ty,
     })
 }
 
@@ -146,6 +196,12 @@ struct ScalarExpansion<'i> {
 
     /// Indices of the struct [`syn::Fields`] marked with an [`attr::Skip`].
     skipped_fields: SkippedFields,
+
+    /// Concrete type of the right-hand side operand, if any.
+    ///
+    /// If [`None`], then the trait is implemented generically for any right-hand side operand
+    /// type, supported by the fields.
+    rhs_ty: Option<syn::Type>,
 }
 
 impl ToTokens for ScalarExpansion<'_> {
@@ -157,10 +213,13 @@ impl ToTokens for ScalarExpansion<'_> {
         let (_, ty_generics, _) = self.self_ty.1.split_for_impl();
         let implementor_ty: syn::Type = parse_quote! { #ty #ty_generics };
         let self_ty: syn::Type = parse_quote! { Self };
-        let rhs_ty: syn::TypeParam = parse_quote! { __derive_more_Rhs };
 
         let mut generics = self.self_ty.1.clone();
-        generics.params.push(rhs_ty.clone().into());
+        let rhs_ty = self.rhs_ty.clone().unwrap_or_else(|| {
+            let rhs_ty: syn::TypeParam = parse_quote! { __derive_more_Rhs };
+            generics.params.push(rhs_ty.clone().into());
+            parse_quote! { #rhs_ty }
+        });
         let mut used_fields_count = 0;
         for field_ty in self.fields.iter().enumerate().filter_map(|(n, field)| {
             (!self.skipped_fields.contains(&n)).then_some(&field.ty)
@@ -1,5 +1,6 @@
 use crate::utils::{
-    add_extra_ty_param_bound, add_extra_where_clauses, MultiFieldData, State,
+    add_extra_generic_param, add_extra_ty_param_bound, add_extra_where_clauses,
+    DeriveType, MultiFieldData, State,
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
@@ -7,10 +8,14 @@ use syn::{DeriveInput, Result};
 
 pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStream> {
     let state = State::new(input, trait_name, trait_name.to_lowercase())?;
+    if state.derive_type == DeriveType::Enum {
+        return Ok(expand_enum(input, trait_name));
+    }
     let multi_field_data = state.enabled_fields_data();
     let MultiFieldData {
         input_type,
         field_types,
+        field_idents,
         trait_path,
         method_ident,
         ..
@@ -41,6 +46,27 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
         .collect();
     let identity = multi_field_data.initializer(&initializers);
 
+    // Every referenced item is converted into an owned one field by field, by
+    // summing (or multiplying) an iterator of a single field reference.
+    let ref_generics = add_extra_where_clauses(
+        &generics,
+        quote! {
+            where #(#field_types: #trait_path<&'__derive_more_a #field_types>),*
+        },
+    );
+    let ref_generics =
+        add_extra_generic_param(&ref_generics, quote! { '__derive_more_a });
+    let (ref_impl_generics, _, ref_where_clause) = ref_generics.split_for_impl();
+    let ref_initializers: Vec<_> = field_idents
+        .iter()
+        .map(|field_ident| {
+            quote! {
+                #trait_path::#method_ident(derive_more::core::iter::once(&__item.#field_ident))
+            }
+        })
+        .collect();
+    let owned_item = multi_field_data.initializer(&ref_initializers);
+
     Ok(quote! {
         #[automatically_derived]
         impl #impl_generics #trait_path for #input_type #ty_generics #where_clause {
@@ -49,5 +75,80 @@ pub fn expand(input: &DeriveInput, trait_name: &'static str) -> Result<TokenStre
                 iter.fold(#identity, #op_path::#op_method_ident)
             }
         }
+
+        #[automatically_derived]
+        impl #ref_impl_generics #trait_path<&'__derive_more_a Self>
+         for #input_type #ty_generics #ref_where_clause
+        {
+            #[inline]
+            fn #method_ident<I: derive_more::core::iter::Iterator<Item = &'__derive_more_a Self>>(
+                iter: I,
+            ) -> Self {
+                iter.fold(#identity, |__acc, __item| #op_path::#op_method_ident(__acc, #owned_item))
+            }
+        }
     })
 }
+
+/// Expands an inherent `try_sum()`/`try_product()` method for an enum, as an
+/// enum has no identity value, and its `Add`/`Mul` implementations may fail.
+fn expand_enum(input: &DeriveInput, trait_name: &'static str) -> TokenStream {
+    let input_type = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let (op_trait_ident, op_method_ident, method_ident) = if trait_name == "Sum" {
+        (
+            format_ident!("Add"),
+            format_ident!("add"),
+            format_ident!("try_sum"),
+        )
+    } else {
+        (
+            format_ident!("Mul"),
+            format_ident!("mul"),
+            format_ident!("try_product"),
+        )
+    };
+    let op_path = quote! { derive_more::core::ops::#op_trait_ident };
+    let doc = format!(
+        "Combines all the items of the provided iterator with `{op_trait_ident}`, \
+         short-circuiting on the first error.\n\n\
+         Returns `None` if the provided iterator is empty.",
+    );
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #input_type #ty_generics #where_clause {
+            #[doc = #doc]
+            #[inline]
+            #[track_caller]
+            pub fn #method_ident<__derive_more_Error, I>(
+                iter: I,
+            ) -> derive_more::core::result::Result<
+                derive_more::core::option::Option<Self>,
+                __derive_more_Error,
+            >
+            where
+                I: derive_more::core::iter::IntoIterator<Item = Self>,
+                Self: #op_path<
+                    Output = derive_more::core::result::Result<Self, __derive_more_Error>,
+                >,
+            {
+                let mut iter = derive_more::core::iter::IntoIterator::into_iter(iter);
+                let derive_more::core::option::Option::Some(mut acc) =
+                    derive_more::core::iter::Iterator::next(&mut iter)
+                else {
+                    return derive_more::core::result::Result::Ok(
+                        derive_more::core::option::Option::None,
+                    );
+                };
+                for item in iter {
+                    acc = #op_path::#op_method_ident(acc, item)?;
+                }
+                derive_more::core::result::Result::Ok(
+                    derive_more::core::option::Option::Some(acc),
+                )
+            }
+        }
+    }
+}
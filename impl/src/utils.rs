@@ -13,19 +13,29 @@ use syn::{
 };
 
 #[cfg(any(
+    feature = "accessors",
     feature = "add",
     feature = "add_assign",
     feature = "as_ref",
+    feature = "builder",
+    feature = "checked_ops",
+    feature = "clone",
     feature = "debug",
+    feature = "default_derive",
+    feature = "discriminant",
     feature = "display",
     feature = "eq",
     feature = "from",
     feature = "from_str",
+    feature = "hash",
     feature = "into",
+    feature = "into_static_str",
     feature = "mul",
     feature = "mul_assign",
+    feature = "ord",
     feature = "try_from",
     feature = "try_into",
+    feature = "variant_name",
 ))]
 pub(crate) use self::either::Either;
 #[cfg(any(feature = "from", feature = "into"))]
@@ -34,26 +44,40 @@ pub(crate) use self::fields_ext::FieldsExt;
     feature = "add",
     feature = "add_assign",
     feature = "as_ref",
+    feature = "clone",
+    feature = "default_derive",
     feature = "eq",
     feature = "from_str",
+    feature = "hash",
     feature = "mul",
     feature = "mul_assign",
+    feature = "ord",
 ))]
 pub(crate) use self::generics_search::GenericsSearch;
 #[cfg(any(
+    feature = "accessors",
     feature = "add",
     feature = "add_assign",
     feature = "as_ref",
+    feature = "builder",
+    feature = "checked_ops",
+    feature = "clone",
     feature = "debug",
+    feature = "default_derive",
+    feature = "discriminant",
     feature = "display",
     feature = "eq",
     feature = "from",
     feature = "from_str",
+    feature = "hash",
     feature = "into",
+    feature = "into_static_str",
     feature = "mul",
     feature = "mul_assign",
+    feature = "ord",
     feature = "try_from",
     feature = "try_into",
+    feature = "variant_name",
 ))]
 pub(crate) use self::spanning::Spanning;
 
@@ -251,6 +275,265 @@ pub fn add_where_clauses_for_new_ident<'a>(
     add_extra_generic_type_param(&generics, generic_param)
 }
 
+/// Field of a struct, specified by an `#[<attribute>(<types>)]` attribute to be indexed by the
+/// concrete index types.
+#[cfg(any(feature = "index", feature = "index_mut"))]
+pub struct TypedIndexField<'a> {
+    pub field: &'a Field,
+    pub member: TokenStream,
+    pub index_types: Vec<Type>,
+}
+
+/// Parses `#[<attribute>(<types>)]` attributes of the struct fields, specifying the concrete index
+/// types to generate an `Index`-like implementation for each of.
+///
+/// Returns [`None`] if there are no such attributes, so the field should be indexed by any type it
+/// supports (the `#[<attribute>]` and `#[<attribute>(ignore)]` attributes are not considered).
+#[cfg(any(feature = "index", feature = "index_mut"))]
+pub fn typed_index_fields<'a>(
+    input: &'a DeriveInput,
+    trait_attr: &str,
+) -> Result<Option<Vec<TypedIndexField<'a>>>> {
+    let Data::Struct(data) = &input.data else {
+        return Ok(None);
+    };
+
+    let mut typed = vec![];
+    let mut untyped = None;
+    for (i, field) in data.fields.iter().enumerate() {
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident(trait_attr)) {
+            let syn::Meta::List(list) = &attr.meta else {
+                untyped.get_or_insert(attr.path().span());
+                continue;
+            };
+            if list
+                .parse_args::<Ident>()
+                .is_ok_and(|ident| ident == "ignore")
+            {
+                continue;
+            }
+            let member = field.ident.as_ref().map_or_else(
+                || {
+                    let index = Index::from(i);
+                    quote! { self.#index }
+                },
+                |ident| quote! { self.#ident },
+            );
+            typed.push(TypedIndexField {
+                field,
+                member,
+                index_types: list
+                    .parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated)?
+                    .into_iter()
+                    .collect(),
+            });
+        }
+    }
+    if typed.is_empty() {
+        return Ok(None);
+    }
+    if let Some(span) = untyped {
+        return Err(Error::new(
+            span,
+            format!(
+                "`#[{trait_attr}]` attribute cannot be combined with `#[{trait_attr}(<types>)]` \
+                 ones, as it covers all the index types",
+            ),
+        ));
+    }
+
+    let mut seen = HashSet::default();
+    for ty in typed.iter().flat_map(|f| &f.index_types) {
+        let mut normalized = ty.clone();
+        normalize_index_type(&mut normalized);
+        if !seen.insert(normalized.to_token_stream().to_string()) {
+            return Err(Error::new_spanned(
+                ty,
+                format!(
+                    "index type `{}` is already claimed by another \
+                     `#[{trait_attr}(...)]` attribute",
+                    ty.to_token_stream(),
+                ),
+            ));
+        }
+    }
+    Ok(Some(typed))
+}
+
+/// Normalizes the provided index [`Type`] for detecting overlaps between `#[<attribute>(<types>)]`
+/// attributes.
+///
+/// This is a best-effort syntactic normalization only, as type aliases and imports cannot be
+/// resolved by a macro: lifetimes, parentheses and leading `::` are omitted, `std`/`alloc` paths
+/// are unified with `core` ones, and `core::primitive` types are reduced to their bare names.
+#[cfg(any(feature = "index", feature = "index_mut"))]
+fn normalize_index_type(ty: &mut Type) {
+    match ty {
+        Type::Group(syn::TypeGroup { elem, .. })
+        | Type::Paren(syn::TypeParen { elem, .. }) => {
+            let mut inner = (**elem).clone();
+            normalize_index_type(&mut inner);
+            *ty = inner;
+        }
+        Type::Reference(ty) => {
+            ty.lifetime = None;
+            normalize_index_type(&mut ty.elem);
+        }
+        Type::Array(syn::TypeArray { elem, .. })
+        | Type::Ptr(syn::TypePtr { elem, .. })
+        | Type::Slice(syn::TypeSlice { elem, .. }) => normalize_index_type(elem),
+        Type::Tuple(ty) => ty.elems.iter_mut().for_each(normalize_index_type),
+        Type::Path(syn::TypePath { qself: None, path }) => {
+            path.leading_colon = None;
+            if let Some(first) = path.segments.first_mut() {
+                if first.ident == "std" || first.ident == "alloc" {
+                    first.ident = Ident::new("core", first.ident.span());
+                }
+            }
+            if path.segments.len() == 3
+                && path.segments[0].ident == "core"
+                && path.segments[1].ident == "primitive"
+            {
+                path.segments = path.segments.iter().skip(2).cloned().collect();
+            }
+            for segment in &mut path.segments {
+                if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments
+                {
+                    args.args = args
+                        .args
+                        .iter()
+                        .filter(|arg| !matches!(arg, syn::GenericArgument::Lifetime(_)))
+                        .cloned()
+                        .map(|mut arg| {
+                            if let syn::GenericArgument::Type(ty) = &mut arg {
+                                normalize_index_type(ty);
+                            }
+                            arg
+                        })
+                        .collect();
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Prepares [`Generics`] of an `Index`-like implementation for the provided `index_type`.
+///
+/// Names the elided lifetime of the `index_type` reference (if any), and bounds the `field_type`
+/// with the `trait_path` when generics are involved.
+#[cfg(any(feature = "index", feature = "index_mut"))]
+pub fn typed_index_generics(
+    generics: &Generics,
+    field_type: &Type,
+    index_type: &Type,
+    trait_path: TokenStream,
+) -> (Type, Generics) {
+    let mut new_generics = generics.clone();
+    let mut index_type = index_type.clone();
+    if let Type::Reference(ty) = &mut index_type {
+        if ty.lifetime.is_none() {
+            let lifetime: syn::Lifetime = parse_quote! { '__derive_more_idx };
+            ty.lifetime = Some(lifetime.clone());
+            new_generics.params.insert(0, parse_quote! { #lifetime });
+        }
+    }
+    if !generics.params.is_empty() {
+        new_generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { #field_type: #trait_path<#index_type> });
+    }
+    (index_type, new_generics)
+}
+
+/// Variants of an enum, forwarding an `Index`-like implementation to their single enabled fields.
+#[cfg(any(feature = "index", feature = "index_mut"))]
+pub struct IndexEnumVariants {
+    /// `Output` type of the implementation, the one of the first variant field.
+    pub output: TokenStream,
+
+    /// Predicates bounding every variant field with the trait, all indexing into the same
+    /// [`IndexEnumVariants::output`].
+    pub predicates: Vec<TokenStream>,
+
+    /// Pattern matching every variant, binding its field to the `__0` variable, along with the
+    /// trait casted field type.
+    pub arms: Vec<(TokenStream, TokenStream)>,
+}
+
+/// Selects the single enabled field of every enabled variant of the enum, to forward an
+/// `Index`-like implementation to, using the provided `index_type`.
+///
+/// Errors if any variant doesn't have exactly one enabled field, or the enum has no variants.
+#[cfg(any(feature = "index", feature = "index_mut"))]
+pub fn index_enum_variants(
+    state: &State<'_>,
+    data: &syn::DataEnum,
+    index_type: &Ident,
+) -> Result<IndexEnumVariants> {
+    let trait_path = &state.trait_path;
+    let trait_path_with_params = quote! { #trait_path<#index_type> };
+
+    let mut output = None;
+    let mut predicates = vec![];
+    let mut arms = vec![];
+
+    for variant_state in state.enabled_variant_data().variant_states {
+        let data = variant_state.enabled_fields_data();
+        if data.fields.len() != 1 {
+            let trait_attr = &variant_state.trait_attr;
+            return Err(Error::new(
+                variant_state.variant.span(),
+                format!(
+                    "`{}` can be derived for enums only when forwarding every variant to a \
+                     single field, try putting `#[{trait_attr}]` or `#[{trait_attr}(ignore)]` \
+                     attribute on the variant fields",
+                    state.trait_name,
+                ),
+            ));
+        }
+
+        let vars = numbered_vars(variant_state.fields.len(), "");
+        let matcher = data.matcher(&data.field_indexes, &vars);
+        let field_type = data.field_types[0];
+
+        // All the variants should be indexed into the same `Output` type, so the one of the first
+        // variant is used for the others.
+        match &output {
+            None => {
+                predicates.push(quote! { #field_type: #trait_path_with_params });
+                output = Some(quote! {
+                    <#field_type as derive_more::core::ops::Index<#index_type>>::Output
+                });
+            }
+            Some(output) => {
+                predicates.push(quote! {
+                    #field_type: #trait_path<#index_type, Output = #output>
+                });
+            }
+        }
+
+        arms.push((matcher, quote! { <#field_type as #trait_path_with_params> }));
+    }
+
+    let Some(output) = output else {
+        return Err(Error::new(
+            data.enum_token.span(),
+            format!(
+                "`{}` cannot be derived for enums with no variants",
+                state.trait_name,
+            ),
+        ));
+    };
+
+    Ok(IndexEnumVariants {
+        output,
+        predicates,
+        arms,
+    })
+}
+
 pub fn unnamed_to_vec(fields: &FieldsUnnamed) -> Vec<&Field> {
     fields.unnamed.iter().collect()
 }
@@ -1322,20 +1605,107 @@ pub fn is_type_parameter_used_in_type(
     }
 }
 
+/// Kind of a primitive scalar type.
+#[cfg(any(feature = "add", feature = "mul", feature = "not"))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Primitive {
+    /// `bool` type.
+    Bool,
+
+    /// Signed integer type, like `i32` or `isize`.
+    SignedInt,
+
+    /// Unsigned integer type, like `u8` or `usize`.
+    UnsignedInt,
+
+    /// Floating-point type, like `f32` or `f64`.
+    Float,
+}
+
+#[cfg(any(feature = "add", feature = "mul", feature = "not"))]
+impl Primitive {
+    /// Recognizes the [`Primitive`] kind of the provided [`syn::Type`], if any.
+    ///
+    /// The type should be named either directly (like `u32`), or via a `primitive` module path
+    /// (like `core::primitive::u32` or `::std::primitive::u32`). Type aliases cannot be resolved by
+    /// a macro, so they're not recognized.
+    pub(crate) fn of(ty: &syn::Type) -> Option<Self> {
+        match ty {
+            syn::Type::Path(ty) => Self::of_path(ty),
+            _ => None,
+        }
+    }
+
+    /// Recognizes the [`Primitive`] kind of the provided [`syn::TypePath`], if any.
+    ///
+    /// See [`Primitive::of()`] for details.
+    pub(crate) fn of_path(ty: &syn::TypePath) -> Option<Self> {
+        let syn::TypePath { qself: None, path } = ty else {
+            return None;
+        };
+        let ident = match path.get_ident() {
+            Some(ident) => ident,
+            None => {
+                let mut segments = path.segments.iter();
+                match (segments.next(), segments.next(), segments.next()) {
+                    (Some(krate), Some(module), Some(last))
+                        if segments.next().is_none()
+                            && ["core", "std"].iter().any(|k| krate.ident == k)
+                            && module.ident == "primitive"
+                            && krate.arguments.is_none()
+                            && module.arguments.is_none()
+                            && last.arguments.is_none() =>
+                    {
+                        &last.ident
+                    }
+                    _ => return None,
+                }
+            }
+        };
+        Some(match ident.to_string().as_str() {
+            "bool" => Self::Bool,
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => Self::SignedInt,
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => Self::UnsignedInt,
+            "f32" | "f64" => Self::Float,
+            _ => return None,
+        })
+    }
+
+    /// Checks whether this [`Primitive`] is a numeric type (an integer or a floating-point one).
+    pub(crate) fn is_numeric(self) -> bool {
+        !matches!(self, Self::Bool)
+    }
+
+    /// Checks whether this [`Primitive`] is an integer type (either a signed or an unsigned one).
+    pub(crate) fn is_integer(self) -> bool {
+        matches!(self, Self::SignedInt | Self::UnsignedInt)
+    }
+}
+
 #[cfg(any(
+    feature = "accessors",
     feature = "add",
     feature = "add_assign",
     feature = "as_ref",
+    feature = "builder",
+    feature = "checked_ops",
+    feature = "clone",
     feature = "debug",
+    feature = "default_derive",
+    feature = "discriminant",
     feature = "display",
     feature = "eq",
     feature = "from",
     feature = "from_str",
+    feature = "hash",
     feature = "into",
+    feature = "into_static_str",
     feature = "mul",
     feature = "mul_assign",
+    feature = "ord",
     feature = "try_from",
     feature = "try_into",
+    feature = "variant_name",
 ))]
 mod either {
     use proc_macro2::TokenStream;
@@ -1401,19 +1771,29 @@ mod either {
 }
 
 #[cfg(any(
+    feature = "accessors",
     feature = "add",
     feature = "add_assign",
     feature = "as_ref",
+    feature = "builder",
+    feature = "checked_ops",
+    feature = "clone",
     feature = "debug",
+    feature = "default_derive",
+    feature = "discriminant",
     feature = "display",
     feature = "eq",
     feature = "from",
     feature = "from_str",
+    feature = "hash",
     feature = "into",
+    feature = "into_static_str",
     feature = "mul",
     feature = "mul_assign",
+    feature = "ord",
     feature = "try_from",
     feature = "try_into",
+    feature = "variant_name",
 ))]
 mod spanning {
     use std::ops::{Deref, DerefMut};
@@ -1503,19 +1883,29 @@ mod spanning {
 }
 
 #[cfg(any(
+    feature = "accessors",
     feature = "add",
     feature = "add_assign",
     feature = "as_ref",
+    feature = "builder",
+    feature = "checked_ops",
+    feature = "clone",
     feature = "debug",
+    feature = "default_derive",
+    feature = "discriminant",
     feature = "display",
     feature = "eq",
     feature = "from",
     feature = "from_str",
+    feature = "hash",
     feature = "into",
+    feature = "into_static_str",
     feature = "mul",
     feature = "mul_assign",
+    feature = "ord",
     feature = "try_from",
     feature = "try_into",
+    feature = "variant_name",
 ))]
 pub(crate) mod attr {
     use std::any::Any;
@@ -1529,6 +1919,7 @@ pub(crate) mod attr {
 
     #[cfg(any(
         feature = "as_ref",
+        feature = "default_derive",
         feature = "from",
         feature = "into",
         feature = "try_from"
@@ -1536,28 +1927,37 @@ pub(crate) mod attr {
     pub(crate) use self::empty::Empty;
     #[cfg(any(feature = "from_str", feature = "try_into"))]
     pub(crate) use self::error::Error;
+    #[cfg(any(feature = "as_ref", feature = "from", feature = "mul_assign",))]
+    pub(crate) use self::forward::Forward;
     #[cfg(any(
-        feature = "as_ref",
-        feature = "from",
-        feature = "mul",
-        feature = "mul_assign",
+        feature = "display",
+        feature = "from_str",
+        feature = "into_static_str",
+        feature = "variant_name",
     ))]
-    pub(crate) use self::forward::Forward;
-    #[cfg(any(feature = "display", feature = "from_str"))]
     pub(crate) use self::rename_all::RenameAll;
     #[cfg(any(
         feature = "add",
         feature = "add_assign",
         feature = "as_ref",
+        feature = "checked_ops",
         feature = "debug",
         feature = "eq",
         feature = "from",
+        feature = "hash",
         feature = "into",
         feature = "mul",
         feature = "mul_assign",
+        feature = "ord",
     ))]
     pub(crate) use self::skip::Skip;
-    #[cfg(any(feature = "as_ref", feature = "from", feature = "try_from"))]
+    #[cfg(any(
+        feature = "as_ref",
+        feature = "from",
+        feature = "mul",
+        feature = "mul_assign",
+        feature = "try_from",
+    ))]
     pub(crate) use self::types::Types;
     #[cfg(any(feature = "as_ref", feature = "from"))]
     pub(crate) use self::{conversion::Conversion, field_conversion::FieldConversion};
@@ -1695,6 +2095,7 @@ pub(crate) mod attr {
 
     #[cfg(any(
         feature = "as_ref",
+        feature = "default_derive",
         feature = "from",
         feature = "into",
         feature = "try_from"
@@ -1756,12 +2157,7 @@ pub(crate) mod attr {
         }
     }
 
-    #[cfg(any(
-        feature = "as_ref",
-        feature = "from",
-        feature = "mul",
-        feature = "mul_assign",
-    ))]
+    #[cfg(any(feature = "as_ref", feature = "from", feature = "mul_assign",))]
     mod forward {
         use syn::{
             parse::{Parse, ParseStream},
@@ -1888,13 +2284,16 @@ pub(crate) mod attr {
         feature = "add",
         feature = "add_assign",
         feature = "as_ref",
+        feature = "checked_ops",
         feature = "debug",
         feature = "display",
         feature = "eq",
         feature = "from",
+        feature = "hash",
         feature = "into",
         feature = "mul",
         feature = "mul_assign",
+        feature = "ord",
     ))]
     mod skip {
         use syn::{
@@ -1931,6 +2330,21 @@ pub(crate) mod attr {
             pub(crate) const fn name(&self) -> &'static str {
                 self.0
             }
+
+            /// Checks whether the provided [`syn::Attribute`]s contain a [`Skip`] with any of the
+            /// provided attribute `names`.
+            #[cfg(any(feature = "hash", feature = "ord"))]
+            pub(crate) fn is_present(
+                attrs: &[syn::Attribute],
+                names: &[syn::Ident],
+            ) -> syn::Result<bool> {
+                for name in names {
+                    if Self::parse_attrs(attrs, name)?.is_some() {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
         }
 
         impl ParseMultiple for Skip {
@@ -1950,7 +2364,13 @@ pub(crate) mod attr {
         }
     }
 
-    #[cfg(any(feature = "as_ref", feature = "from", feature = "try_from"))]
+    #[cfg(any(
+        feature = "as_ref",
+        feature = "from",
+        feature = "mul",
+        feature = "mul_assign",
+        feature = "try_from",
+    ))]
     mod types {
         use syn::{
             parse::{Parse, ParseStream},
@@ -2295,7 +2715,12 @@ pub(crate) mod attr {
         }
     }
 
-    #[cfg(any(feature = "display", feature = "from_str"))]
+    #[cfg(any(
+        feature = "display",
+        feature = "from_str",
+        feature = "into_static_str",
+        feature = "variant_name",
+    ))]
     mod rename_all {
         use syn::{
             parse::{Parse, ParseStream},
@@ -2517,10 +2942,14 @@ mod fields_ext {
     feature = "add",
     feature = "add_assign",
     feature = "as_ref",
+    feature = "clone",
+    feature = "default_derive",
     feature = "eq",
     feature = "from_str",
+    feature = "hash",
     feature = "mul",
     feature = "mul_assign",
+    feature = "ord",
 ))]
 mod generics_search {
     use syn::visit::Visit;
@@ -2872,9 +3301,13 @@ pub(crate) mod replace_self {
 #[cfg(any(
     feature = "add",
     feature = "add_assign",
+    feature = "clone",
+    feature = "default_derive",
     feature = "eq",
+    feature = "hash",
     feature = "mul",
     feature = "mul_assign",
+    feature = "ord",
 ))]
 pub(crate) mod structural_inclusion {
     //! Helper extensions of [`syn`] types for checking structural inclusion.
@@ -3031,9 +3464,12 @@ pub(crate) mod structural_inclusion {
 #[cfg(any(
     feature = "add",
     feature = "add_assign",
+    feature = "clone",
     feature = "eq",
+    feature = "hash",
     feature = "mul",
     feature = "mul_assign",
+    feature = "ord",
 ))]
 pub(crate) mod pattern_matching {
     //! Helper extensions of [`syn`] types for pattern matching code generation.
@@ -3041,12 +3477,28 @@ pub(crate) mod pattern_matching {
     use proc_macro2::TokenStream;
     use quote::{format_ident, quote};
 
-    #[cfg(any(feature = "add_assign", feature = "eq", feature = "mul_assign"))]
+    #[cfg(any(
+        feature = "add",
+        feature = "add_assign",
+        feature = "eq",
+        feature = "hash",
+        feature = "mul",
+        feature = "mul_assign",
+        feature = "ord",
+    ))]
     use crate::utils::HashSet;
 
     /// Extension of [`syn::Fields`] for pattern matching code generation.
     pub(crate) trait FieldsExt {
-        #[cfg(any(feature = "add_assign", feature = "eq", feature = "mul_assign"))]
+        #[cfg(any(
+            feature = "add",
+            feature = "add_assign",
+            feature = "eq",
+            feature = "hash",
+            feature = "mul",
+            feature = "mul_assign",
+            feature = "ord",
+        ))]
         /// Generates a pattern for matching these [`syn::Fields`] non-exhaustively (considering the
         /// provided `skipped_indices`) in an arm of a `match` expression.
         ///
@@ -3057,7 +3509,7 @@ pub(crate) mod pattern_matching {
             skipped_indices: &HashSet<usize>,
         ) -> TokenStream;
 
-        #[cfg(any(feature = "add", feature = "mul"))]
+        #[cfg(any(feature = "add", feature = "clone", feature = "mul"))]
         /// Generates a pattern for matching these [`syn::Fields`] exhaustively in an arm of a
         /// `match` expression.
         ///
@@ -3066,7 +3518,15 @@ pub(crate) mod pattern_matching {
     }
 
     impl FieldsExt for syn::Fields {
-        #[cfg(any(feature = "add_assign", feature = "eq", feature = "mul_assign"))]
+        #[cfg(any(
+            feature = "add",
+            feature = "add_assign",
+            feature = "eq",
+            feature = "hash",
+            feature = "mul",
+            feature = "mul_assign",
+            feature = "ord",
+        ))]
         fn non_exhaustive_arm_pattern(
             &self,
             prefix: &str,
@@ -3103,7 +3563,7 @@ pub(crate) mod pattern_matching {
             }
         }
 
-        #[cfg(any(feature = "add", feature = "mul"))]
+        #[cfg(any(feature = "add", feature = "clone", feature = "mul"))]
         fn exhaustive_arm_pattern(&self, prefix: &str) -> TokenStream {
             match self {
                 Self::Named(fields) => {
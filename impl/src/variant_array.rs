@@ -0,0 +1,94 @@
+//! Implementation of a [`VariantArray`] derive macro.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned as _;
+
+/// Expands a [`VariantArray`] derive macro.
+pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStream> {
+    let data = match &input.data {
+        syn::Data::Enum(data) => data,
+        syn::Data::Struct(data) => {
+            return Err(syn::Error::new(
+                data.struct_token.span(),
+                "`VariantArray` can be derived only for enums",
+            ))
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "`VariantArray` can be derived only for enums",
+            ))
+        }
+    };
+    if let Some(variant) = data.variants.iter().find(|v| !v.fields.is_empty()) {
+        return Err(syn::Error::new(
+            variant.fields.span(),
+            "`VariantArray` can be derived only for enums with no fields",
+        ));
+    }
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let variants = data.variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+    let count = variants.len();
+    let indices = 0..count;
+
+    let index_body = if variants.is_empty() {
+        quote! { match *self {} }
+    } else {
+        let indices = indices.clone();
+        quote! {
+            match self {
+                #( Self::#variants { .. } => #indices, )*
+            }
+        }
+    };
+
+    Ok(quote! {
+        #[allow(deprecated)] // omit warnings on deprecated variants
+        #[automatically_derived]
+        impl #impl_generics #ty #ty_generics #where_clause {
+            /// All the variants of this enum, in their declaration order.
+            pub const VARIANTS: &'static [Self] = &[ #( Self::#variants {} ),* ];
+
+            /// Number of the variants of this enum.
+            pub const COUNT: usize = #count;
+
+            /// Returns an iterator over all the variants of this enum, in their declaration
+            /// order.
+            #[inline]
+            pub fn iter() -> derive_more::core::iter::Map<
+                derive_more::core::ops::Range<usize>,
+                fn(usize) -> Self,
+            > {
+                let variant: fn(usize) -> Self = |index| match Self::from_index(index) {
+                    derive_more::core::option::Option::Some(variant) => variant,
+                    derive_more::core::option::Option::None => {
+                        derive_more::core::unreachable!()
+                    }
+                };
+                (0..Self::COUNT).map(variant)
+            }
+
+            /// Returns the index of this variant in the [`VARIANTS`](Self::VARIANTS) array.
+            #[inline]
+            #[must_use]
+            pub const fn index(&self) -> usize {
+                #index_body
+            }
+
+            /// Returns the variant with the provided `index` in the
+            /// [`VARIANTS`](Self::VARIANTS) array, if any.
+            #[inline]
+            #[must_use]
+            pub const fn from_index(index: usize) -> derive_more::core::option::Option<Self> {
+                match index {
+                    #( #indices => derive_more::core::option::Option::Some(Self::#variants {}), )*
+                    _ => derive_more::core::option::Option::None,
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,79 @@
+//! Implementation of a [`VariantName`] derive macro.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt as _, spanned::Spanned as _};
+
+use crate::utils::{
+    attr::{self, ParseMultiple as _},
+    Spanning,
+};
+
+/// Expands a [`VariantName`] derive macro.
+pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStream> {
+    let attr_name = format_ident!("variant_name");
+
+    let data = match &input.data {
+        syn::Data::Enum(data) => data,
+        syn::Data::Struct(data) => {
+            return Err(syn::Error::new(
+                data.struct_token.span(),
+                "`VariantName` can be derived only for enums",
+            ))
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "`VariantName` can be derived only for enums",
+            ))
+        }
+    };
+
+    let rename_all = attr::RenameAll::parse_attrs(&input.attrs, &attr_name)?
+        .map(Spanning::into_inner);
+
+    let variants = data.variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+    let names = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let renaming = attr::RenameAll::parse_attrs(&variant.attrs, &attr_name)?
+                .map(Spanning::into_inner)
+                .or(rename_all);
+            let name = variant.ident.unraw().to_string();
+            Ok(match renaming {
+                Some(renaming) => renaming.convert_case(&name),
+                None => name,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let variant_name_body = if variants.is_empty() {
+        quote! { match *self {} }
+    } else {
+        quote! {
+            match self {
+                #( Self::#variants { .. } => #names, )*
+            }
+        }
+    };
+
+    Ok(quote! {
+        #[allow(deprecated)] // omit warnings on deprecated variants
+        #[automatically_derived]
+        impl #impl_generics #ty #ty_generics #where_clause {
+            /// Names of all the variants of this enum, in their declaration order.
+            pub const VARIANT_NAMES: &'static [&'static str] = &[#( #names ),*];
+
+            /// Returns the name of the variant of this value.
+            #[inline]
+            #[must_use]
+            pub const fn variant_name(&self) -> &'static str {
+                #variant_name_body
+            }
+        }
+    })
+}
@@ -0,0 +1,67 @@
+use core::{error::Error, fmt};
+
+/// Error of building a value without setting some of its required fields.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BuilderError {
+    type_name: &'static str,
+    required: &'static [&'static str],
+    missing: u128,
+}
+
+impl BuilderError {
+    #[doc(hidden)]
+    #[must_use]
+    #[inline]
+    pub const fn new(
+        type_name: &'static str,
+        required: &'static [&'static str],
+        missing: u128,
+    ) -> Self {
+        Self {
+            type_name,
+            required,
+            missing,
+        }
+    }
+
+    /// Returns the name of the first (in declaration order) required field that has not been set.
+    #[must_use]
+    #[inline]
+    pub fn field(&self) -> &'static str {
+        self.missing_fields()
+            .next()
+            .unwrap_or_else(|| unreachable!("at least one field is missing"))
+    }
+
+    /// Returns names of all the required fields that have not been set, in declaration order.
+    pub fn missing_fields(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.required
+            .iter()
+            .enumerate()
+            .filter(|(n, _)| self.missing & (1 << n) != 0)
+            .map(|(_, name)| *name)
+    }
+}
+
+impl fmt::Display for BuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Cannot build `{}` without setting its required ",
+            self.type_name
+        )?;
+        for (n, field) in self.missing_fields().enumerate() {
+            if n > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "`{field}`")?;
+        }
+        if self.missing.count_ones() > 1 {
+            write!(f, " fields")
+        } else {
+            write!(f, " field")
+        }
+    }
+}
+
+impl Error for BuilderError {}
@@ -6,6 +6,7 @@
 //! [`TryFrom`]: macro@crate::TryFrom
 //! [`TryInto`]: macro@crate::TryInto
 //! [`IntoIterator`]: macro@crate::IntoIterator
+//! [`IntoStaticStr`]: macro@crate::IntoStaticStr
 //! [`AsRef`]: macro@crate::AsRef
 //!
 //! [`Debug`]: macro@crate::Debug
@@ -25,11 +26,25 @@
 //! [`MulAssign`-like]: macro@crate::MulAssign
 //! [`Eq`]: macro@crate::Eq
 //! [`PartialEq`]: macro@crate::PartialEq
+//! [`Ord`]: macro@crate::Ord
+//! [`PartialOrd`]: macro@crate::PartialOrd
+//! [`Hash`]: macro@crate::Hash
 //!
+//! [`Clone`]: macro@crate::Clone
+//! [`Copy`]: macro@crate::Copy
+//! [`Default`]: macro@crate::Default
+//!
+//! [`Builder`]: macro@crate::Builder
 //! [`Constructor`]: macro@crate::Constructor
+//! [`Getters`]: macro@crate::Getters
+//! [`Setters`]: macro@crate::Setters
 //! [`IsVariant`]: macro@crate::IsVariant
 //! [`Unwrap`]: macro@crate::Unwrap
 //! [`TryUnwrap`]: macro@crate::TryUnwrap
+//! [`Discriminant`]: macro@crate::Discriminant
+//! [`VariantArray`]: macro@crate::VariantArray
+//! [`VariantName`]: macro@crate::VariantName
+//! [`CheckedOps`-like]: macro@crate::CheckedOps
 
 // The README includes doctests requiring these features. To make sure that
 // tests pass when not all features are provided we exclude it when the
@@ -69,21 +84,54 @@ pub mod __private {
 
     #[cfg(feature = "error")]
     pub use crate::as_dyn_error::AsDynError;
+
+    #[cfg(feature = "from_str")]
+    pub use crate::r#str::{
+        ExtractFieldSource, ExtractNoFieldSource, FieldError, FieldSource,
+    };
 }
 
 // The modules containing error types and other helpers.
 
-#[cfg(any(feature = "add", feature = "mul"))]
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "mul",
+    feature = "mul_assign",
+))]
 mod add;
-#[cfg(any(feature = "add", feature = "mul"))]
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "mul",
+    feature = "mul_assign",
+))]
 pub use crate::add::{BinaryError, WrongVariantError};
 
+#[cfg(feature = "builder")]
+mod builder;
+#[cfg(feature = "builder")]
+#[doc(inline)]
+pub use crate::builder::BuilderError;
+
 #[cfg(feature = "eq")]
 mod cmp;
 
-#[cfg(any(feature = "add", feature = "not", feature = "mul"))]
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "not",
+    feature = "mul",
+    feature = "mul_assign",
+))]
 mod ops;
-#[cfg(any(feature = "add", feature = "not", feature = "mul"))]
+#[cfg(any(
+    feature = "add",
+    feature = "add_assign",
+    feature = "not",
+    feature = "mul",
+    feature = "mul_assign",
+))]
 pub use crate::ops::UnitError;
 
 #[cfg(feature = "as_ref")]
@@ -183,7 +231,10 @@ pub mod with_trait {
             SubAssign,
         );
         re_export_traits!("as_ref", as_ref_traits, core::convert, AsMut, AsRef);
+        re_export_traits!("clone", clone_traits, core::clone, Clone);
+        re_export_traits!("clone", copy_traits, core::marker, Copy);
         re_export_traits!("debug", debug_traits, core::fmt, Debug);
+        re_export_traits!("default_derive", default_traits, core::default, Default);
         re_export_traits!("deref", deref_traits, core::ops, Deref);
         re_export_traits!("deref_mut", deref_mut_traits, core::ops, DerefMut);
         re_export_traits!(
@@ -208,6 +259,8 @@ pub mod with_trait {
 
         re_export_traits!("from_str", from_str_traits, core::str, FromStr);
 
+        re_export_traits!("hash", hash_traits, core::hash, Hash);
+
         re_export_traits!("index", index_traits, core::ops, Index);
 
         re_export_traits!("index_mut", index_mut_traits, core::ops, IndexMut);
@@ -237,6 +290,8 @@ pub mod with_trait {
 
         re_export_traits!("not", not_traits, core::ops, Neg, Not);
 
+        re_export_traits!("ord", ord_traits, core::cmp, Ord, PartialOrd);
+
         re_export_traits!("sum", sum_traits, core::iter, Product, Sum);
 
         re_export_traits!("try_from", try_from_traits, core::convert, TryFrom);
@@ -245,6 +300,9 @@ pub mod with_trait {
 
         // Now re-export our own derives by their exact name to overwrite any derives that the trait
         // re-exporting might inadvertently pull into scope.
+        #[cfg(feature = "accessors")]
+        pub use derive_more_impl::{Getters, Setters};
+
         #[cfg(feature = "add")]
         pub use derive_more_impl::{Add, BitAnd, BitOr, BitXor, Sub};
 
@@ -256,18 +314,33 @@ pub mod with_trait {
         #[cfg(feature = "as_ref")]
         pub use derive_more_impl::{AsMut, AsRef};
 
+        #[cfg(feature = "builder")]
+        pub use derive_more_impl::Builder;
+
+        #[cfg(feature = "checked_ops")]
+        pub use derive_more_impl::{CheckedOps, SaturatingOps, WrappingOps};
+
+        #[cfg(feature = "clone")]
+        pub use derive_more_impl::{Clone, Copy};
+
         #[cfg(feature = "constructor")]
         pub use derive_more_impl::Constructor;
 
         #[cfg(feature = "debug")]
         pub use derive_more_impl::Debug;
 
+        #[cfg(feature = "default_derive")]
+        pub use derive_more_impl::Default;
+
         #[cfg(feature = "deref")]
         pub use derive_more_impl::Deref;
 
         #[cfg(feature = "deref_mut")]
         pub use derive_more_impl::DerefMut;
 
+        #[cfg(feature = "discriminant")]
+        pub use derive_more_impl::Discriminant;
+
         #[cfg(feature = "display")]
         pub use derive_more_impl::{
             Binary, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex,
@@ -285,6 +358,9 @@ pub mod with_trait {
         #[cfg(feature = "from_str")]
         pub use derive_more_impl::FromStr;
 
+        #[cfg(feature = "hash")]
+        pub use derive_more_impl::Hash;
+
         #[cfg(feature = "index")]
         pub use derive_more_impl::Index;
 
@@ -297,6 +373,9 @@ pub mod with_trait {
         #[cfg(feature = "into_iterator")]
         pub use derive_more_impl::IntoIterator;
 
+        #[cfg(feature = "into_static_str")]
+        pub use derive_more_impl::IntoStaticStr;
+
         #[cfg(feature = "is_variant")]
         pub use derive_more_impl::IsVariant;
 
@@ -311,6 +390,9 @@ pub mod with_trait {
         #[cfg(feature = "not")]
         pub use derive_more_impl::{Neg, Not};
 
+        #[cfg(feature = "ord")]
+        pub use derive_more_impl::{Ord, PartialOrd};
+
         #[cfg(feature = "sum")]
         pub use derive_more_impl::{Product, Sum};
 
@@ -325,6 +407,12 @@ pub mod with_trait {
 
         #[cfg(feature = "unwrap")]
         pub use derive_more_impl::Unwrap;
+
+        #[cfg(feature = "variant_array")]
+        pub use derive_more_impl::VariantArray;
+
+        #[cfg(feature = "variant_name")]
+        pub use derive_more_impl::VariantName;
     }
 
     // Now re-export our own derives and the std traits by their exact name to make rust-analyzer
@@ -332,6 +420,10 @@ pub mod with_trait {
     // See issues:
     // 1. https://github.com/rust-lang/rust-analyzer/issues/11698
     // 2. https://github.com/rust-lang/rust-analyzer/issues/14079
+    #[cfg(feature = "accessors")]
+    #[doc(hidden)]
+    pub use all_traits_and_derives::{Getters, Setters};
+
     #[cfg(feature = "add")]
     #[doc(hidden)]
     pub use all_traits_and_derives::{Add, BitAnd, BitOr, BitXor, Sub};
@@ -346,6 +438,18 @@ pub mod with_trait {
     #[doc(hidden)]
     pub use all_traits_and_derives::{AsMut, AsRef};
 
+    #[cfg(feature = "builder")]
+    #[doc(hidden)]
+    pub use all_traits_and_derives::Builder;
+
+    #[cfg(feature = "checked_ops")]
+    #[doc(hidden)]
+    pub use all_traits_and_derives::{CheckedOps, SaturatingOps, WrappingOps};
+
+    #[cfg(feature = "clone")]
+    #[doc(hidden)]
+    pub use all_traits_and_derives::{Clone, Copy};
+
     #[cfg(feature = "constructor")]
     #[doc(hidden)]
     pub use all_traits_and_derives::Constructor;
@@ -354,6 +458,10 @@ pub mod with_trait {
     #[doc(hidden)]
     pub use all_traits_and_derives::Debug;
 
+    #[cfg(feature = "default_derive")]
+    #[doc(hidden)]
+    pub use all_traits_and_derives::Default;
+
     #[cfg(feature = "deref")]
     #[doc(hidden)]
     pub use all_traits_and_derives::Deref;
@@ -362,6 +470,10 @@ pub mod with_trait {
     #[doc(hidden)]
     pub use all_traits_and_derives::DerefMut;
 
+    #[cfg(feature = "discriminant")]
+    #[doc(hidden)]
+    pub use all_traits_and_derives::Discriminant;
+
     #[cfg(feature = "display")]
     #[doc(hidden)]
     pub use all_traits_and_derives::{
@@ -384,6 +496,10 @@ pub mod with_trait {
     #[doc(hidden)]
     pub use all_traits_and_derives::FromStr;
 
+    #[cfg(feature = "hash")]
+    #[doc(hidden)]
+    pub use all_traits_and_derives::Hash;
+
     #[cfg(feature = "index")]
     #[doc(hidden)]
     pub use all_traits_and_derives::Index;
@@ -400,6 +516,10 @@ pub mod with_trait {
     #[doc(hidden)]
     pub use all_traits_and_derives::IntoIterator;
 
+    #[cfg(feature = "into_static_str")]
+    #[doc(hidden)]
+    pub use all_traits_and_derives::IntoStaticStr;
+
     #[cfg(feature = "is_variant")]
     #[doc(hidden)]
     pub use all_traits_and_derives::IsVariant;
@@ -418,6 +538,10 @@ pub mod with_trait {
     #[doc(hidden)]
     pub use all_traits_and_derives::{Neg, Not};
 
+    #[cfg(feature = "ord")]
+    #[doc(hidden)]
+    pub use all_traits_and_derives::{Ord, PartialOrd};
+
     #[cfg(feature = "sum")]
     #[doc(hidden)]
     pub use all_traits_and_derives::{Product, Sum};
@@ -438,6 +562,14 @@ pub mod with_trait {
     #[doc(hidden)]
     pub use all_traits_and_derives::Unwrap;
 
+    #[cfg(feature = "variant_array")]
+    #[doc(hidden)]
+    pub use all_traits_and_derives::VariantArray;
+
+    #[cfg(feature = "variant_name")]
+    #[doc(hidden)]
+    pub use all_traits_and_derives::VariantName;
+
     // Re-export the derive macros again to show docs for our derives (but not for traits). This is
     // done using a glob import to not hit E0252.
     #[allow(unused_imports)]
@@ -447,31 +579,42 @@ pub mod with_trait {
 // Check if any feature is enabled
 #[cfg(not(any(
     feature = "full",
+    feature = "accessors",
     feature = "add",
     feature = "add_assign",
     feature = "as_ref",
+    feature = "builder",
+    feature = "checked_ops",
+    feature = "clone",
     feature = "constructor",
     feature = "debug",
+    feature = "default_derive",
     feature = "deref",
     feature = "deref_mut",
+    feature = "discriminant",
     feature = "display",
     feature = "eq",
     feature = "error",
     feature = "from",
     feature = "from_str",
+    feature = "hash",
     feature = "index",
     feature = "index_mut",
     feature = "into",
     feature = "into_iterator",
+    feature = "into_static_str",
     feature = "is_variant",
     feature = "mul",
     feature = "mul_assign",
     feature = "not",
+    feature = "ord",
     feature = "sum",
     feature = "try_from",
     feature = "try_into",
     feature = "try_unwrap",
     feature = "unwrap",
+    feature = "variant_array",
+    feature = "variant_name",
 )))]
 compile_error!(
     "at least one derive feature must be enabled (or the \"full\" feature enabling all the derives)"
@@ -1,9 +1,22 @@
 use core::{error::Error, fmt};
+#[cfg(feature = "std")]
+use std::sync::Arc;
 
 /// Error of parsing an enum value its string representation.
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+///
+/// When parsing according to a format string, it also reports the field (and the enum variant,
+/// if any) whose value failed to be parsed. With the `std` feature enabled, the error of parsing
+/// this field is available as the [`Error::source()`], if it implements [`Error`] and is
+/// [`Send`] + [`Sync`] + `'static`. Otherwise, the cause is not preserved.
+///
+/// Two [`FromStrError`]s are equal if they report the same type, variant and field, regardless of
+/// their sources.
+#[derive(Debug, Clone)]
 pub struct FromStrError {
     type_name: &'static str,
+    variant: Option<&'static str>,
+    field: Option<&'static str>,
+    source: FieldSource,
 }
 
 impl FromStrError {
@@ -11,14 +24,138 @@ impl FromStrError {
     #[must_use]
     #[inline]
     pub const fn new(type_name: &'static str) -> Self {
-        Self { type_name }
+        Self {
+            type_name,
+            variant: None,
+            field: None,
+            source: FieldSource::NONE,
+        }
+    }
+
+    #[doc(hidden)]
+    #[must_use]
+    #[inline]
+    pub const fn new_field(
+        type_name: &'static str,
+        variant: Option<&'static str>,
+        field: &'static str,
+        source: FieldSource,
+    ) -> Self {
+        Self {
+            type_name,
+            variant,
+            field: Some(field),
+            source,
+        }
+    }
+
+    /// Returns the name of the enum variant whose field failed to be parsed, if any.
+    #[must_use]
+    #[inline]
+    pub const fn variant(&self) -> Option<&'static str> {
+        self.variant
+    }
+
+    /// Returns the name of the field failed to be parsed, if any.
+    ///
+    /// Unnamed fields are named by their index prefixed with `_` (like `_0`).
+    #[must_use]
+    #[inline]
+    pub const fn field(&self) -> Option<&'static str> {
+        self.field
     }
 }
 
+impl PartialEq for FromStrError {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_name == other.type_name
+            && self.variant == other.variant
+            && self.field == other.field
+    }
+}
+
+impl Eq for FromStrError {}
+
 impl fmt::Display for FromStrError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Invalid `{}` string representation", self.type_name)
+        write!(f, "Invalid `{}` string representation", self.type_name)?;
+        match (self.variant, self.field) {
+            (Some(variant), Some(field)) => {
+                write!(f, ": cannot parse `{field}` field of `{variant}` variant")
+            }
+            (None, Some(field)) => write!(f, ": cannot parse `{field}` field"),
+            (_, None) => Ok(()),
+        }
+    }
+}
+
+impl Error for FromStrError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.get()
+    }
+}
+
+/// Error of parsing a field, preserved as a [`FromStrError`] source.
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct FieldSource {
+    #[cfg(feature = "std")]
+    error: Option<Arc<dyn Error + Send + Sync + 'static>>,
+}
+
+impl FieldSource {
+    /// [`FieldSource`] not preserving any error.
+    const NONE: Self = Self {
+        #[cfg(feature = "std")]
+        error: None,
+    };
+
+    /// Returns the preserved error, if any.
+    fn get(&self) -> Option<&(dyn Error + 'static)> {
+        #[cfg(feature = "std")]
+        if let Some(error) = &self.error {
+            return Some(&**error);
+        }
+        None
+    }
+}
+
+/// Container of a field parsing error to [autoref-based specialize][0] over in `FromStr` macro
+/// expansion.
+///
+/// Allows to preserve a field parsing error as a [`FieldSource`] whenever it implements [`Error`],
+/// without requiring it to.
+///
+/// [0]: https://lukaskalbertodt.github.io/2019/12/05/generalized-autoref-based-specialization.html
+#[doc(hidden)]
+pub struct FieldError<E>(pub E);
+
+/// Trait preserving a field parsing error implementing [`Error`].
+#[doc(hidden)]
+pub trait ExtractFieldSource {
+    /// Converts this field parsing error into a [`FieldSource`].
+    fn __extract_field_source(self) -> FieldSource;
+}
+
+impl<E: Error + Send + Sync + 'static> ExtractFieldSource for FieldError<E> {
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    fn __extract_field_source(self) -> FieldSource {
+        FieldSource {
+            #[cfg(feature = "std")]
+            error: Some(Arc::new(self.0)),
+        }
     }
 }
 
-impl Error for FromStrError {}
+/// Trait discarding a field parsing error not implementing [`Error`].
+#[doc(hidden)]
+pub trait ExtractNoFieldSource {
+    /// Discards this field parsing error, returning an empty [`FieldSource`].
+    fn __extract_field_source(self) -> FieldSource;
+}
+
+impl<E> ExtractNoFieldSource for &FieldError<E> {
+    fn __extract_field_source(self) -> FieldSource {
+        FieldSource::NONE
+    }
+}
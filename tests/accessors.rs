@@ -0,0 +1,295 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+mod getters {
+    #[cfg(not(feature = "std"))]
+    use ::alloc::{boxed::Box, string::String, vec, vec::Vec};
+
+    use derive_more::Getters;
+
+    #[test]
+    fn by_reference() {
+        #[derive(Getters)]
+        struct Foo {
+            a: i32,
+            b: String,
+        }
+
+        let foo = Foo {
+            a: 1,
+            b: "b".into(),
+        };
+        assert_eq!(*foo.a(), 1);
+        assert_eq!(foo.b(), "b");
+    }
+
+    #[test]
+    fn copy() {
+        #[derive(Getters)]
+        struct Foo {
+            #[getter(copy)]
+            a: i32,
+            #[getter(copy)]
+            b: Option<char>,
+        }
+
+        const FOO: Foo = Foo { a: 1, b: None };
+        const A: i32 = FOO.a();
+
+        assert_eq!(A, 1);
+        assert_eq!(FOO.b(), None);
+    }
+
+    #[test]
+    fn deref() {
+        #[derive(Getters)]
+        struct Foo {
+            #[getter(deref)]
+            a: String,
+            #[getter(deref)]
+            b: Vec<u8>,
+            #[getter(deref)]
+            c: Box<i32>,
+        }
+
+        let foo = Foo {
+            a: "a".into(),
+            b: vec![1, 2],
+            c: Box::new(3),
+        };
+        let a: &str = foo.a();
+        let b: &[u8] = foo.b();
+        let c: &i32 = foo.c();
+        assert_eq!(a, "a");
+        assert_eq!(b, [1, 2]);
+        assert_eq!(*c, 3);
+    }
+
+    #[test]
+    fn skip() {
+        #[derive(Getters)]
+        struct Foo {
+            a: i32,
+            #[getter(skip)]
+            b: i32,
+            #[getter(ignore)]
+            c: i32,
+        }
+
+        trait B {
+            fn b(&self) -> bool {
+                true
+            }
+
+            fn c(&self) -> bool {
+                true
+            }
+        }
+
+        impl B for Foo {}
+
+        let foo = Foo { a: 1, b: 2, c: 3 };
+        assert_eq!(*foo.a(), 1);
+        assert!(foo.b());
+        assert!(foo.c());
+    }
+
+    #[test]
+    fn opt_in() {
+        #[derive(Getters)]
+        #[getter(skip)]
+        struct Foo {
+            #[getter]
+            a: i32,
+            b: i32,
+            #[getter(copy)]
+            c: i32,
+        }
+
+        trait B {
+            fn b(&self) -> bool {
+                true
+            }
+        }
+
+        impl B for Foo {}
+
+        let foo = Foo { a: 1, b: 2, c: 3 };
+        assert_eq!(*foo.a(), 1);
+        assert!(foo.b());
+        assert_eq!(foo.c(), 3);
+    }
+
+    #[test]
+    fn raw_identifier() {
+        #[derive(Getters)]
+        struct Foo {
+            r#type: u8,
+        }
+
+        assert_eq!(*Foo { r#type: 1 }.r#type(), 1);
+    }
+
+    mod visibility {
+        mod inner {
+            use derive_more::Getters;
+
+            #[derive(Default, Getters)]
+            #[getter(vis = pub(crate))]
+            pub struct Foo {
+                a: u8,
+                #[getter(vis = pub)]
+                b: u8,
+                #[getter(vis = pub(self))]
+                c: u8,
+            }
+
+            impl Foo {
+                pub fn sum(&self) -> u8 {
+                    self.c() + 1
+                }
+            }
+        }
+
+        mod inherited {
+            use derive_more::Getters;
+
+            #[derive(Default, Getters)]
+            pub(crate) struct Bar {
+                a: u8,
+            }
+        }
+
+        #[test]
+        fn assert() {
+            let foo = inner::Foo::default();
+            assert_eq!(*foo.a(), 0);
+            assert_eq!(*foo.b(), 0);
+            assert_eq!(foo.sum(), 1);
+        }
+
+        #[test]
+        fn inherited() {
+            assert_eq!(*inherited::Bar::default().a(), 0);
+        }
+    }
+
+    #[test]
+    fn generic() {
+        #[derive(Getters)]
+        struct Foo<'a, T: Clone, const N: usize>
+        where
+            T: Copy,
+        {
+            #[getter(copy)]
+            a: T,
+            #[getter(deref)]
+            b: Vec<T>,
+            c: &'a [T; N],
+        }
+
+        let arr = [3, 4];
+        let foo = Foo {
+            a: 1,
+            b: vec![2],
+            c: &arr,
+        };
+        assert_eq!(foo.a(), 1);
+        assert_eq!(foo.b(), [2]);
+        assert_eq!(*foo.c(), &[3, 4]);
+    }
+}
+
+mod setters {
+    #[cfg(not(feature = "std"))]
+    use ::alloc::string::String;
+
+    use derive_more::Setters;
+
+    #[test]
+    fn chained() {
+        #[derive(Debug, PartialEq, Setters)]
+        struct Foo {
+            a: i32,
+            b: String,
+        }
+
+        let mut foo = Foo {
+            a: 1,
+            b: "b".into(),
+        };
+        foo.set_a(2).set_b("c".into());
+        assert_eq!(
+            foo,
+            Foo {
+                a: 2,
+                b: "c".into(),
+            },
+        );
+    }
+
+    #[test]
+    fn into() {
+        #[derive(Debug, PartialEq, Setters)]
+        struct Foo {
+            #[setter(into)]
+            a: String,
+            #[setter(into)]
+            b: u64,
+        }
+
+        let mut foo = Foo {
+            a: "a".into(),
+            b: 0,
+        };
+        foo.set_a("b").set_b(1_u8);
+        assert_eq!(
+            foo,
+            Foo {
+                a: "b".into(),
+                b: 1,
+            },
+        );
+    }
+
+    #[test]
+    fn opt_in() {
+        #[derive(Debug, PartialEq, Setters)]
+        #[setter(skip)]
+        struct Foo {
+            #[setter(into)]
+            a: u16,
+            b: u16,
+        }
+
+        trait SetB {
+            fn set_b(&mut self) -> bool {
+                true
+            }
+        }
+
+        impl SetB for Foo {}
+
+        let mut foo = Foo { a: 0, b: 0 };
+        foo.set_a(1_u8);
+        assert!(foo.set_b());
+        assert_eq!(foo, Foo { a: 1, b: 0 });
+    }
+
+    #[test]
+    fn generic() {
+        #[derive(Debug, PartialEq, Setters)]
+        struct Foo<T> {
+            a: T,
+            #[setter(into)]
+            b: Option<T>,
+        }
+
+        let mut foo = Foo { a: 1, b: None };
+        foo.set_a(2).set_b(3);
+        assert_eq!(foo, Foo { a: 2, b: Some(3) });
+    }
+}
@@ -108,6 +108,243 @@ mod structs {
             assert_eq!((a + b).x, 14);
         }
     }
+
+    mod by_ref {
+        use core::marker::PhantomData;
+
+        use derive_more::{Add, Sub};
+
+        #[derive(Add, Debug, PartialEq, Sub)]
+        #[add(ref)]
+        #[sub(ref)]
+        struct Matrix(i32, i32);
+
+        #[test]
+        fn tuple() {
+            let a = Matrix(1, 2);
+            let b = Matrix(3, 5);
+
+            assert_eq!(&a + &b, Matrix(4, 7));
+            assert_eq!(&a + Matrix(1, 1), Matrix(2, 3));
+            assert_eq!(Matrix(1, 1) + &b, Matrix(4, 6));
+            assert_eq!(&b - &a, Matrix(2, 3));
+            assert_eq!(a + b, Matrix(4, 7));
+        }
+
+        #[test]
+        fn generic_struct() {
+            #[derive(Add, Debug, PartialEq)]
+            #[add(ref)]
+            struct Point<T, M> {
+                x: T,
+                y: T,
+                #[add(skip)]
+                marker: PhantomData<M>,
+            }
+
+            let a = Point::<_, ()> {
+                x: 1,
+                y: 2,
+                marker: PhantomData,
+            };
+            let b = Point {
+                x: 3,
+                y: 4,
+                marker: PhantomData,
+            };
+
+            assert_eq!(
+                &a + &b,
+                Point {
+                    x: 4,
+                    y: 6,
+                    marker: PhantomData,
+                },
+            );
+        }
+    }
+
+    mod forward {
+        use core::time::Duration;
+
+        use derive_more::{Add, Sub};
+
+        #[test]
+        fn concrete() {
+            #[derive(Add, Clone, Copy, Debug, PartialEq, Sub)]
+            #[add(forward)]
+            #[sub(forward)]
+            struct Timeout(Duration);
+
+            let timeout = Timeout(Duration::from_secs(2));
+
+            assert_eq!(
+                timeout + Duration::from_secs(1),
+                Timeout(Duration::from_secs(3)),
+            );
+            assert_eq!(
+                timeout - Duration::from_secs(1),
+                Timeout(Duration::from_secs(1)),
+            );
+        }
+
+        #[test]
+        #[allow(clippy::op_ref)] // forwarding to reference implementation is tested
+        fn generic() {
+            #[derive(Add, Debug, PartialEq)]
+            #[add(forward)]
+            struct Wrapper<T> {
+                inner: T,
+            }
+
+            assert_eq!(Wrapper { inner: 1 } + 2, Wrapper { inner: 3 });
+            assert_eq!(Wrapper { inner: 1 } + &2, Wrapper { inner: 3 });
+        }
+
+        #[test]
+        fn generic_output() {
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            struct Meters(u32);
+
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            struct Offset(Meters);
+
+            impl core::ops::Add<u32> for Meters {
+                type Output = Offset;
+
+                fn add(self, rhs: u32) -> Offset {
+                    Offset(Meters(self.0 + rhs))
+                }
+            }
+
+            #[derive(Add, Debug, PartialEq)]
+            #[add(forward)]
+            struct Wrapper<T: Copy>(T)
+            where
+                T: core::fmt::Debug;
+
+            assert_eq!(Wrapper(Meters(1)) + 2, Wrapper(Offset(Meters(3))));
+        }
+    }
+
+    mod typed {
+        use derive_more::{Add, Sub};
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Seconds(u64);
+
+        #[derive(Add, Clone, Copy, Debug, PartialEq, Sub)]
+        #[add(Seconds, output = Timestamp)]
+        #[sub(Seconds, output = Timestamp)]
+        #[sub(Self, output = Seconds)]
+        struct Timestamp(u64);
+
+        #[test]
+        fn tuple() {
+            assert_eq!(Timestamp(10) + Timestamp(2), Timestamp(12));
+            assert_eq!(Timestamp(10) + Seconds(2), Timestamp(12));
+            assert_eq!(Timestamp(10) - Seconds(2), Timestamp(8));
+            assert_eq!(Timestamp(10) - Timestamp(2), Seconds(8));
+        }
+
+        #[test]
+        fn struct_() {
+            #[derive(Add, Debug, PartialEq)]
+            #[add(i64, output = i64)]
+            struct Offset {
+                value: i64,
+            }
+
+            assert_eq!(
+                Offset { value: 1 } + Offset { value: 2 },
+                Offset { value: 3 }
+            );
+            assert_eq!(Offset { value: 1 } + 2, 3);
+        }
+    }
+
+    mod const_ {
+        use core::marker::PhantomData;
+
+        use derive_more::{Add, BitXor, Sub};
+
+        #[derive(Add, Clone, Copy, Debug, PartialEq, Sub)]
+        #[add(const)]
+        #[sub(const)]
+        struct Vec2 {
+            x: i64,
+            y: i64,
+        }
+
+        const SUM: Vec2 = Vec2 { x: 1, y: 2 }.add(Vec2 { x: 3, y: 4 });
+        const DIFF: Vec2 = SUM.sub(Vec2 { x: 1, y: 1 });
+
+        #[test]
+        fn struct_() {
+            assert_eq!(SUM, Vec2 { x: 4, y: 6 });
+            assert_eq!(DIFF, Vec2 { x: 3, y: 5 });
+            assert_eq!(SUM - DIFF, Vec2 { x: 1, y: 1 });
+        }
+
+        #[test]
+        fn tuple() {
+            #[derive(BitXor, Debug, PartialEq)]
+            #[bitxor(const)]
+            struct Flags(u8, u16);
+
+            const FLAGS: Flags = Flags(0b11, 0b101).bitxor(Flags(0b01, 0b100));
+
+            assert_eq!(FLAGS, Flags(0b10, 0b001));
+            assert_eq!(Flags(0b11, 0b101) ^ Flags(0b01, 0b100), FLAGS);
+        }
+
+        #[test]
+        fn generic_ignore() {
+            #[derive(Add)]
+            #[add(const)]
+            struct Money<C> {
+                cents: i64,
+                #[add(skip)]
+                currency: PhantomData<C>,
+            }
+
+            struct Euro;
+
+            const TOTAL: Money<Euro> = Money {
+                cents: 150,
+                currency: PhantomData,
+            }
+            .add(Money {
+                cents: 50,
+                currency: PhantomData,
+            });
+
+            assert_eq!(TOTAL.cents, 200);
+        }
+
+        #[test]
+        fn primitive_ignore() {
+            #[derive(Add)]
+            #[add(const)]
+            struct Tagged {
+                value: u32,
+                #[add(skip)]
+                tag: u8,
+            }
+
+            const TAGGED: Tagged = Tagged {
+                value: 1,
+                tag: 1,
+            }
+            .add(Tagged {
+                value: 2,
+                tag: 2,
+            });
+
+            assert_eq!(TAGGED.value, 3);
+            assert_eq!(TAGGED.tag, 1);
+        }
+    }
 }
 
 mod enums {
@@ -173,6 +410,39 @@ mod enums {
         );
     }
 
+    mod by_ref {
+        use derive_more::Add;
+
+        #[test]
+        fn empty() {
+            #[derive(Add)]
+            #[add(ref)]
+            enum Empty {}
+        }
+
+        #[test]
+        fn output_policy() {
+            #[derive(Add, Debug, PartialEq)]
+            #[add(ref, output = option)]
+            enum Amount {
+                Cents(u64),
+                Units { value: u64 },
+                Unknown,
+            }
+
+            let a = Amount::Cents(1);
+
+            assert_eq!(&a + &Amount::Cents(2), Some(Amount::Cents(3)));
+            assert_eq!(a + &Amount::Cents(2), Some(Amount::Cents(3)));
+            assert_eq!(
+                &Amount::Units { value: 1 } + Amount::Units { value: 2 },
+                Some(Amount::Units { value: 3 }),
+            );
+            assert_eq!(&Amount::Unknown + &Amount::Unknown, Some(Amount::Unknown));
+            assert_eq!(&Amount::Cents(1) + &Amount::Unknown, None);
+        }
+    }
+
     mod ignore {
         #[cfg(not(feature = "std"))]
         use alloc::string::ToString as _;
@@ -216,4 +486,82 @@ mod enums {
             );
         }
     }
+    mod output {
+        use derive_more::{Add, Sub};
+
+        #[derive(Add, Sub, Clone, Copy, Debug, PartialEq)]
+        #[add(output = panic)]
+        #[sub(output = option)]
+        enum Length {
+            Mm(u32),
+            In { value: u32 },
+            Unknown,
+        }
+
+        #[test]
+        fn panic() {
+            assert_eq!(Length::Mm(1) + Length::Mm(2), Length::Mm(3));
+            assert_eq!(
+                Length::In { value: 1 } + Length::In { value: 2 },
+                Length::In { value: 3 },
+            );
+            assert_eq!(Length::Unknown + Length::Unknown, Length::Unknown);
+        }
+
+        #[test]
+        #[should_panic(expected = "Trying to add() mismatched enum variants")]
+        fn panic_on_mismatch() {
+            _ = Length::Mm(1) + Length::In { value: 2 };
+        }
+
+        #[test]
+        fn option() {
+            assert_eq!(Length::Mm(3) - Length::Mm(2), Some(Length::Mm(1)));
+            assert_eq!(Length::Unknown - Length::Unknown, Some(Length::Unknown));
+            assert_eq!(Length::Mm(3) - Length::In { value: 2 }, None);
+        }
+
+        #[test]
+        fn result() {
+            #[derive(Add, Debug, PartialEq)]
+            #[add(output = result)]
+            enum Length {
+                Mm(u32),
+                Unknown,
+            }
+
+            assert_eq!((Length::Mm(1) + Length::Mm(2)).unwrap(), Length::Mm(3));
+            assert!((Length::Unknown + Length::Unknown).is_err());
+        }
+
+        #[test]
+        fn generic() {
+            fn sum<T: core::ops::Add<Output = T>>(a: T, b: T, c: T) -> T {
+                a + b + c
+            }
+
+            #[derive(Add, Debug, PartialEq)]
+            #[add(output = panic)]
+            enum Either<L, R> {
+                Left(L),
+                Right(R),
+            }
+
+            assert_eq!(
+                sum(Either::<i32, u8>::Left(1), Either::Left(2), Either::Left(3)),
+                Either::Left(6),
+            );
+        }
+
+        #[test]
+        fn empty() {
+            #[derive(Add)]
+            #[add(output = panic)]
+            enum Panicking {}
+
+            #[derive(Add)]
+            #[add(output = option)]
+            enum Optional {}
+        }
+    }
 }
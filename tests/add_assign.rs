@@ -104,3 +104,90 @@ mod structs {
         }
     }
 }
+
+mod enums {
+    use derive_more::{AddAssign, BitXorAssign, SubAssign};
+
+    #[derive(AddAssign, SubAssign, Debug, PartialEq)]
+    #[add_assign(panic)]
+    #[sub_assign(panic)]
+    enum Length {
+        Mm(u32),
+        In { value: u32 },
+        Unknown,
+    }
+
+    #[test]
+    fn panic() {
+        let mut a = Length::Mm(10);
+        a += Length::Mm(5);
+        assert_eq!(a, Length::Mm(15));
+        a -= Length::Mm(3);
+        assert_eq!(a, Length::Mm(12));
+
+        let mut b = Length::In { value: 1 };
+        b += Length::In { value: 2 };
+        assert_eq!(b, Length::In { value: 3 });
+
+        let mut unit = Length::Unknown;
+        unit += Length::Unknown;
+        assert_eq!(unit, Length::Unknown);
+    }
+
+    #[test]
+    #[should_panic(expected = "Trying to add_assign() mismatched enum variants")]
+    fn panic_on_mismatch() {
+        let mut a = Length::Mm(10);
+        a += Length::In { value: 1 };
+    }
+
+    #[test]
+    fn try_() {
+        #[derive(AddAssign, BitXorAssign, Debug, PartialEq)]
+        #[add_assign(try)]
+        #[bitxor_assign(try)]
+        enum Flags {
+            Small(u8),
+            Large(u32, #[add_assign(skip)] bool),
+        }
+
+        let mut a = Flags::Small(1);
+        assert!(a.try_add_assign(Flags::Small(2)).is_ok());
+        assert_eq!(a, Flags::Small(3));
+        assert!(a.try_bitxor_assign(Flags::Small(1)).is_ok());
+        assert_eq!(a, Flags::Small(2));
+
+        let mut b = Flags::Large(1, true);
+        assert!(b.try_add_assign(Flags::Large(2, false)).is_ok());
+        assert_eq!(b, Flags::Large(3, true));
+
+        let err = a.try_add_assign(Flags::Large(1, true)).unwrap_err();
+        assert!(matches!(err, derive_more::BinaryError::Mismatch(_)));
+        assert_eq!(a, Flags::Small(2));
+    }
+
+    #[test]
+    fn generic() {
+        #[derive(AddAssign, Debug, PartialEq)]
+        #[add_assign(panic)]
+        enum Either<L, R> {
+            Left(L),
+            Right(R),
+        }
+
+        let mut a = Either::<i32, u8>::Left(1);
+        a += Either::Left(2);
+        assert_eq!(a, Either::Left(3));
+    }
+
+    #[test]
+    fn empty() {
+        #[derive(AddAssign)]
+        #[add_assign(panic)]
+        enum Never {}
+
+        #[derive(AddAssign)]
+        #[add_assign(try)]
+        enum TryNever {}
+    }
+}
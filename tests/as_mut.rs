@@ -7,7 +7,9 @@
 extern crate alloc;
 
 #[cfg(not(feature = "std"))]
-use alloc::{borrow::ToOwned, collections::VecDeque, string::String, vec, vec::Vec};
+use alloc::{
+    borrow::ToOwned, boxed::Box, collections::VecDeque, string::String, vec, vec::Vec,
+};
 
 #[cfg(feature = "std")]
 use std::collections::VecDeque;
@@ -1812,3 +1814,203 @@ mod multi_field {
         }
     }
 }
+
+mod enums {
+    use super::*;
+
+    #[test]
+    fn nothing() {
+        #[derive(AsMut)]
+        enum Nothing {
+            Tuple(String),
+            Named { first: String },
+        }
+
+        let mut item = Nothing::Tuple("test".to_owned());
+        let rf: &mut String = item.as_mut();
+        rf.push('!');
+        let Nothing::Tuple(first) = &mut item else {
+            unreachable!()
+        };
+        assert_eq!(first, "test!");
+
+        let mut item = Nothing::Named {
+            first: "test".to_owned(),
+        };
+        let rf: *const String = item.as_mut();
+        let Nothing::Named { first } = &mut item else {
+            unreachable!()
+        };
+        assert!(ptr::eq(rf, first));
+    }
+
+    #[test]
+    fn field() {
+        #[derive(AsMut)]
+        enum Field {
+            Tuple(i32, #[as_mut] String),
+            Named {
+                #[as_mut]
+                first: String,
+                second: i32,
+            },
+        }
+
+        let mut item = Field::Tuple(1, "test".to_owned());
+        let rf: *const String = item.as_mut();
+        let Field::Tuple(_, second) = &mut item else {
+            unreachable!()
+        };
+        assert!(ptr::eq(rf, second));
+    }
+
+    #[test]
+    fn skip() {
+        #[derive(AsMut)]
+        enum Skip {
+            Tuple(#[as_mut(skip)] i32, String),
+            Named {
+                first: String,
+                #[as_mut(ignore)]
+                second: i32,
+            },
+        }
+
+        let mut item = Skip::Named {
+            first: "b".to_owned(),
+            second: 2,
+        };
+        let rf: &mut String = item.as_mut();
+        assert_eq!(rf, "b");
+    }
+
+    #[test]
+    fn forward() {
+        #[derive(AsMut)]
+        #[as_mut(forward)]
+        enum Forward {
+            Str(String),
+            Boxed(i32, #[as_mut] Box<str>),
+        }
+
+        let mut item = Forward::Str("test".to_owned());
+        let rf: &mut str = item.as_mut();
+        rf.make_ascii_uppercase();
+        let Forward::Str(first) = &item else {
+            unreachable!()
+        };
+        assert_eq!(first, "TEST");
+
+        let mut item = Forward::Boxed(1, "boxed".into());
+        let rf: &mut str = item.as_mut();
+        assert_eq!(rf, "boxed");
+    }
+
+    #[test]
+    fn types() {
+        #[derive(AsMut)]
+        #[as_mut(str, String)]
+        enum Types {
+            Tuple(String),
+            Named { first: String },
+        }
+
+        let mut item = Types::Named {
+            first: "test".to_owned(),
+        };
+        let rf: &mut str = item.as_mut();
+        assert_eq!(rf, "test");
+        let rf: &mut String = item.as_mut();
+        assert_eq!(rf, "test");
+    }
+
+    #[test]
+    fn field_types() {
+        #[derive(AsMut)]
+        enum FieldTypes {
+            Tuple(#[as_mut(i32, f64)] Helper),
+            Named {
+                #[as_mut(i32, f64)]
+                first: Helper,
+                second: i32,
+            },
+        }
+
+        let mut item = FieldTypes::Tuple(Helper(1, 2.0, false));
+        let rf: &mut i32 = item.as_mut();
+        assert_eq!(*rf, 1);
+        let rf: &mut f64 = item.as_mut();
+        assert_eq!(*rf, 2.0);
+    }
+
+    mod generic {
+        use super::*;
+
+        #[test]
+        fn nothing() {
+            #[derive(AsMut)]
+            enum Nothing<T> {
+                Tuple(T),
+                Named {
+                    #[as_mut]
+                    first: T,
+                    second: i32,
+                },
+            }
+
+            let mut item = Nothing::Tuple(1);
+            let rf: &mut i32 = item.as_mut();
+            assert_eq!(*rf, 1);
+        }
+
+        #[test]
+        fn forward() {
+            #[derive(AsMut)]
+            #[as_mut(forward)]
+            enum Forward<T, U> {
+                First(T),
+                Second(U),
+            }
+
+            let mut item = Forward::<_, Vec<u8>>::First(vec![1_u8]);
+            let rf: &mut [u8] = item.as_mut();
+            assert_eq!(rf, [1]);
+        }
+
+        #[test]
+        fn types() {
+            #[derive(AsMut)]
+            #[as_mut(str)]
+            enum Types<T> {
+                First(T),
+                Second(String),
+            }
+
+            let mut item = Types::First("test".to_owned());
+            let rf: &mut str = item.as_mut();
+            assert_eq!(rf, "test");
+        }
+    }
+
+    #[cfg(nightly)]
+    mod never {
+        use super::*;
+
+        #[derive(AsMut)]
+        enum Nothing {
+            First(!),
+            Second(!),
+        }
+    }
+
+    mod deprecated {
+        use super::*;
+
+        #[derive(AsMut)]
+        #[deprecated(note = "enum")]
+        enum Deprecated {
+            #[deprecated(note = "variant")]
+            First(#[deprecated(note = "field")] i32),
+        }
+    }
+}
@@ -1805,3 +1805,269 @@ mod multi_field {
         }
     }
 }
+
+mod enums {
+    use super::*;
+
+    #[test]
+    fn nothing() {
+        #[derive(AsRef)]
+        enum Nothing {
+            Tuple(String),
+            Named { first: String },
+        }
+
+        let item = Nothing::Tuple("test".to_owned());
+        let Nothing::Tuple(first) = &item else {
+            unreachable!()
+        };
+        assert!(ptr::eq(item.as_ref(), first));
+
+        let item = Nothing::Named {
+            first: "test".to_owned(),
+        };
+        let Nothing::Named { first } = &item else {
+            unreachable!()
+        };
+        assert!(ptr::eq(item.as_ref(), first));
+    }
+
+    #[test]
+    fn field() {
+        #[derive(AsRef)]
+        enum Field {
+            Tuple(i32, #[as_ref] String),
+            Named {
+                #[as_ref]
+                first: String,
+                second: i32,
+            },
+            Single(String),
+        }
+
+        let item = Field::Tuple(1, "test".to_owned());
+        let Field::Tuple(_, second) = &item else {
+            unreachable!()
+        };
+        assert!(ptr::eq(item.as_ref(), second));
+
+        let item = Field::Named {
+            first: "test".to_owned(),
+            second: 1,
+        };
+        let Field::Named { first, .. } = &item else {
+            unreachable!()
+        };
+        assert!(ptr::eq(item.as_ref(), first));
+
+        let item = Field::Single("test".to_owned());
+        let rf: &String = item.as_ref();
+        assert_eq!(rf, "test");
+    }
+
+    #[test]
+    fn skip() {
+        #[derive(AsRef)]
+        enum Skip {
+            Tuple(#[as_ref(skip)] i32, String),
+            Named {
+                first: String,
+                #[as_ref(ignore)]
+                second: i32,
+            },
+        }
+
+        let item = Skip::Tuple(1, "a".to_owned());
+        let rf: &String = item.as_ref();
+        assert_eq!(rf, "a");
+        let item = Skip::Named {
+            first: "b".to_owned(),
+            second: 2,
+        };
+        let rf: &String = item.as_ref();
+        assert_eq!(rf, "b");
+    }
+
+    #[test]
+    fn forward() {
+        #[derive(AsRef)]
+        #[as_ref(forward)]
+        enum Forward {
+            Str(String),
+            Vec(i32, #[as_ref] Vec<u8>),
+        }
+
+        let item = Forward::Str("test".to_owned());
+        let Forward::Str(first) = &item else {
+            unreachable!()
+        };
+        let rf: &[u8] = item.as_ref();
+        assert!(ptr::eq(rf, first.as_bytes()));
+
+        let item = Forward::Vec(1, vec![1, 2]);
+        let rf: &[u8] = item.as_ref();
+        assert_eq!(rf, [1, 2]);
+    }
+
+    #[test]
+    fn field_forward() {
+        #[derive(AsRef)]
+        enum FieldForward {
+            Tuple(#[as_ref(forward)] String),
+            Named {
+                #[as_ref(forward)]
+                first: String,
+                second: i32,
+            },
+        }
+
+        let item = FieldForward::Tuple("test".to_owned());
+        let rf: &str = item.as_ref();
+        assert_eq!(rf, "test");
+    }
+
+    #[test]
+    fn types() {
+        #[derive(AsRef)]
+        #[as_ref(str, [u8], String)]
+        enum Types {
+            Tuple(String),
+            Named { first: String },
+        }
+
+        let item = Types::Named {
+            first: "test".to_owned(),
+        };
+        let Types::Named { first } = &item else {
+            unreachable!()
+        };
+
+        let rf: &str = item.as_ref();
+        assert!(ptr::eq(rf, first.as_str()));
+        let rf: &[u8] = item.as_ref();
+        assert!(ptr::eq(rf, first.as_bytes()));
+        let rf: &String = item.as_ref();
+        assert!(ptr::eq(rf, first));
+    }
+
+    #[test]
+    fn types_with_different_fields() {
+        #[derive(AsRef)]
+        #[as_ref(i32)]
+        enum Types {
+            Direct(i32),
+            Helper(Helper),
+        }
+
+        let item = Types::Direct(1);
+        let rf: &i32 = item.as_ref();
+        assert_eq!(*rf, 1);
+
+        let item = Types::Helper(Helper(2, 3.0, true));
+        let rf: &i32 = item.as_ref();
+        assert_eq!(*rf, 2);
+    }
+
+    #[test]
+    fn field_types() {
+        #[derive(AsRef)]
+        enum FieldTypes {
+            Tuple(#[as_ref(str, [u8])] String),
+            Named {
+                #[as_ref(str, [u8])]
+                first: String,
+                second: i32,
+            },
+        }
+
+        let item = FieldTypes::Tuple("test".to_owned());
+        let rf: &str = item.as_ref();
+        assert_eq!(rf, "test");
+        let rf: &[u8] = item.as_ref();
+        assert_eq!(rf, b"test");
+    }
+
+    mod generic {
+        use super::*;
+
+        #[test]
+        fn nothing() {
+            #[derive(AsRef)]
+            enum Nothing<T> {
+                Tuple(T),
+                Named {
+                    #[as_ref]
+                    first: T,
+                    second: i32,
+                },
+            }
+
+            let item = Nothing::Tuple(1);
+            let rf: &i32 = item.as_ref();
+            assert_eq!(*rf, 1);
+        }
+
+        #[test]
+        fn forward() {
+            #[derive(AsRef)]
+            #[as_ref(forward)]
+            enum Forward<T, U> {
+                First(T),
+                Second(U),
+            }
+
+            let item = Forward::<_, Vec<u8>>::First("test".to_owned());
+            let rf: &[u8] = item.as_ref();
+            assert_eq!(rf, b"test");
+        }
+
+        #[test]
+        fn types() {
+            #[derive(AsRef)]
+            #[as_ref(str)]
+            enum Types<T> {
+                First(T),
+                Second(String),
+            }
+
+            let item = Types::First("test".to_owned());
+            let rf: &str = item.as_ref();
+            assert_eq!(rf, "test");
+        }
+
+        #[test]
+        fn lifetime() {
+            #[derive(AsRef)]
+            enum Lifetime<'a> {
+                First(&'a i32),
+                Second(#[as_ref(skip)] bool, &'a i32),
+            }
+
+            let item = Lifetime::Second(true, &1);
+            let rf: &&i32 = item.as_ref();
+            assert_eq!(**rf, 1);
+        }
+    }
+
+    #[cfg(nightly)]
+    mod never {
+        use super::*;
+
+        #[derive(AsRef)]
+        enum Nothing {
+            First(!),
+            Second(!),
+        }
+    }
+
+    mod deprecated {
+        use super::*;
+
+        #[derive(AsRef)]
+        #[deprecated(note = "enum")]
+        enum Deprecated {
+            #[deprecated(note = "variant")]
+            First(#[deprecated(note = "field")] i32),
+        }
+    }
+}
@@ -0,0 +1,225 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString as _},
+    vec,
+    vec::Vec,
+};
+
+use derive_more::{Builder, BuilderError};
+
+#[test]
+fn required() {
+    #[derive(Builder, Debug, PartialEq)]
+    struct Foo {
+        a: i32,
+        b: String,
+    }
+
+    assert_eq!(
+        Foo::builder().b("b".into()).a(1).build(),
+        Ok(Foo {
+            a: 1,
+            b: "b".into()
+        }),
+    );
+
+    let err = Foo::builder().b("b".into()).build().unwrap_err();
+    assert_eq!(err.field(), "a");
+    assert_eq!(
+        err.to_string(),
+        "Cannot build `Foo` without setting its required `a` field",
+    );
+    assert_eq!(
+        Foo::builder().a(1).build(),
+        Err(BuilderError::new("Foo", &["a", "b"], 0b10)),
+    );
+}
+
+#[test]
+fn all_missing_fields() {
+    #[derive(Builder, Debug, PartialEq)]
+    struct Foo {
+        a: i32,
+        #[builder(default)]
+        b: i32,
+        c: i32,
+        d: i32,
+    }
+
+    let err = Foo::builder().c(1).build().unwrap_err();
+    assert_eq!(err.field(), "a");
+    assert_eq!(err.missing_fields().collect::<Vec<_>>(), ["a", "d"]);
+    assert_eq!(
+        err.to_string(),
+        "Cannot build `Foo` without setting its required `a`, `d` fields",
+    );
+}
+
+#[test]
+fn builder_field() {
+    #[derive(Builder, Debug, PartialEq)]
+    struct Foo {
+        builder: u8,
+    }
+
+    assert_eq!(Foo::builder().builder(1).build(), Ok(Foo { builder: 1 }));
+}
+
+#[test]
+fn setter_overrides() {
+    #[derive(Builder, Debug, PartialEq)]
+    struct Foo {
+        a: i32,
+    }
+
+    assert_eq!(Foo::builder().a(1).a(2).build(), Ok(Foo { a: 2 }));
+}
+
+#[test]
+fn default() {
+    const LIMIT: usize = 10;
+
+    #[derive(Builder, Debug, PartialEq)]
+    struct Foo {
+        #[builder(default)]
+        a: Vec<u8>,
+        #[builder(default = LIMIT * 2)]
+        b: usize,
+        c: bool,
+    }
+
+    assert_eq!(
+        Foo::builder().c(true).build(),
+        Ok(Foo {
+            a: vec![],
+            b: 20,
+            c: true,
+        }),
+    );
+    assert_eq!(
+        Foo::builder().a(vec![1]).b(1).c(false).build(),
+        Ok(Foo {
+            a: vec![1],
+            b: 1,
+            c: false,
+        }),
+    );
+}
+
+#[test]
+fn into() {
+    #[derive(Builder, Debug, PartialEq)]
+    struct Foo {
+        #[builder(into)]
+        a: String,
+        #[builder(into, default = 1)]
+        b: u64,
+        #[builder(default)]
+        #[builder(into)]
+        c: Option<u8>,
+    }
+
+    assert_eq!(
+        Foo::builder().a("a").b(2_u8).c(3).build(),
+        Ok(Foo {
+            a: "a".into(),
+            b: 2,
+            c: Some(3),
+        }),
+    );
+}
+
+#[test]
+fn raw_identifiers() {
+    #[derive(Builder, Debug, PartialEq)]
+    struct Foo {
+        r#type: u8,
+    }
+
+    assert_eq!(Foo::builder().r#type(1).build(), Ok(Foo { r#type: 1 }));
+    assert_eq!(Foo::builder().build().unwrap_err().field(), "type");
+}
+
+#[test]
+fn builder_default() {
+    #[derive(Builder, Debug, PartialEq)]
+    struct Foo {
+        #[builder(default)]
+        a: u8,
+    }
+
+    assert_eq!(FooBuilder::default().build(), Ok(Foo { a: 0 }));
+}
+
+mod visibility {
+    mod inner {
+        use derive_more::Builder;
+
+        #[derive(Builder, Debug, PartialEq)]
+        pub struct Foo {
+            a: u8,
+        }
+    }
+
+    #[test]
+    fn private_fields() {
+        let foo = inner::Foo::builder().a(1).build().unwrap();
+        let _: inner::FooBuilder = inner::Foo::builder();
+        assert_eq!(foo, inner::Foo::builder().a(1).build().unwrap());
+    }
+}
+
+mod generic {
+    use core::marker::PhantomData;
+
+    use derive_more::Builder;
+
+    #[cfg(not(feature = "std"))]
+    use super::Vec;
+
+    #[test]
+    fn type_params() {
+        #[derive(Builder, Debug, PartialEq)]
+        struct Foo<T, U: Clone = u8>
+        where
+            T: Copy,
+        {
+            a: T,
+            #[builder(default)]
+            b: Vec<U>,
+        }
+
+        assert_eq!(
+            Foo::<_>::builder().a(1_i8).build(),
+            Ok(Foo {
+                a: 1,
+                b: Vec::new()
+            }),
+        );
+    }
+
+    #[test]
+    fn lifetime_and_const_param() {
+        #[derive(Builder, Debug, PartialEq)]
+        struct Foo<'a, T, const N: usize> {
+            a: &'a [u8; N],
+            #[builder(default)]
+            b: PhantomData<T>,
+        }
+
+        let arr = [1, 2];
+        assert_eq!(
+            Foo::<'_, (), 2>::builder().a(&arr).build(),
+            Ok(Foo {
+                a: &arr,
+                b: PhantomData,
+            }),
+        );
+    }
+}
@@ -0,0 +1,187 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+use core::marker::PhantomData;
+
+use derive_more::{CheckedOps, SaturatingOps, WrappingOps};
+
+#[derive(CheckedOps, SaturatingOps, WrappingOps, Clone, Copy, Debug, PartialEq)]
+struct Money {
+    units: u32,
+    cents: u8,
+}
+
+#[derive(CheckedOps, SaturatingOps, WrappingOps, Clone, Copy, Debug, PartialEq)]
+struct Counter(i16);
+
+#[test]
+fn checked() {
+    let a = Money {
+        units: 1,
+        cents: 200,
+    };
+    let b = Money {
+        units: 2,
+        cents: 50,
+    };
+    assert_eq!(
+        a.checked_add(b),
+        Some(Money {
+            units: 3,
+            cents: 250,
+        }),
+    );
+    assert_eq!(a.checked_add(a), None);
+    assert_eq!(a.checked_sub(b), None);
+    assert_eq!(b.checked_sub(b), Some(Money { units: 0, cents: 0 }));
+    assert_eq!(Counter(3).checked_mul(Counter(4)), Some(Counter(12)));
+    assert_eq!(Counter(i16::MAX).checked_mul(Counter(2)), None);
+    assert_eq!(Counter(12).checked_div(Counter(4)), Some(Counter(3)));
+    assert_eq!(Counter(12).checked_div(Counter(0)), None);
+}
+
+#[test]
+fn saturating() {
+    let a = Money {
+        units: u32::MAX,
+        cents: 200,
+    };
+    assert_eq!(
+        a.saturating_add(a),
+        Money {
+            units: u32::MAX,
+            cents: u8::MAX,
+        },
+    );
+    assert_eq!(
+        Money { units: 1, cents: 2 }.saturating_sub(a),
+        Money { units: 0, cents: 0 },
+    );
+    assert_eq!(
+        Counter(i16::MIN).saturating_mul(Counter(2)),
+        Counter(i16::MIN)
+    );
+    assert_eq!(
+        Counter(i16::MIN).saturating_div(Counter(-1)),
+        Counter(i16::MAX)
+    );
+}
+
+#[test]
+fn wrapping() {
+    let a = Money {
+        units: u32::MAX,
+        cents: 200,
+    };
+    assert_eq!(
+        a.wrapping_add(Money {
+            units: 2,
+            cents: 60
+        }),
+        Money { units: 1, cents: 4 },
+    );
+    assert_eq!(
+        Counter(i16::MIN).wrapping_sub(Counter(1)),
+        Counter(i16::MAX)
+    );
+    assert_eq!(Counter(i16::MAX).wrapping_mul(Counter(2)), Counter(-2));
+    assert_eq!(
+        Counter(i16::MIN).wrapping_div(Counter(-1)),
+        Counter(i16::MIN)
+    );
+}
+
+#[test]
+fn nested() {
+    #[derive(CheckedOps, Clone, Copy, Debug, PartialEq)]
+    struct Wallet {
+        main: Money,
+        savings: Counter,
+    }
+
+    let wallet = Wallet {
+        main: Money { units: 1, cents: 2 },
+        savings: Counter(3),
+    };
+    assert_eq!(
+        wallet.checked_add(wallet),
+        Some(Wallet {
+            main: Money { units: 2, cents: 4 },
+            savings: Counter(6),
+        }),
+    );
+    let overflowing = Wallet {
+        main: Money { units: 1, cents: 2 },
+        savings: Counter(i16::MAX),
+    };
+    assert_eq!(wallet.checked_add(overflowing), None);
+}
+
+#[test]
+fn selected_ops() {
+    #[derive(CheckedOps, Debug, PartialEq)]
+    #[checked_ops(sub, add)]
+    struct Counter(u64);
+
+    impl Counter {
+        fn checked_mul(self, _: Self) -> &'static str {
+            "not derived"
+        }
+    }
+
+    assert_eq!(Counter(1).checked_sub(Counter(2)), None);
+    assert_eq!(Counter(1).checked_add(Counter(2)), Some(Counter(3)));
+    assert_eq!(Counter(1).checked_mul(Counter(2)), "not derived");
+}
+
+#[test]
+fn skip() {
+    #[derive(CheckedOps, SaturatingOps, Debug, PartialEq)]
+    struct Tagged<T> {
+        value: u8,
+        #[checked_ops(skip)]
+        #[saturating_ops(ignore)]
+        tag: PhantomData<T>,
+        #[checked_ops(skip)]
+        #[saturating_ops(skip)]
+        id: &'static str,
+    }
+
+    let a = Tagged::<()> {
+        value: 250,
+        tag: PhantomData,
+        id: "a",
+    };
+    let b = Tagged {
+        value: 3,
+        tag: PhantomData,
+        id: "b",
+    };
+    assert_eq!(
+        a.checked_add(b),
+        Some(Tagged {
+            value: 253,
+            tag: PhantomData,
+            id: "a",
+        }),
+    );
+
+    let a = Tagged::<()> {
+        value: 250,
+        tag: PhantomData,
+        id: "a",
+    };
+    let b = Tagged {
+        value: 10,
+        tag: PhantomData,
+        id: "b",
+    };
+    assert_eq!(
+        a.saturating_add(b),
+        Tagged {
+            value: 255,
+            tag: PhantomData,
+            id: "a",
+        },
+    );
+}
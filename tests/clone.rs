@@ -0,0 +1,244 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+mod structs {
+    #[cfg(not(feature = "std"))]
+    use ::alloc::{boxed::Box, string::String, vec, vec::Vec};
+
+    use derive_more::{Clone, Copy};
+
+    fn assert_copy<T: Copy>() {}
+
+    #[test]
+    fn unit() {
+        #[derive(Clone, Copy)]
+        struct Foo;
+
+        assert_copy::<Foo>();
+    }
+
+    #[test]
+    fn tuple() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Foo(i32, String);
+
+        let foo = Foo(1, "a".into());
+        assert_eq!(foo.clone(), foo);
+    }
+
+    #[test]
+    fn named() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Foo {
+            a: u8,
+            b: bool,
+        }
+
+        assert_copy::<Foo>();
+        let foo = Foo { a: 1, b: true };
+        assert_eq!(foo.clone(), foo);
+    }
+
+    #[test]
+    fn recursive() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Foo(i32, Vec<Foo>, Option<Box<Self>>);
+
+        let foo = Foo(1, vec![Foo(2, vec![], None)], None);
+        assert_eq!(foo.clone(), foo);
+    }
+
+    mod with {
+        use derive_more::Clone;
+
+        #[derive(Debug, PartialEq)]
+        struct NoClone(u8);
+
+        fn reset(_: &NoClone) -> NoClone {
+            NoClone(0)
+        }
+
+        #[test]
+        fn field() {
+            #[derive(Clone, Debug, PartialEq)]
+            struct Foo {
+                a: u8,
+                #[clone(with = reset)]
+                b: NoClone,
+            }
+
+            assert_eq!(
+                Foo {
+                    a: 1,
+                    b: NoClone(2),
+                }
+                .clone(),
+                Foo {
+                    a: 1,
+                    b: NoClone(0),
+                },
+            );
+        }
+
+        #[test]
+        fn generic_field() {
+            fn first<T: Copy>(v: &[T; 2]) -> [T; 2] {
+                [v[0], v[0]]
+            }
+
+            #[derive(Clone, Debug, PartialEq)]
+            struct Foo<T: Copy>(#[clone(with = first)] [T; 2]);
+
+            assert_eq!(Foo([1, 2]).clone(), Foo([1, 1]));
+        }
+    }
+
+    mod generic {
+        #[cfg(not(feature = "std"))]
+        use ::alloc::rc::Rc;
+        use core::marker::PhantomData;
+        #[cfg(feature = "std")]
+        use std::rc::Rc;
+
+        use derive_more::{Clone, Copy};
+
+        use super::assert_copy;
+
+        trait Some {
+            type Assoc;
+        }
+
+        impl<T> Some for T {
+            type Assoc = u32;
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct NoClone;
+
+        #[test]
+        fn unconstrained() {
+            #[derive(Clone, Copy)]
+            struct Foo<'a, A, B, C> {
+                a: &'a A,
+                b: PhantomData<B>,
+                c: *const C,
+            }
+
+            #[derive(Clone)]
+            struct Bar<A>(Rc<A>);
+
+            assert_copy::<Foo<'static, NoClone, NoClone, NoClone>>();
+            let _ = Bar(Rc::new(NoClone)).clone();
+        }
+
+        #[test]
+        fn assoc() {
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            struct Foo<A: Some, B>(A::Assoc, B);
+
+            assert_copy::<Foo<NoClone, u8>>();
+            assert_eq!(Foo::<NoClone, _>(1, 2).clone(), Foo(1, 2));
+        }
+
+        #[test]
+        fn bound() {
+            trait Trait {
+                type Assoc;
+            }
+
+            impl Trait for u8 {
+                type Assoc = i32;
+            }
+
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            #[clone(bound(T::Assoc: Clone))]
+            #[clone(bound(T: Trait))]
+            #[copy(bound(T::Assoc: Copy))]
+            struct Foo<T: Trait>(T::Assoc, PhantomData<T>);
+
+            assert_copy::<Foo<u8>>();
+            assert_eq!(Foo::<u8>(1, PhantomData).clone(), Foo(1, PhantomData));
+        }
+
+        #[test]
+        fn lifetime_and_const_param() {
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            struct Foo<'a, T, const N: usize>(&'a [T; N]);
+
+            assert_copy::<Foo<'static, NoClone, 3>>();
+        }
+    }
+}
+
+mod enums {
+    #[cfg(not(feature = "std"))]
+    use ::alloc::string::String;
+
+    use derive_more::{Clone, Copy};
+
+    fn assert_copy<T: Copy>() {}
+
+    #[test]
+    fn empty() {
+        #[derive(Clone, Copy)]
+        enum E {}
+
+        assert_copy::<E>();
+    }
+
+    #[test]
+    fn multi_variant() {
+        #[derive(Clone, Debug, PartialEq)]
+        enum E {
+            Foo(i32, String),
+            Bar,
+            Baz { a: String },
+        }
+
+        for e in [E::Foo(1, "a".into()), E::Bar, E::Baz { a: "b".into() }] {
+            assert_eq!(e.clone(), e);
+        }
+    }
+
+    #[test]
+    fn with() {
+        fn zero(_: &u8) -> u8 {
+            0
+        }
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        enum E {
+            Foo(#[clone(with = zero)] u8, u8),
+            Bar,
+        }
+
+        assert_copy::<E>();
+        assert_eq!(E::Foo(1, 2).clone(), E::Foo(0, 2));
+    }
+
+    mod generic {
+        use core::marker::PhantomData;
+
+        use derive_more::{Clone, Copy};
+
+        use super::assert_copy;
+
+        #[derive(Debug, PartialEq)]
+        struct NoClone;
+
+        #[test]
+        fn multi_variant() {
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            enum E<A, B> {
+                Foo(A),
+                Bar(PhantomData<B>),
+            }
+
+            assert_copy::<E<u8, NoClone>>();
+            assert_eq!(E::<_, NoClone>::Foo(1).clone(), E::Foo(1));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Getters)]
+struct Foo {
+    #[getter(copy, deref)]
+    a: Box<i32>,
+}
+
+fn main() {}
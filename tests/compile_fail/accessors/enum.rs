@@ -0,0 +1,6 @@
+#[derive(derive_more::Getters)]
+enum Foo {
+    Bar { a: i32 },
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Getters)]
+struct Foo {
+    #[getter(into)]
+    a: i32,
+}
+
+fn main() {}
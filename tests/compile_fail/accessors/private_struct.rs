@@ -0,0 +1,21 @@
+mod outer {
+    pub mod inner {
+        #[derive(Default, derive_more::Getters, derive_more::Setters)]
+        pub(in crate::outer) struct Foo {
+            secret: u8,
+        }
+    }
+
+    #[allow(private_interfaces)]
+    pub fn foo() -> inner::Foo {
+        let mut foo = inner::Foo::default();
+        foo.set_secret(*foo.secret() + 1);
+        foo
+    }
+}
+
+fn main() {
+    let mut foo = outer::foo();
+    let _ = foo.secret();
+    foo.set_secret(0);
+}
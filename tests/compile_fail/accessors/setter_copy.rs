@@ -0,0 +1,7 @@
+#[derive(derive_more::Setters)]
+struct Foo {
+    #[setter(copy)]
+    a: i32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Getters)]
+#[getter(copy)]
+struct Foo {
+    a: i32,
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(derive_more::Setters)]
+struct Foo(i32);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Getters)]
+struct Foo {
+    #[getter(unknown)]
+    a: i32,
+}
+
+fn main() {}
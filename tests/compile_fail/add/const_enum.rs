@@ -0,0 +1,7 @@
+#[derive(derive_more::Add)]
+#[add(const)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(derive_more::Add)]
+#[add(const)]
+struct Foo(i32, f64);
+
+fn main() {}
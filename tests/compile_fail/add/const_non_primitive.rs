@@ -0,0 +1,5 @@
+#[derive(derive_more::Add)]
+#[add(const)]
+struct Foo(i32, char);
+
+fn main() {}
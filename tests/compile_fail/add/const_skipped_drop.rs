@@ -0,0 +1,9 @@
+#[derive(derive_more::Add)]
+#[add(const)]
+struct Tagged {
+    value: u32,
+    #[add(skip)]
+    tag: String,
+}
+
+fn main() {}
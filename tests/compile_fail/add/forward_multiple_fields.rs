@@ -0,0 +1,11 @@
+#[derive(derive_more::Add)]
+#[add(forward)]
+struct Foo(i32, i32);
+
+#[derive(derive_more::Add)]
+#[add(forward)]
+enum Bar {
+    Baz(i32),
+}
+
+fn main() {}
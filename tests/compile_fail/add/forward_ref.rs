@@ -0,0 +1,5 @@
+#[derive(derive_more::Add)]
+#[add(forward, ref)]
+struct Foo(i32);
+
+fn main() {}
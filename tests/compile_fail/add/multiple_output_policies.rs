@@ -0,0 +1,8 @@
+#[derive(derive_more::Add)]
+#[add(output = panic)]
+#[add(output = option)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(derive_more::Add)]
+#[add(output = panic)]
+struct Foo(i32);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Add)]
+#[add(i32, output = i32)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
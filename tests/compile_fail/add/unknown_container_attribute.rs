@@ -0,0 +1,5 @@
+#[derive(derive_more::Add)]
+#[add(ref, unknown)]
+struct Foo(i32);
+
+fn main() {}
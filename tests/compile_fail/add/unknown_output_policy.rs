@@ -0,0 +1,7 @@
+#[derive(derive_more::Add)]
+#[add(output = saturate)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::AddAssign)]
+#[add_assign(forward, panic)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::AddAssign)]
+#[add_assign(panic, try)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::AsMut)]
+#[as_mut(forward)]
+enum Foo {
+    Bar(#[as_mut(forward)] String),
+}
+
+fn main() {}
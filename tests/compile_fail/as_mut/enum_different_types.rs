@@ -1,7 +1,7 @@
 #[derive(derive_more::AsMut)]
 enum Foo {
     Bar(i32),
-    Baz(i32),
+    Baz(u32),
 }
 
 fn main() {}
@@ -0,0 +1,4 @@
+#[derive(derive_more::AsMut)]
+enum Foo {}
+
+fn main() {}
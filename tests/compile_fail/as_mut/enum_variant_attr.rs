@@ -0,0 +1,7 @@
+#[derive(derive_more::AsMut)]
+enum Foo {
+    #[as_mut]
+    Bar(i32),
+}
+
+fn main() {}
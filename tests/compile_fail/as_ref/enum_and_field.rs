@@ -0,0 +1,7 @@
+#[derive(derive_more::AsRef)]
+#[as_ref(forward)]
+enum Foo {
+    Bar(#[as_ref(forward)] String),
+}
+
+fn main() {}
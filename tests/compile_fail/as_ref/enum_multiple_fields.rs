@@ -0,0 +1,7 @@
+#[derive(derive_more::AsRef)]
+enum Foo {
+    Bar(i32),
+    Baz(i32, i32),
+}
+
+fn main() {}
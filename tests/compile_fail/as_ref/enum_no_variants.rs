@@ -0,0 +1,4 @@
+#[derive(derive_more::AsRef)]
+enum Foo {}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::AsRef)]
+enum Foo {
+    #[as_ref]
+    Bar(i32),
+}
+
+fn main() {}
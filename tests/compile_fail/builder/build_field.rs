@@ -0,0 +1,6 @@
+#[derive(derive_more::Builder)]
+struct Foo {
+    build: bool,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(derive_more::Builder)]
+struct Foo {
+    #[builder(default)]
+    #[builder(default = 1)]
+    a: i32,
+}
+
+fn main() {}
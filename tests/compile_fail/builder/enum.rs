@@ -0,0 +1,6 @@
+#[derive(derive_more::Builder)]
+enum Foo {
+    Bar { a: i32 },
+}
+
+fn main() {}
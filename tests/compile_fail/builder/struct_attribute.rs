@@ -0,0 +1,7 @@
+#[derive(derive_more::Builder)]
+#[builder(into)]
+struct Foo {
+    a: i32,
+}
+
+fn main() {}
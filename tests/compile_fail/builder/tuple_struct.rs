@@ -0,0 +1,4 @@
+#[derive(derive_more::Builder)]
+struct Foo(i32, i32);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Builder)]
+struct Foo {
+    #[builder(unknown)]
+    a: i32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::SaturatingOps)]
+struct Foo {
+    #[saturating_ops(skip)]
+    bar: u32,
+}
+
+fn main() {}
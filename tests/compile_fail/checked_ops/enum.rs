@@ -0,0 +1,6 @@
+#[derive(derive_more::CheckedOps)]
+enum Foo {
+    Bar(u32),
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(derive_more::CheckedOps)]
+struct Meters(f64);
+
+fn main() {}
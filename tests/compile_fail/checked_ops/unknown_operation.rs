@@ -0,0 +1,5 @@
+#[derive(derive_more::WrappingOps)]
+#[wrapping_ops(add, rem)]
+struct Foo(u32);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(Clone, derive_more::Copy)]
+struct Foo {
+    #[copy(bound(i32: Copy))]
+    bar: i32,
+}
+
+fn main() {}
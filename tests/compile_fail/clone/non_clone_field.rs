@@ -0,0 +1,6 @@
+struct NoClone;
+
+#[derive(derive_more::Clone)]
+struct Foo(NoClone);
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[derive(derive_more::Clone)]
+pub union Foo {
+    bar: i32,
+}
+
+fn main() {}
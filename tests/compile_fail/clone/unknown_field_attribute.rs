@@ -0,0 +1,7 @@
+#[derive(derive_more::Clone)]
+struct Foo {
+    #[clone(skip)]
+    bar: i32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Clone)]
+#[clone(with = Foo::new)]
+struct Foo {
+    bar: i32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Clone)]
+enum Foo {
+    #[clone(bound(i32: Clone))]
+    Bar(i32),
+}
+
+fn main() {}
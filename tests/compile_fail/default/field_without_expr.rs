@@ -0,0 +1,7 @@
+#[derive(derive_more::Default)]
+struct Foo {
+    #[default]
+    bar: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(derive_more::Default)]
+enum Foo {
+    #[default]
+    Bar,
+    #[default]
+    Baz(i32),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Default)]
+enum Foo {
+    Bar,
+    Baz(i32),
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+struct NoDefault;
+
+#[derive(derive_more::Default)]
+struct Foo(i32, NoDefault);
+
+fn main() {}
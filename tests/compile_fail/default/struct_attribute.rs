@@ -0,0 +1,7 @@
+#[derive(derive_more::Default)]
+#[default]
+struct Foo {
+    bar: i32,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[derive(derive_more::Default)]
+pub union Foo {
+    bar: i32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(derive_more::Default)]
+enum Foo {
+    #[default(Self::Baz(1))]
+    Bar,
+    Baz(i32),
+}
+
+fn main() {}
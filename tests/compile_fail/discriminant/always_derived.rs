@@ -0,0 +1,13 @@
+#[derive(derive_more::Discriminant)]
+#[discriminant(derive(Debug, derive_more::PartialEq))]
+enum Foo {
+    Bar,
+}
+
+#[derive(derive_more::Discriminant)]
+#[discriminant(derive(core::marker::Copy))]
+enum Baz {
+    Qux,
+}
+
+fn main() {}
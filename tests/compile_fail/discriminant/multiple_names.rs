@@ -0,0 +1,8 @@
+#[derive(derive_more::Discriminant)]
+#[discriminant(name = Kind)]
+#[discriminant(name = Other)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
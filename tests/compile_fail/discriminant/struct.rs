@@ -0,0 +1,4 @@
+#[derive(derive_more::Discriminant)]
+struct Foo(i32);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Discriminant)]
+#[discriminant(rename = Kind)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
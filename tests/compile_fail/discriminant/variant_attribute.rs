@@ -0,0 +1,7 @@
+#[derive(derive_more::Discriminant)]
+enum Foo {
+    #[discriminant(name = Kind)]
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[derive(derive_more::FromStr)]
+#[from_str(display)]
+#[from_str("<{_0}>")]
+struct Foo(i32);
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(derive_more::FromStr)]
+#[from_str(display)]
+struct Foo(i32);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::FromStr)]
+#[from_str("{_0}")]
+enum Enum {
+    Foo(u8),
+}
+
+fn main() {}
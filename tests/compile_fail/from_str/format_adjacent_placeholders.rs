@@ -0,0 +1,5 @@
+#[derive(derive_more::FromStr)]
+#[from_str("{_0}{_1}")]
+struct Pair(u8, u8);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::FromStr)]
+#[from_str("{x:>5}")]
+struct Foo {
+    x: u8,
+}
+
+fn main() {}
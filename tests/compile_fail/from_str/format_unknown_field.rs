@@ -0,0 +1,8 @@
+#[derive(derive_more::FromStr)]
+#[from_str("{x},{z}")]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Hash)]
+#[hash(skip)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+struct NoHash;
+
+#[derive(derive_more::Hash)]
+struct Foo(NoHash);
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[derive(derive_more::Hash)]
+pub union Foo {
+    bar: i32,
+}
+
+fn main() {}
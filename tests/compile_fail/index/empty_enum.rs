@@ -0,0 +1,4 @@
+#[derive(derive_more::Index)]
+enum Foo {}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::Index)]
+enum Foo {
+    Single(#[index] Vec<u8>),
+    Multiple(Vec<u8>, Vec<u8>),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+#[derive(derive_more::Index)]
+struct Registry {
+    #[index(&str)]
+    by_name: HashMap<&'static str, i32>,
+    #[index(&'static str)]
+    by_alias: HashMap<&'static str, i32>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(derive_more::Index)]
+struct Registry {
+    #[index(usize)]
+    items: Vec<i32>,
+    #[index(core::primitive::usize)]
+    flags: Vec<bool>,
+}
+
+fn main() {}
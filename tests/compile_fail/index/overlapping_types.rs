@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+#[derive(derive_more::Index)]
+struct Registry {
+    #[index(usize)]
+    items: Vec<i32>,
+    #[index(usize)]
+    by_id: HashMap<usize, i32>,
+}
+
+fn main() {}
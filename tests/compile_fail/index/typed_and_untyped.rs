@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+#[derive(derive_more::Index)]
+struct Registry {
+    #[index]
+    items: Vec<i32>,
+    #[index(&str)]
+    by_name: HashMap<String, usize>,
+}
+
+fn main() {}
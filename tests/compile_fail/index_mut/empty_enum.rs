@@ -0,0 +1,4 @@
+#[derive(derive_more::IndexMut)]
+enum Foo {}
+
+fn main() {}
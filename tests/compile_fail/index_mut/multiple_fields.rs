@@ -0,0 +1,7 @@
+#[derive(derive_more::IndexMut)]
+enum Foo {
+    Single(#[index_mut] Vec<u8>),
+    Multiple(Vec<u8>, Vec<u8>),
+}
+
+fn main() {}
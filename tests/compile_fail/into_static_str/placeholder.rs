@@ -0,0 +1,7 @@
+#[derive(derive_more::IntoStaticStr)]
+enum Foo {
+    #[display("bar {}")]
+    Bar,
+}
+
+fn main() {}
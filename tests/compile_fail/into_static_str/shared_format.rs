@@ -0,0 +1,7 @@
+#[derive(derive_more::IntoStaticStr)]
+#[display("Foo::{_variant}")]
+enum Foo {
+    Bar,
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(derive_more::IntoStaticStr)]
+struct Foo;
+
+fn main() {}
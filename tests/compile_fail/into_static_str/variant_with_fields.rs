@@ -0,0 +1,7 @@
+#[derive(derive_more::IntoStaticStr)]
+enum Foo {
+    Bar,
+    Baz(i32),
+}
+
+fn main() {}
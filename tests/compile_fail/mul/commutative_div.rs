@@ -0,0 +1,5 @@
+#[derive(derive_more::Div)]
+#[div(commutative(f32))]
+struct Foo(f32);
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(derive_more::Mul)]
+#[mul(commutative())]
+struct Foo(i32);
+
+fn main() {}
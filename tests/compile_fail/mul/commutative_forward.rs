@@ -0,0 +1,6 @@
+#[derive(derive_more::Mul)]
+#[mul(forward)]
+#[mul(commutative(i32))]
+struct Foo(i32);
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(derive_more::Mul)]
+#[mul(const)]
+struct Foo(i32, i64);
+
+fn main() {}
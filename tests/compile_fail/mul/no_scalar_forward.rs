@@ -0,0 +1,6 @@
+#[derive(derive_more::Mul)]
+#[mul(forward, no_scalar)]
+#[mul(Self, output = Self)]
+struct Foo(i32);
+
+fn main() {}
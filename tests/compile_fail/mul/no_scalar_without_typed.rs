@@ -0,0 +1,5 @@
+#[derive(derive_more::Mul)]
+#[mul(no_scalar)]
+struct Foo(i32);
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(derive_more::Shl)]
+#[shl()]
+struct Foo(u64);
+
+fn main() {}
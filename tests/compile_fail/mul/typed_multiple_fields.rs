@@ -0,0 +1,5 @@
+#[derive(derive_more::Mul)]
+#[mul(Self, output = i32)]
+struct Foo(i32, i32);
+
+fn main() {}
@@ -0,0 +1,10 @@
+struct Seconds {
+    value: i32,
+}
+
+#[derive(derive_more::Mul)]
+#[mul(no_scalar)]
+#[mul(Seconds, output = Self)]
+struct Foo(i32);
+
+fn main() {}
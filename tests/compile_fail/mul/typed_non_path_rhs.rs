@@ -0,0 +1,6 @@
+#[derive(derive_more::Mul)]
+#[mul(no_scalar)]
+#[mul((i32, i32), output = Self)]
+struct Foo(i32);
+
+fn main() {}
@@ -0,0 +1,8 @@
+type Scalar = i32;
+
+#[derive(derive_more::Mul)]
+#[mul(no_scalar)]
+#[mul(Scalar, output = Self)]
+struct Foo(i32);
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(derive_more::Mul)]
+#[mul(i32, output = Foo)]
+struct Foo(i32);
+
+fn main() {}
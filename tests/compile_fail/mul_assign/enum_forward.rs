@@ -0,0 +1,7 @@
+#[derive(derive_more::MulAssign)]
+#[mul_assign(forward, panic)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
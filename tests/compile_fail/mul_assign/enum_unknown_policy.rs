@@ -0,0 +1,7 @@
+#[derive(derive_more::MulAssign)]
+#[mul_assign(forward, ignore)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(derive_more::ShrAssign)]
+#[shr_assign()]
+struct Foo(u64);
+
+fn main() {}
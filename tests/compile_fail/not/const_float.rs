@@ -0,0 +1,12 @@
+#[derive(derive_more::Neg)]
+#[neg(const)]
+struct Foo(i32, f64);
+
+#[derive(derive_more::Not)]
+#[not(const)]
+struct Bar {
+    flag: bool,
+    value: f32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[derive(derive_more::Neg)]
+struct Foo(i32, u32);
+
+#[derive(derive_more::Neg)]
+enum Bar {
+    Signed(i64),
+    Unsigned { value: usize },
+}
+
+fn main() {}
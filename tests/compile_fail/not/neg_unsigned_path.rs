@@ -0,0 +1,9 @@
+#[derive(derive_more::Neg)]
+struct Foo(i32, core::primitive::u32);
+
+#[derive(derive_more::Neg)]
+struct Bar {
+    value: ::std::primitive::u8,
+}
+
+fn main() {}
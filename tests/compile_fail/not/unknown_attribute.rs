@@ -0,0 +1,5 @@
+#[derive(derive_more::Not)]
+#[not(forward)]
+struct Foo(i32);
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(PartialEq, Eq, PartialOrd, derive_more::Ord)]
+#[ord(order(Bar, Baz, Bar))]
+enum Foo {
+    Bar,
+    Baz,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(PartialEq, derive_more::PartialOrd)]
+#[partial_ord(skip)]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(PartialEq, Eq, PartialOrd)]
+struct NoOrd;
+
+#[derive(PartialEq, Eq, PartialOrd, derive_more::Ord)]
+struct Foo(NoOrd);
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(derive_more::PartialEq, derive_more::PartialOrd)]
+#[partial_ord(order(1))]
+struct Foo(i32, #[partial_eq(skip)] i32);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::PartialOrd)]
+union IntOrFloat {
+    i: u32,
+    f: f32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(PartialEq, derive_more::PartialOrd)]
+struct Foo(#[partial_ord(unknown)] i32);
+
+#[derive(PartialEq, derive_more::PartialOrd)]
+enum Enum {
+    Bar { #[ord(reverse, unknown)] i: i32 },
+}
+
+fn main() {}
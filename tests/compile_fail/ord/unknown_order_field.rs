@@ -0,0 +1,8 @@
+#[derive(PartialEq, derive_more::PartialOrd)]
+#[partial_ord(order(b, c))]
+struct Foo {
+    a: i32,
+    b: i32,
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(derive_more::VariantArray)]
+struct Foo;
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::VariantArray)]
+enum Enum {
+    Foo,
+    Bar(i32),
+}
+
+fn main() {}
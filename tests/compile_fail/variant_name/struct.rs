@@ -0,0 +1,4 @@
+#[derive(derive_more::VariantName)]
+struct Foo(i32);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::VariantName)]
+enum Foo {
+    #[variant_name(rename = "bar")]
+    Bar(i32),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(derive_more::VariantName)]
+#[variant_name(rename_all = "Title Case")]
+enum Foo {
+    Bar(i32),
+}
+
+fn main() {}
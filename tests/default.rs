@@ -0,0 +1,180 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+mod structs {
+    #[cfg(not(feature = "std"))]
+    use ::alloc::{boxed::Box, string::String, vec, vec::Vec};
+
+    use derive_more::Default;
+
+    #[test]
+    fn unit() {
+        #[derive(Debug, Default, PartialEq)]
+        struct Foo;
+
+        fn assert_default<T: Default>() {}
+
+        assert_default::<Foo>();
+    }
+
+    #[test]
+    fn tuple() {
+        #[derive(Debug, Default, PartialEq)]
+        struct Foo(i32, #[default(3)] u8, #[default(vec![1])] Vec<u8>);
+
+        assert_eq!(Foo::default(), Foo(0, 3, vec![1]));
+    }
+
+    #[test]
+    fn named() {
+        const PORT: u16 = 80;
+
+        fn host() -> String {
+            "localhost".into()
+        }
+
+        #[derive(Debug, Default, PartialEq)]
+        struct Foo {
+            #[default(PORT + 1)]
+            port: u16,
+            #[default(host())]
+            host: String,
+            verbose: bool,
+        }
+
+        assert_eq!(
+            Foo::default(),
+            Foo {
+                port: 81,
+                host: "localhost".into(),
+                verbose: false,
+            },
+        );
+    }
+
+    #[test]
+    fn recursive() {
+        #[derive(Debug, Default, PartialEq)]
+        struct Foo(
+            i32,
+            Option<Box<Self>>,
+            #[default(Some(Box::new(Foo(1, None, None))))] Option<Box<Foo>>,
+        );
+
+        assert_eq!(
+            Foo::default(),
+            Foo(0, None, Some(Box::new(Foo(1, None, None)))),
+        );
+    }
+
+    mod generic {
+        use core::marker::PhantomData;
+
+        use derive_more::Default;
+
+        #[derive(Debug, PartialEq)]
+        struct NoDefault;
+
+        #[test]
+        fn unconstrained() {
+            #[derive(Debug, Default, PartialEq)]
+            struct Foo<A, B> {
+                a: Option<A>,
+                b: PhantomData<B>,
+            }
+
+            assert_eq!(
+                Foo::<NoDefault, NoDefault>::default(),
+                Foo {
+                    a: None,
+                    b: PhantomData,
+                },
+            );
+        }
+
+        #[test]
+        fn explicit_expr() {
+            #[derive(Debug, PartialEq)]
+            struct Wrapper<T>(Option<T>);
+
+            #[derive(Debug, Default, PartialEq)]
+            struct Foo<T>(#[default(Wrapper(None))] Wrapper<T>, u8);
+
+            assert_eq!(Foo::<NoDefault>::default(), Foo(Wrapper(None), 0));
+        }
+
+        #[test]
+        fn bounded() {
+            #[derive(Debug, Default, PartialEq)]
+            struct Foo<T, const N: usize>([u8; N], T);
+
+            assert_eq!(Foo::<u8, 2>::default(), Foo([0, 0], 0));
+        }
+    }
+}
+
+mod enums {
+    use derive_more::Default;
+
+    #[test]
+    fn unit_variant() {
+        #[derive(Debug, Default, PartialEq)]
+        enum Foo {
+            Bar(i32),
+            #[default]
+            Baz,
+        }
+
+        assert_eq!(Foo::default(), Foo::Baz);
+    }
+
+    #[test]
+    fn tuple_variant() {
+        #[derive(Debug, Default, PartialEq)]
+        enum Foo {
+            Bar,
+            #[default]
+            Baz(i32, #[default(true)] bool),
+        }
+
+        assert_eq!(Foo::default(), Foo::Baz(0, true));
+    }
+
+    #[test]
+    fn named_variant() {
+        #[derive(Debug, Default, PartialEq)]
+        enum Foo {
+            #[default]
+            Bar {
+                #[default(1.5)]
+                a: f32,
+                b: u8,
+            },
+            Baz,
+        }
+
+        assert_eq!(Foo::default(), Foo::Bar { a: 1.5, b: 0 });
+    }
+
+    mod generic {
+        use derive_more::Default;
+
+        #[derive(Debug, PartialEq)]
+        struct NoDefault;
+
+        #[test]
+        fn only_default_variant_is_bounded() {
+            #[derive(Debug, Default, PartialEq)]
+            enum Foo<A, B> {
+                Bar(A),
+                #[default]
+                Baz(Option<B>),
+            }
+
+            assert_eq!(Foo::<NoDefault, NoDefault>::default(), Foo::Baz(None));
+        }
+    }
+}
@@ -0,0 +1,119 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use derive_more::Discriminant;
+
+#[derive(Discriminant)]
+enum Enum {
+    Unit,
+    Tuple(i32, String),
+    Named { a: bool },
+    Empty(),
+}
+
+#[test]
+fn kind() {
+    assert!(Enum::Unit.kind() == EnumKind::Unit);
+    assert!(Enum::Tuple(1, "a".into()).kind() == EnumKind::Tuple);
+    assert!(Enum::Named { a: true }.kind() == EnumKind::Named);
+    assert!(Enum::Empty().kind() == EnumKind::Empty);
+    assert!(Enum::Unit.kind() != EnumKind::Empty);
+
+    const KIND: EnumKind = Enum::Unit.kind();
+    assert!(KIND == EnumKind::Unit);
+}
+
+#[test]
+fn is_kind() {
+    assert!(Enum::Named { a: false }.is_kind(EnumKind::Named));
+    assert!(!Enum::Named { a: false }.is_kind(EnumKind::Unit));
+
+    const IS_UNIT: bool = Enum::Unit.is_kind(EnumKind::Unit);
+    const { assert!(IS_UNIT) };
+}
+
+#[test]
+fn from() {
+    let kind = EnumKind::from(&Enum::Tuple(1, "a".into()));
+    assert!(kind == EnumKind::Tuple);
+
+    let kind: EnumKind = (&Enum::Unit).into();
+    let copied = kind;
+    assert!(kind == copied);
+}
+
+#[test]
+fn name_and_derives() {
+    #[derive(Discriminant)]
+    #[discriminant(name = Kind, derive(Debug))]
+    #[discriminant(derive(Hash, PartialOrd, Ord))]
+    enum Enum {
+        Foo(u8),
+        Bar,
+    }
+
+    assert_eq!(Enum::Foo(1).kind(), Kind::Foo);
+    assert!(Kind::Foo < Kind::Bar);
+
+    fn assert_hash<T: core::hash::Hash>() {}
+    assert_hash::<Kind>();
+}
+
+#[cfg(feature = "display")]
+#[test]
+fn forwarded_derive_more() {
+    #[cfg(not(feature = "std"))]
+    use alloc::string::ToString as _;
+
+    #[derive(Discriminant)]
+    #[discriminant(derive(derive_more::Display))]
+    enum Enum {
+        Foo(u8),
+    }
+
+    assert_eq!(Enum::Foo(1).kind().to_string(), "Foo");
+}
+
+#[test]
+fn generic() {
+    #[derive(Discriminant)]
+    enum Enum<'a, T, const N: usize> {
+        Foo(&'a T),
+        Bar([T; N]),
+    }
+
+    assert!(Enum::<'_, u8, 1>::Bar([1]).kind() == EnumKind::Bar);
+    assert!(EnumKind::from(&Enum::<_, 2>::Foo(&1)) == EnumKind::Foo);
+}
+
+#[test]
+fn empty() {
+    #[derive(Discriminant)]
+    enum Enum {}
+
+    fn assert_kind(e: &Enum) -> EnumKind {
+        e.kind()
+    }
+}
+
+mod visibility {
+    mod inner {
+        use derive_more::Discriminant;
+
+        #[derive(Discriminant)]
+        pub enum Enum {
+            Foo(u8),
+        }
+    }
+
+    #[test]
+    fn public() {
+        assert!(inner::Enum::Foo(1).kind() == inner::EnumKind::Foo);
+    }
+}
@@ -61,6 +61,41 @@ mod structs {
             }
         }
 
+        mod padding {
+            use super::*;
+
+            #[derive(Display)]
+            struct Unit;
+
+            #[derive(Display)]
+            #[display(rename_all = "snake_case")]
+            struct RenamedUnit;
+
+            #[derive(Display)]
+            #[display("{{str}}")]
+            struct Str;
+
+            #[derive(Display)]
+            #[display(no_pad)]
+            struct NoPadUnit;
+
+            #[derive(Display)]
+            #[display("str")]
+            #[display(no_pad)]
+            struct NoPadStr;
+
+            #[test]
+            fn assert() {
+                assert_eq!(format!("{Unit:>6}"), "  Unit");
+                assert_eq!(format!("{Unit:-^8}"), "--Unit--");
+                assert_eq!(format!("{Unit:.2}"), "Un");
+                assert_eq!(format!("{RenamedUnit:<14}|"), "renamed_unit  |");
+                assert_eq!(format!("{Str:>7}"), "  {str}");
+                assert_eq!(format!("{NoPadUnit:>12}"), "NoPadUnit");
+                assert_eq!(format!("{NoPadStr:>5}"), "str");
+            }
+        }
+
         mod interpolated {
             use super::*;
 
@@ -1948,6 +1983,49 @@ mod enums {
             "TWO"
         );
     }
+
+    mod padding {
+        use super::*;
+
+        #[derive(Display)]
+        enum Enum {
+            Unit,
+            Named {},
+            #[display("STR")]
+            Str,
+            #[display(no_pad)]
+            NoPad,
+            Field(i32),
+        }
+
+        #[derive(Display)]
+        #[display(rename_all = "kebab-case")]
+        #[display(no_pad)]
+        enum NoPadEnum {
+            UnitOne,
+            #[display("STR")]
+            Str,
+        }
+
+        #[derive(Display)]
+        #[display("Shared")]
+        enum SharedEnum {
+            A,
+            B(i32),
+        }
+
+        #[test]
+        fn assert() {
+            assert_eq!(format!("{:>6}", Enum::Unit), "  Unit");
+            assert_eq!(format!("{:<7}|", Enum::Named {}), "Named  |");
+            assert_eq!(format!("{:*^7}", Enum::Str), "**STR**");
+            assert_eq!(format!("{:>7}", Enum::NoPad), "NoPad");
+            assert_eq!(format!("{:>4}", Enum::Field(1)), "   1");
+            assert_eq!(format!("{:>10}", NoPadEnum::UnitOne), "unit-one");
+            assert_eq!(format!("{:>5}", NoPadEnum::Str), "STR");
+            assert_eq!(format!("{:>8}", SharedEnum::B(1)), "  Shared");
+        }
+    }
 }
 
 mod generic {
@@ -5,7 +5,7 @@
 extern crate alloc;
 
 #[cfg(not(feature = "std"))]
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 use core::{convert::Infallible, marker::PhantomData};
 
 use derive_more::with_trait::FromStr;
@@ -584,6 +584,244 @@ mod structs {
             }
         }
     }
+    mod format {
+        use super::*;
+
+        #[test]
+        fn named() {
+            #[derive(Debug, Eq, FromStr, PartialEq)]
+            #[from_str("({x}, {y})")]
+            struct Point {
+                x: i32,
+                y: i32,
+            }
+
+            assert_eq!("(1, -2)".parse::<Point>().unwrap(), Point { x: 1, y: -2 });
+            assert_eq!(
+                "1, 2".parse::<Point>().unwrap_err().to_string(),
+                "Invalid `Point` string representation",
+            );
+            assert_eq!(
+                "(1, 2".parse::<Point>().unwrap_err().to_string(),
+                "Invalid `Point` string representation",
+            );
+
+            let err = "(1, a)".parse::<Point>().unwrap_err();
+            assert_eq!(err.field(), Some("y"));
+            assert_eq!(err.variant(), None);
+            assert_eq!(
+                err.to_string(),
+                "Invalid `Point` string representation: cannot parse `y` field",
+            );
+        }
+
+        #[test]
+        fn unnamed() {
+            #[derive(Debug, Eq, FromStr, PartialEq)]
+            #[from_str("{_1}x{_0}")]
+            struct Size(u16, u16);
+
+            assert_eq!("3x4".parse::<Size>().unwrap(), Size(4, 3));
+            assert_eq!("3x4x5".parse::<Size>().unwrap_err().field(), Some("_0"));
+        }
+
+        #[test]
+        fn single_field() {
+            #[derive(Debug, Eq, FromStr, PartialEq)]
+            #[from_str("#{_0}")]
+            struct Id(u32);
+
+            #[derive(Debug, Eq, FromStr, PartialEq)]
+            #[from_str("{{{value}}}")]
+            struct Braced {
+                value: u8,
+            }
+
+            assert_eq!("#42".parse::<Id>().unwrap(), Id(42));
+            assert!("42".parse::<Id>().is_err());
+            assert_eq!("{7}".parse::<Braced>().unwrap(), Braced { value: 7 });
+        }
+
+        #[test]
+        fn no_fields() {
+            #[derive(Debug, Eq, FromStr, PartialEq)]
+            #[from_str("<origin>")]
+            struct Origin;
+
+            assert_eq!("<origin>".parse::<Origin>().unwrap(), Origin);
+            assert!("origin".parse::<Origin>().is_err());
+        }
+
+        #[cfg(feature = "display")]
+        #[test]
+        fn display_literal() {
+            #[derive(Debug, derive_more::Display, Eq, FromStr, PartialEq)]
+            #[from_str(display)]
+            #[display("{major}.{minor}")]
+            struct Version {
+                major: u8,
+                minor: u8,
+            }
+
+            let version = "1.82".parse::<Version>().unwrap();
+            assert_eq!(
+                version,
+                Version {
+                    major: 1,
+                    minor: 82
+                }
+            );
+            assert_eq!(version.to_string().parse::<Version>().unwrap(), version);
+        }
+
+        #[cfg(feature = "display")]
+        #[test]
+        fn display_literal_single_field() {
+            #[derive(Debug, derive_more::Display, Eq, FromStr, PartialEq)]
+            #[from_str(display)]
+            #[display("Id({_0})")]
+            struct Id(u32);
+
+            #[derive(Debug, derive_more::Display, Eq, FromStr, PartialEq)]
+            #[from_str(display)]
+            #[display("<none>")]
+            struct Nothing;
+
+            assert_eq!("Id(7)".parse::<Id>().unwrap(), Id(7));
+            assert!("7".parse::<Id>().is_err());
+            assert_eq!(Id(3).to_string().parse::<Id>().unwrap(), Id(3));
+            assert_eq!("<none>".parse::<Nothing>().unwrap(), Nothing);
+            assert!("nothing".parse::<Nothing>().is_err());
+        }
+
+        #[cfg(feature = "display")]
+        #[test]
+        fn display_literal_ignored_by_default() {
+            #[derive(Debug, derive_more::Display, Eq, FromStr, PartialEq)]
+            #[display("Id({_0})")]
+            struct Id(u32);
+
+            #[derive(Debug, derive_more::Display, Eq, FromStr, PartialEq)]
+            #[display("{_0:x}")]
+            struct Hex(u32);
+
+            #[derive(Debug, derive_more::Display, Eq, FromStr, PartialEq)]
+            #[display("<none>")]
+            struct Nothing;
+
+            assert_eq!("5".parse::<Id>().unwrap(), Id(5));
+            let _: core::num::ParseIntError = "Id(5)".parse::<Id>().unwrap_err();
+            assert_eq!("10".parse::<Hex>().unwrap(), Hex(10));
+            assert_eq!("nothing".parse::<Nothing>().unwrap(), Nothing);
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn field_error_source() {
+            use std::error::Error as _;
+
+            #[derive(Debug)]
+            struct NoError;
+
+            #[derive(Debug, PartialEq)]
+            struct Opaque;
+
+            impl FromStr for Opaque {
+                type Err = NoError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    (s == "opaque").then_some(Self).ok_or(NoError)
+                }
+            }
+
+            #[derive(Debug, FromStr, PartialEq)]
+            #[from_str("{num}/{opaque}")]
+            struct Foo {
+                num: u8,
+                opaque: Opaque,
+            }
+
+            let err = "256/opaque".parse::<Foo>().unwrap_err();
+            assert_eq!(err.field(), Some("num"));
+            assert_eq!(
+                err.source().map(ToString::to_string),
+                Some("number too large to fit in target type".into()),
+            );
+
+            let err = "1/none".parse::<Foo>().unwrap_err();
+            assert_eq!(err.field(), Some("opaque"));
+            assert!(err.source().is_none());
+        }
+
+        #[test]
+        fn no_backtracking() {
+            #[derive(Debug, Eq, FromStr, PartialEq)]
+            #[from_str("{a}-{b}")]
+            struct Pair {
+                a: String,
+                b: String,
+            }
+
+            assert_eq!(
+                "x-y-z".parse::<Pair>().unwrap(),
+                Pair {
+                    a: "x".into(),
+                    b: "y-z".into(),
+                },
+            );
+            // The first field is split off at the first separator occurrence, so the values
+            // containing it don't round-trip.
+            assert_ne!(
+                "x-y-z".parse::<Pair>().unwrap(),
+                Pair {
+                    a: "x-y".into(),
+                    b: "z".into(),
+                },
+            );
+
+            #[derive(Debug, Eq, FromStr, PartialEq)]
+            #[from_str("{a}-{b}")]
+            struct Numbers {
+                a: i8,
+                b: u8,
+            }
+
+            // `a = -1` could be matched by backtracking, but is not.
+            assert_eq!("-1-2".parse::<Numbers>().unwrap_err().field(), Some("a"));
+        }
+
+        #[test]
+        fn generic() {
+            #[derive(Debug, Eq, FromStr, PartialEq)]
+            #[from_str("{key}={value}")]
+            struct Pair<K, V> {
+                key: K,
+                value: V,
+            }
+
+            assert_eq!(
+                "a=1".parse::<Pair<char, u8>>().unwrap(),
+                Pair { key: 'a', value: 1 },
+            );
+        }
+
+        #[test]
+        fn custom_error() {
+            #[derive(Debug, Eq, PartialEq)]
+            struct CustomError(Option<&'static str>);
+
+            #[derive(Debug, Eq, FromStr, PartialEq)]
+            #[from_str("{a}-{b}")]
+            #[from_str(error(CustomError, |e: derive_more::FromStrError| CustomError(e.field())))]
+            struct Range {
+                a: u8,
+                b: u8,
+            }
+
+            assert_eq!("1-x".parse::<Range>(), Err(CustomError(Some("b"))));
+            assert_eq!("1".parse::<Range>(), Err(CustomError(None)));
+        }
+    }
 }
 
 mod enums {
@@ -1046,4 +1284,184 @@ mod enums {
             }
         }
     }
+
+    mod format {
+        use super::*;
+
+        #[test]
+        fn variants_tried_in_order() {
+            #[derive(Debug, FromStr, PartialEq)]
+            enum Shape {
+                #[from_str("circle({r})")]
+                Circle {
+                    r: f32,
+                },
+                #[from_str("rect({_0}x{_1})")]
+                Rect(u32, u32),
+                #[from_str("{_0}")]
+                Number(u32),
+                #[from_str("{_0}")]
+                Word(char),
+                Empty,
+            }
+
+            assert_eq!(
+                "circle(1.5)".parse::<Shape>().unwrap(),
+                Shape::Circle { r: 1.5 },
+            );
+            assert_eq!("rect(2x3)".parse::<Shape>().unwrap(), Shape::Rect(2, 3));
+            assert_eq!("7".parse::<Shape>().unwrap(), Shape::Number(7));
+            assert_eq!("w".parse::<Shape>().unwrap(), Shape::Word('w'));
+            assert_eq!("Empty".parse::<Shape>().unwrap(), Shape::Empty);
+            assert_eq!("empty".parse::<Shape>().unwrap(), Shape::Empty);
+
+            let err = "rect(2xz)".parse::<Shape>().unwrap_err();
+            assert_eq!(err.variant(), Some("Rect"));
+            assert_eq!(err.field(), Some("_1"));
+            assert_eq!(
+                err.to_string(),
+                "Invalid `Shape` string representation: \
+                 cannot parse `_1` field of `Rect` variant",
+            );
+
+            let err = "many".parse::<Shape>().unwrap_err();
+            assert_eq!(err.variant(), Some("Number"));
+            assert_eq!(err.field(), Some("_0"));
+        }
+
+        #[test]
+        fn rename_all_unit_variants() {
+            #[derive(Debug, Eq, FromStr, PartialEq)]
+            #[from_str(rename_all = "snake_case")]
+            enum Command {
+                #[from_str("move {_0}")]
+                Move(i8),
+                StandStill,
+                #[from_str(rename_all = "UPPERCASE")]
+                Stop,
+            }
+
+            assert_eq!("move -1".parse::<Command>().unwrap(), Command::Move(-1));
+            assert_eq!(
+                "stand_still".parse::<Command>().unwrap(),
+                Command::StandStill,
+            );
+            assert_eq!("STOP".parse::<Command>().unwrap(), Command::Stop);
+        }
+
+        #[cfg(feature = "display")]
+        #[test]
+        fn display_literal() {
+            #[derive(Debug, derive_more::Display, Eq, FromStr, PartialEq)]
+            #[from_str(display)]
+            enum Token {
+                #[display("+")]
+                Plus,
+                #[display("num:{_0}")]
+                Num(i64),
+                #[display("{name}")]
+                #[from_str("id:{name}")]
+                Ident { name: char },
+            }
+
+            assert_eq!("+".parse::<Token>().unwrap(), Token::Plus);
+            assert_eq!("num:-3".parse::<Token>().unwrap(), Token::Num(-3));
+            assert_eq!("id:x".parse::<Token>().unwrap(), Token::Ident { name: 'x' },);
+        }
+
+        #[cfg(feature = "display")]
+        #[test]
+        fn display_literal_unit_variants() {
+            #[derive(Debug, derive_more::Display, Eq, FromStr, PartialEq)]
+            #[from_str(display)]
+            enum Flat {
+                #[display("+")]
+                Plus,
+                Minus,
+            }
+
+            assert_eq!("+".parse::<Flat>().unwrap(), Flat::Plus);
+            assert!("plus".parse::<Flat>().is_err());
+            assert_eq!("minus".parse::<Flat>().unwrap(), Flat::Minus);
+            assert_eq!("Minus".parse::<Flat>().unwrap(), Flat::Minus);
+        }
+
+        #[cfg(feature = "display")]
+        #[test]
+        fn display_literal_ignored_by_default() {
+            #[derive(Debug, derive_more::Display, Eq, FromStr, PartialEq)]
+            #[display("<{_variant}>")]
+            enum Status {
+                Ok,
+                #[display("FAILED")]
+                Failed,
+            }
+
+            assert_eq!("failed".parse::<Status>().unwrap(), Status::Failed);
+            assert_eq!("Failed".parse::<Status>().unwrap(), Status::Failed);
+            assert_eq!("ok".parse::<Status>().unwrap(), Status::Ok);
+            assert!("FAILED".parse::<Status>().is_ok());
+            assert!("<FAILED>".parse::<Status>().is_err());
+        }
+
+        #[cfg(feature = "display")]
+        #[test]
+        fn display_literal_wrapping() {
+            #[derive(Debug, derive_more::Display, Eq, FromStr, PartialEq)]
+            #[from_str(display)]
+            #[display("<{_variant}>")]
+            enum Tag {
+                #[display("num:{_0}")]
+                Num(i64),
+                Named {
+                    name: char,
+                },
+                Empty,
+            }
+
+            assert_eq!("<num:-3>".parse::<Tag>().unwrap(), Tag::Num(-3));
+            assert_eq!("<x>".parse::<Tag>().unwrap(), Tag::Named { name: 'x' });
+            assert_eq!("<Empty>".parse::<Tag>().unwrap(), Tag::Empty);
+            for tag in [Tag::Num(1), Tag::Named { name: 'y' }, Tag::Empty] {
+                assert_eq!(tag.to_string().parse::<Tag>().unwrap(), tag);
+            }
+            assert!("num:-3".parse::<Tag>().is_err());
+        }
+
+        #[cfg(feature = "display")]
+        #[test]
+        fn display_literal_default() {
+            #[derive(Debug, derive_more::Display, Eq, FromStr, PartialEq)]
+            #[from_str(display)]
+            #[display("value={_0}")]
+            enum Value {
+                #[display("none")]
+                None,
+                Int(i64),
+            }
+
+            assert_eq!("none".parse::<Value>().unwrap(), Value::None);
+            assert_eq!("value=5".parse::<Value>().unwrap(), Value::Int(5));
+            assert_eq!(
+                Value::Int(-1).to_string().parse::<Value>().unwrap(),
+                Value::Int(-1)
+            );
+        }
+
+        #[test]
+        fn generic() {
+            #[derive(Debug, Eq, FromStr, PartialEq)]
+            enum Either<L, R> {
+                #[from_str("L:{_0}")]
+                Left(L),
+                #[from_str("R:{_0}")]
+                Right(R),
+            }
+
+            assert_eq!(
+                "R:true".parse::<Either<u8, bool>>().unwrap(),
+                Either::Right(true),
+            );
+        }
+    }
 }
@@ -0,0 +1,343 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use core::hash::{Hash, Hasher};
+
+/// Simple FNV-1a [`Hasher`], as there is none in `core`.
+struct Fnv(u64);
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 = (self.0 ^ u64::from(*b)).wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = Fnv(0xcbf29ce484222325);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+mod structs {
+    mod structural {
+        #[cfg(not(feature = "std"))]
+        use ::alloc::{boxed::Box, vec, vec::Vec};
+
+        use derive_more::Hash;
+
+        use super::super::hash;
+
+        #[test]
+        fn unit() {
+            #[derive(Hash)]
+            struct Foo;
+
+            assert_eq!(hash(&Foo), hash(&()));
+        }
+
+        #[test]
+        fn multi_field_tuple() {
+            #[derive(Hash)]
+            struct Foo(bool, i32);
+
+            assert_eq!(hash(&Foo(true, 1)), hash(&(true, 1)));
+            assert_ne!(hash(&Foo(true, 1)), hash(&Foo(true, 2)));
+        }
+
+        #[test]
+        fn multi_field_struct() {
+            #[derive(Hash)]
+            struct Bar {
+                b: bool,
+                i: i32,
+            }
+
+            assert_eq!(hash(&Bar { b: false, i: 3 }), hash(&(false, 3)));
+        }
+
+        #[test]
+        fn recursive() {
+            #[derive(Hash)]
+            struct Foo(i32, Vec<Foo>, Option<Box<Self>>);
+
+            assert_ne!(
+                hash(&Foo(1, vec![], None)),
+                hash(&Foo(1, vec![Foo(0, vec![], None)], None)),
+            );
+        }
+
+        mod skip {
+            use derive_more::{Eq, Hash, PartialEq};
+
+            use super::super::super::hash;
+
+            #[derive(Debug)]
+            struct NoHash;
+
+            #[test]
+            fn eq_skipped_fields() {
+                #[derive(Debug, Eq, Hash, PartialEq)]
+                struct Foo {
+                    num: i32,
+                    #[eq(skip)]
+                    no_hash: NoHash,
+                    #[partial_eq(ignore)]
+                    hits: u64,
+                }
+
+                let (a, b) = (
+                    Foo {
+                        num: 1,
+                        no_hash: NoHash,
+                        hits: 1,
+                    },
+                    Foo {
+                        num: 1,
+                        no_hash: NoHash,
+                        hits: 2,
+                    },
+                );
+                assert_eq!(a, b);
+                assert_eq!(hash(&a), hash(&b));
+            }
+
+            #[test]
+            fn eq_skipped_struct() {
+                #[derive(Debug, Eq, Hash, PartialEq)]
+                #[eq(skip)]
+                struct Foo(NoHash, i32);
+
+                assert_eq!(hash(&Foo(NoHash, 0)), hash(&Foo(NoHash, 1)));
+            }
+
+            #[test]
+            fn hash_skipped_fields() {
+                #[derive(Hash)]
+                struct Foo(#[hash(skip)] NoHash, i32);
+
+                assert_eq!(hash(&Foo(NoHash, 0)), hash(&0));
+            }
+
+            #[test]
+            fn consistent_skips() {
+                #[derive(Debug, Eq, Hash, PartialEq)]
+                struct Foo {
+                    num: i32,
+                    #[eq(skip)]
+                    #[hash(skip)]
+                    hits: u64,
+                }
+
+                assert_eq!(
+                    hash(&Foo { num: 0, hits: 1 }),
+                    hash(&Foo { num: 0, hits: 2 }),
+                );
+            }
+
+            #[test]
+            fn hash_skips_along_with_eq_skips() {
+                #[derive(Debug, Eq, Hash, PartialEq)]
+                struct Foo {
+                    num: i32,
+                    #[eq(skip)]
+                    cache: u64,
+                    #[hash(skip)]
+                    hits: u64,
+                }
+
+                let (a, b) = (
+                    Foo {
+                        num: 1,
+                        cache: 1,
+                        hits: 1,
+                    },
+                    Foo {
+                        num: 1,
+                        cache: 2,
+                        hits: 2,
+                    },
+                );
+                assert_ne!(a, b);
+                assert_eq!(hash(&a), hash(&b));
+                assert_eq!(
+                    a,
+                    Foo {
+                        num: 1,
+                        cache: 2,
+                        hits: 1,
+                    },
+                );
+            }
+        }
+
+        mod generic {
+            use core::marker::PhantomData;
+
+            use derive_more::Hash;
+
+            use super::super::super::hash;
+
+            trait Some {
+                type Assoc;
+            }
+
+            impl<T> Some for T {
+                type Assoc = u32;
+            }
+
+            struct NoHash;
+
+            #[test]
+            fn multi_field() {
+                #[derive(Hash)]
+                struct Foo<A: Some, B> {
+                    a: A::Assoc,
+                    b: B,
+                    c: PhantomData<A>,
+                }
+
+                assert_eq!(
+                    hash(&Foo::<NoHash, _> {
+                        a: 1,
+                        b: 2,
+                        c: PhantomData
+                    }),
+                    hash(&(1u32, 2, PhantomData::<NoHash>)),
+                );
+            }
+
+            #[test]
+            fn lifetime_and_const_param() {
+                #[derive(Hash)]
+                struct Foo<'a, const N: usize>(&'a [u8; N]);
+
+                assert_eq!(hash(&Foo(&[1, 2])), hash(&[1u8, 2]));
+            }
+        }
+    }
+}
+
+mod enums {
+    mod structural {
+        use derive_more::Hash;
+
+        use super::super::hash;
+
+        #[test]
+        fn empty() {
+            #[derive(Hash)]
+            enum E {}
+        }
+
+        #[test]
+        fn single_variant() {
+            #[derive(Hash)]
+            enum E {
+                Foo(i32, i32),
+            }
+
+            assert_eq!(hash(&E::Foo(0, 1)), hash(&(0, 1)));
+        }
+
+        #[test]
+        fn multi_variant() {
+            #[derive(Hash)]
+            enum E {
+                Foo(i32),
+                Bar,
+                Baz { a: i32 },
+            }
+
+            assert_ne!(hash(&E::Foo(0)), hash(&E::Baz { a: 0 }));
+            assert_ne!(hash(&E::Foo(0)), hash(&E::Foo(1)));
+            assert_eq!(hash(&E::Bar), hash(&E::Bar));
+        }
+
+        mod skip {
+            use derive_more::{Eq, Hash, PartialEq};
+
+            use super::super::super::hash;
+
+            #[derive(Debug)]
+            struct NoHash;
+
+            #[test]
+            fn eq_skipped_fields_and_variants() {
+                #[derive(Debug, Eq, Hash, PartialEq)]
+                enum E {
+                    Foo(#[partial_eq(skip)] NoHash, i32),
+                    #[eq(skip)]
+                    Bar(NoHash, i32),
+                    Baz(i32),
+                }
+
+                assert_eq!(hash(&E::Foo(NoHash, 0)), hash(&E::Foo(NoHash, 0)));
+                assert_ne!(hash(&E::Foo(NoHash, 0)), hash(&E::Foo(NoHash, 1)));
+                assert_eq!(E::Bar(NoHash, 0), E::Bar(NoHash, 1));
+                assert_eq!(hash(&E::Bar(NoHash, 0)), hash(&E::Bar(NoHash, 1)));
+                assert_ne!(hash(&E::Bar(NoHash, 0)), hash(&E::Baz(0)));
+            }
+
+            #[test]
+            fn hash_skipped_variants() {
+                #[derive(Hash)]
+                enum E {
+                    #[hash(skip)]
+                    Foo(NoHash),
+                    Bar(#[hash(ignore)] NoHash, i32),
+                }
+
+                assert_eq!(hash(&E::Foo(NoHash)), hash(&E::Foo(NoHash)));
+                assert_eq!(hash(&E::Bar(NoHash, 1)), hash(&E::Bar(NoHash, 1)));
+            }
+
+            #[test]
+            fn hash_skips_along_with_eq_skips() {
+                #[derive(Debug, Hash, PartialEq)]
+                enum E {
+                    Foo(#[partial_eq(skip)] i32, u8),
+                    #[hash(skip)]
+                    Bar(u8),
+                }
+
+                assert_eq!(E::Foo(1, 2), E::Foo(3, 2));
+                assert_eq!(hash(&E::Foo(1, 2)), hash(&E::Foo(3, 2)));
+                assert_ne!(E::Bar(1), E::Bar(2));
+                assert_eq!(hash(&E::Bar(1)), hash(&E::Bar(2)));
+            }
+        }
+
+        mod generic {
+            use core::marker::PhantomData;
+
+            use derive_more::Hash;
+
+            use super::super::super::hash;
+
+            struct NoHash;
+
+            #[test]
+            fn multi_variant() {
+                #[derive(Hash)]
+                enum E<A, B> {
+                    Foo(A),
+                    Bar(PhantomData<B>),
+                }
+
+                assert_ne!(
+                    hash(&E::<_, NoHash>::Foo(1)),
+                    hash(&E::<_, NoHash>::Foo(2)),
+                );
+            }
+        }
+    }
+}
@@ -18,3 +18,109 @@ struct Numbers {
     numbers: Vec<i32>,
     useless: bool,
 }
+
+mod enums {
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    use derive_more::Index;
+
+    #[derive(Index)]
+    enum Buffer {
+        Small([u8; 4]),
+        Large(Vec<u8>),
+        Named {
+            #[index]
+            bytes: Vec<u8>,
+            len: usize,
+        },
+        Skipped(#[index(ignore)] bool, Vec<u8>),
+    }
+
+    #[test]
+    fn index() {
+        assert_eq!(Buffer::Small([1, 2, 3, 4])[1], 2);
+        assert_eq!(Buffer::Large(vec![5, 6])[0], 5);
+        let named = Buffer::Named {
+            bytes: vec![7, 8, 9],
+            len: 3,
+        };
+        assert_eq!(named[2], 9);
+        assert_eq!(Buffer::Skipped(true, vec![10])[0], 10);
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(&Buffer::Small([1, 2, 3, 4])[1..3], [2, 3]);
+        assert_eq!(&Buffer::Large(vec![5, 6, 7])[..2], [5, 6]);
+    }
+
+    #[test]
+    fn generic() {
+        #[derive(Index)]
+        enum Either<L, R> {
+            Left(L),
+            Right(R),
+        }
+
+        let left = Either::<_, [i32; 2]>::Left(vec![1, 2, 3]);
+        assert_eq!(left[2], 3);
+        let right = Either::<Vec<i32>, _>::Right([4, 5]);
+        assert_eq!(right[0], 4);
+    }
+}
+
+mod typed {
+    #[cfg(not(feature = "std"))]
+    use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+    #[cfg(feature = "std")]
+    use std::collections::BTreeMap;
+
+    use derive_more::Index;
+
+    #[derive(Index)]
+    struct Registry {
+        #[index(usize)]
+        items: Vec<&'static str>,
+        #[index(&str)]
+        by_name: BTreeMap<String, usize>,
+    }
+
+    #[test]
+    fn multiple_fields() {
+        let registry = Registry {
+            items: vec!["foo", "bar"],
+            by_name: [("bar".into(), 1)].into_iter().collect(),
+        };
+        assert_eq!(registry[0], "foo");
+        assert_eq!(registry["bar"], 1);
+    }
+
+    #[test]
+    fn multiple_types() {
+        #[derive(Index)]
+        struct Tuple(#[index(usize, core::ops::Range<usize>)] Vec<i32>, bool);
+
+        let tuple = Tuple(vec![1, 2, 3], true);
+        assert_eq!(tuple[1], 2);
+        assert_eq!(tuple[1..3], [2, 3]);
+    }
+
+    #[test]
+    fn generic() {
+        #[derive(Index)]
+        struct Table<'a, T> {
+            #[index(usize)]
+            rows: Vec<T>,
+            #[index(&'a str)]
+            by_key: BTreeMap<&'a str, T>,
+        }
+
+        let table = Table {
+            rows: vec![1, 2],
+            by_key: [("one", 1)].into_iter().collect(),
+        };
+        assert_eq!(table[1], 2);
+        assert_eq!(table["one"], 1);
+    }
+}
@@ -34,3 +34,98 @@ where
         <Vec<i32> as ::core::ops::Index<__IdxT>>::index(&self.numbers, idx)
     }
 }
+
+#[cfg(feature = "index")]
+mod enums {
+    use derive_more::{Index, IndexMut};
+
+    #[derive(Index, IndexMut)]
+    enum Buffer {
+        Small([u8; 4]),
+        Large(Vec<u8>),
+        Named {
+            #[index]
+            #[index_mut]
+            bytes: Vec<u8>,
+            len: usize,
+        },
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut small = Buffer::Small([1, 2, 3, 4]);
+        small[1] = 20;
+        assert_eq!(small[1], 20);
+
+        let mut large = Buffer::Large(vec![5, 6]);
+        large[0] += 1;
+        assert_eq!(large[0], 6);
+
+        let mut named = Buffer::Named {
+            bytes: vec![7, 8],
+            len: 2,
+        };
+        named[1..].copy_from_slice(&[9]);
+        assert_eq!(&named[..], [7, 9]);
+    }
+
+    #[test]
+    fn generic() {
+        #[derive(Index, IndexMut)]
+        enum Either<L, R> {
+            Left(L),
+            Right(R),
+        }
+
+        let mut left = Either::<_, [i32; 2]>::Left(vec![1, 2, 3]);
+        left[2] = 4;
+        assert_eq!(left[2], 4);
+    }
+}
+
+#[cfg(feature = "index")]
+mod typed {
+    use std::{collections::HashMap, ops::Range};
+
+    use derive_more::{Index, IndexMut};
+
+    #[derive(Index, IndexMut)]
+    struct Registry {
+        #[index(usize)]
+        #[index_mut(usize)]
+        items: Vec<i32>,
+        #[index(&str)]
+        by_name: HashMap<String, usize>,
+        #[index(Range<usize>)]
+        #[index_mut(Range<usize>)]
+        flags: Vec<bool>,
+    }
+
+    #[test]
+    fn multiple_fields() {
+        let mut registry = Registry {
+            items: vec![1, 2],
+            by_name: [("two".into(), 1)].into_iter().collect(),
+            flags: vec![false; 3],
+        };
+        registry[0] = 10;
+        registry[1..3].fill(true);
+        assert_eq!(registry[0], 10);
+        assert_eq!(registry["two"], 1);
+        assert_eq!(registry[0..3], [false, true, true]);
+    }
+
+    #[test]
+    fn generic() {
+        #[derive(Index, IndexMut)]
+        struct Wrapper<T>(
+            #[index(usize)]
+            #[index_mut(usize)]
+            Vec<T>,
+        );
+
+        let mut wrapper = Wrapper(vec!["a"]);
+        wrapper[0] = "b";
+        assert_eq!(wrapper[0], "b");
+    }
+}
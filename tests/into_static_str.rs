@@ -0,0 +1,121 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(all(not(feature = "std"), feature = "display"))]
+extern crate alloc;
+
+use derive_more::IntoStaticStr;
+
+#[derive(Clone, Copy, IntoStaticStr)]
+enum Enum {
+    Foo,
+    BarBaz,
+    r#Raw,
+}
+
+#[test]
+fn as_str() {
+    assert_eq!(Enum::Foo.as_str(), "Foo");
+    assert_eq!(Enum::BarBaz.as_str(), "BarBaz");
+    assert_eq!(Enum::r#Raw.as_str(), "Raw");
+
+    const STR: &str = Enum::BarBaz.as_str();
+    assert_eq!(STR, "BarBaz");
+}
+
+#[test]
+fn conversions() {
+    let s: &'static str = Enum::Foo.into();
+    assert_eq!(s, "Foo");
+    let s: &'static str = (&Enum::BarBaz).into();
+    assert_eq!(s, "BarBaz");
+    assert_eq!(Enum::Foo.as_ref(), "Foo");
+
+    fn as_ref(v: impl AsRef<str>) -> usize {
+        v.as_ref().len()
+    }
+    assert_eq!(as_ref(Enum::BarBaz), 6);
+}
+
+#[test]
+fn empty() {
+    #[derive(IntoStaticStr)]
+    enum Enum {}
+
+    fn as_str(e: &Enum) -> &'static str {
+        e.as_str()
+    }
+}
+
+mod display_attrs {
+    use derive_more::IntoStaticStr;
+
+    #[test]
+    fn rename_all() {
+        #[derive(IntoStaticStr)]
+        #[display(rename_all = "snake_case")]
+        enum Enum {
+            FooBar,
+            #[display(rename_all = "SCREAMING-KEBAB-CASE")]
+            BazQux,
+        }
+
+        assert_eq!(Enum::FooBar.as_str(), "foo_bar");
+        assert_eq!(Enum::BazQux.as_str(), "BAZ-QUX");
+    }
+
+    #[test]
+    fn literal() {
+        #[derive(IntoStaticStr)]
+        #[display(rename_all = "lowercase")]
+        enum Enum {
+            #[display("foo")]
+            Foo,
+            #[display("{{bar}}")]
+            Bar,
+            Baz,
+        }
+
+        assert_eq!(Enum::Foo.as_str(), "foo");
+        assert_eq!(Enum::Bar.as_str(), "{bar}");
+        assert_eq!(Enum::Baz.as_str(), "baz");
+    }
+
+    #[test]
+    fn other_args_ignored() {
+        #[derive(IntoStaticStr)]
+        #[display(no_pad)]
+        enum Enum {
+            #[display(rename_all = "UPPERCASE")]
+            #[display(no_pad)]
+            Foo,
+        }
+
+        assert_eq!(Enum::Foo.as_str(), "FOO");
+    }
+
+    #[cfg(feature = "display")]
+    mod same_as_display {
+        #[cfg(not(feature = "std"))]
+        use ::alloc::string::ToString as _;
+
+        use derive_more::{Display, IntoStaticStr};
+
+        #[derive(Clone, Copy, Display, IntoStaticStr)]
+        #[display(rename_all = "kebab-case")]
+        enum Enum {
+            FooBar,
+            #[display(rename_all = "UPPERCASE")]
+            BazQux,
+            #[display("{{quux}}")]
+            Quux,
+        }
+
+        #[test]
+        fn assert() {
+            for v in [Enum::FooBar, Enum::BazQux, Enum::Quux] {
+                assert_eq!(v.to_string(), v.as_str());
+            }
+        }
+    }
+}
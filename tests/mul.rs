@@ -114,6 +114,194 @@ mod structs {
                 assert_eq!((a * -3).x, -36);
             }
         }
+
+        mod by_ref {
+            use core::marker::PhantomData;
+
+            use derive_more::Mul;
+
+            #[test]
+            #[allow(clippy::op_ref)] // reference implementation is tested
+            fn tuple() {
+                #[derive(Debug, Mul, PartialEq)]
+                #[mul(ref)]
+                struct MyInts(i32, i32);
+
+                let a = MyInts(1, 2);
+
+                assert_eq!(&a * 3, MyInts(3, 6));
+                assert_eq!(&a * &3, MyInts(3, 6));
+                assert_eq!(a * 2, MyInts(2, 4));
+            }
+
+            #[test]
+            fn generic_struct() {
+                #[derive(Debug, Mul, PartialEq)]
+                #[mul(ref)]
+                struct Point<T, M> {
+                    x: T,
+                    #[mul(skip)]
+                    marker: PhantomData<M>,
+                }
+
+                let a = Point::<_, ()> {
+                    x: 2.5,
+                    marker: PhantomData,
+                };
+
+                assert_eq!(
+                    &a * 2.0,
+                    Point {
+                        x: 5.0,
+                        marker: PhantomData,
+                    },
+                );
+            }
+        }
+
+        mod commutative {
+            use derive_more::Mul;
+
+            #[derive(Clone, Copy, Debug, Mul, PartialEq)]
+            #[mul(commutative(f32, f64))]
+            struct Vec2<T> {
+                x: T,
+                y: T,
+            }
+
+            #[test]
+            fn struct_() {
+                let v = Vec2 { x: 1.0_f32, y: 2.0 };
+
+                assert_eq!(2.0_f32 * v, Vec2 { x: 2.0, y: 4.0 });
+                assert_eq!(2.0_f32 * v, v * 2.0_f32);
+
+                let v = Vec2 { x: 1.0_f64, y: 2.0 };
+
+                assert_eq!(3.0_f64 * v, Vec2 { x: 3.0, y: 6.0 });
+            }
+
+            #[test]
+            #[allow(clippy::op_ref)] // reference implementation is tested
+            fn multiple_attributes() {
+                #[derive(Debug, Mul, PartialEq)]
+                #[mul(commutative(i32))]
+                #[mul(commutative(&'static i32))]
+                struct MyInt(i32);
+
+                assert_eq!(3 * MyInt(2), MyInt(6));
+                assert_eq!(&3 * MyInt(2), MyInt(6));
+            }
+
+            #[test]
+            fn typed() {
+                #[derive(Debug, PartialEq)]
+                struct Seconds(f64);
+
+                #[derive(Debug, Mul, PartialEq)]
+                #[mul(no_scalar, commutative(f64))]
+                #[mul(f64, output = Seconds)]
+                struct Hertz(f64);
+
+                assert_eq!(2.0 * Hertz(3.0), Seconds(6.0));
+            }
+        }
+
+        mod const_ {
+            use derive_more::{Div, Mul, Shl};
+
+            #[derive(Clone, Copy, Debug, Div, Mul, PartialEq)]
+            #[mul(const)]
+            #[div(const)]
+            struct Vec2 {
+                x: i32,
+                y: i32,
+            }
+
+            const DOUBLED: Vec2 = Vec2 { x: 1, y: 2 }.mul(2);
+            const HALVED: Vec2 = DOUBLED.div(4);
+
+            #[test]
+            fn struct_() {
+                assert_eq!(DOUBLED, Vec2 { x: 2, y: 4 });
+                assert_eq!(HALVED, Vec2 { x: 0, y: 1 });
+                assert_eq!(DOUBLED / 2, Vec2 { x: 1, y: 2 });
+            }
+
+            #[test]
+            fn tuple() {
+                #[derive(Debug, PartialEq, Shl)]
+                #[shl(const)]
+                struct Bits(u32, u32);
+
+                const BITS: Bits = Bits(1, 3).shl(2);
+
+                assert_eq!(BITS, Bits(4, 12));
+                assert_eq!(Bits(1, 3) << 2, BITS);
+            }
+        }
+
+        mod shift_types {
+            use derive_more::{Shl, Shr};
+
+            #[test]
+            fn tuple() {
+                #[derive(Debug, PartialEq, Shl, Shr)]
+                #[shl(u8, u32, usize)]
+                #[shr(u32)]
+                struct Flags(u64);
+
+                assert_eq!(Flags(1) << 3_u8, Flags(8));
+                assert_eq!(Flags(1) << 4_u32, Flags(16));
+                assert_eq!(Flags(1) << 5_usize, Flags(32));
+                assert_eq!(Flags(32) >> 2_u32, Flags(8));
+            }
+
+            #[test]
+            fn struct_() {
+                #[derive(Debug, PartialEq, Shl, Shr)]
+                #[shl(u32)]
+                #[shr(u8)]
+                #[shr(u32)]
+                struct Fixed {
+                    raw: i32,
+                    frac: i16,
+                }
+
+                let a = Fixed { raw: 1, frac: -1 };
+
+                assert_eq!(a << 2, Fixed { raw: 4, frac: -4 });
+                assert_eq!(
+                    Fixed { raw: 8, frac: -8 } >> 1_u8,
+                    Fixed { raw: 4, frac: -4 }
+                );
+                assert_eq!(
+                    Fixed { raw: 8, frac: -8 } >> 3_u32,
+                    Fixed { raw: 1, frac: -1 }
+                );
+            }
+
+            #[test]
+            fn generic() {
+                #[derive(Debug, PartialEq, Shl)]
+                #[shl(u8)]
+                struct Bits<T>(T);
+
+                assert_eq!(Bits(1_u16) << 2_u8, Bits(4_u16));
+                assert_eq!(Bits(1_i64) << 3_u8, Bits(8_i64));
+            }
+
+            #[test]
+            #[allow(clippy::op_ref)] // reference implementation is tested
+            fn by_ref() {
+                #[derive(Debug, PartialEq, Shl)]
+                #[shl(ref)]
+                struct Flags(u64);
+
+                assert_eq!(&Flags(1) << 3_u8, Flags(8));
+                assert_eq!(Flags(1) << 3_u8, Flags(8));
+            }
+        }
     }
 
     mod structural {
@@ -163,6 +351,21 @@ mod structs {
             );
         }
 
+        #[test]
+        fn by_ref() {
+            #[derive(Debug, Mul, PartialEq)]
+            #[mul(forward, ref)]
+            struct MyInts(i32, i32);
+
+            let a = MyInts(1, 2);
+            let b = MyInts(3, 5);
+
+            assert_eq!(&a * &b, MyInts(3, 10));
+            assert_eq!(&a * MyInts(2, 2), MyInts(2, 4));
+            assert_eq!(MyInts(2, 2) * &b, MyInts(6, 10));
+            assert_eq!(a * b, MyInts(3, 10));
+        }
+
         mod generic {
             use derive_more::Mul;
 
@@ -243,6 +446,93 @@ mod structs {
                 assert_eq!((a * b).x, 24);
             }
         }
+
+        mod const_ {
+            use derive_more::Mul;
+
+            #[derive(Clone, Copy, Debug, Mul, PartialEq)]
+            #[mul(forward, const)]
+            struct Scale(i32, i64);
+
+            const SCALE: Scale = Scale(2, 3).mul(Scale(4, 5));
+
+            #[test]
+            fn tuple() {
+                assert_eq!(SCALE, Scale(8, 15));
+                assert_eq!(Scale(2, 3) * Scale(4, 5), SCALE);
+            }
+        }
+    }
+
+    mod typed {
+        use derive_more::{Div, Mul};
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct SquareMeters(f64);
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Seconds(f64);
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct MetersPerSecond(f64);
+
+        #[derive(Clone, Copy, Debug, Div, Mul, PartialEq)]
+        #[mul(no_scalar)]
+        #[mul(Meters, output = SquareMeters)]
+        #[mul(core::primitive::f64, output = Meters)]
+        #[div(no_scalar)]
+        #[div(Seconds, output = MetersPerSecond)]
+        #[div(Self, output = f64)]
+        struct Meters(f64);
+
+        #[test]
+        fn tuple() {
+            assert_eq!(Meters(2.0) * Meters(3.0), SquareMeters(6.0));
+            assert_eq!(Meters(2.0) * 3.0, Meters(6.0));
+            assert_eq!(Meters(6.0) / Seconds(2.0), MetersPerSecond(3.0));
+            assert_eq!(Meters(6.0) / Meters(2.0), 3.0);
+        }
+
+        #[test]
+        fn struct_() {
+            #[derive(Debug, Mul, PartialEq)]
+            #[mul(no_scalar)]
+            #[mul(Self, output = Self)]
+            #[mul(Seconds, output = Seconds)]
+            struct Factor {
+                value: f64,
+            }
+
+            assert_eq!(
+                Factor { value: 2.0 } * Factor { value: 4.0 },
+                Factor { value: 8.0 },
+            );
+            assert_eq!(Factor { value: 2.0 } * Seconds(4.0), Seconds(8.0));
+        }
+
+        #[test]
+        fn forward() {
+            #[derive(Debug, Mul, PartialEq)]
+            #[mul(forward)]
+            #[mul(u32, output = u32)]
+            struct Ratio(u32);
+
+            assert_eq!(Ratio(2) * Ratio(3), Ratio(6));
+            assert_eq!(Ratio(2) * 3, 6);
+        }
+
+        #[test]
+        fn generic_output() {
+            #[derive(Debug, PartialEq)]
+            struct Area<T>(T);
+
+            #[derive(Debug, Mul, PartialEq)]
+            #[mul(no_scalar)]
+            #[mul(Length, output = Area<i32>)]
+            struct Length(i32);
+
+            assert_eq!(Length(2) * Length(3), Area(6));
+        }
     }
 }
 
@@ -125,6 +125,44 @@ mod structs {
                 assert_eq!(a.x, -36);
             }
         }
+
+        mod shift_types {
+            use derive_more::{ShlAssign, ShrAssign};
+
+            #[test]
+            fn tuple() {
+                #[derive(Debug, PartialEq, ShlAssign, ShrAssign)]
+                #[shl_assign(u8, u32)]
+                #[shr_assign(u32)]
+                struct Flags(u64);
+
+                let mut a = Flags(1);
+                a <<= 3_u8;
+                a <<= 1_u32;
+                assert_eq!(a, Flags(16));
+
+                a >>= 2_u32;
+                assert_eq!(a, Flags(4));
+            }
+
+            #[test]
+            fn struct_() {
+                #[derive(Debug, PartialEq, ShlAssign)]
+                #[shl_assign(u32)]
+                struct Fixed<T> {
+                    raw: T,
+                    frac: i16,
+                }
+
+                let mut a = Fixed {
+                    raw: 1_i64,
+                    frac: -1,
+                };
+                a <<= 2;
+
+                assert_eq!(a, Fixed { raw: 4, frac: -4 });
+            }
+        }
     }
 
     mod structural {
@@ -263,3 +301,44 @@ mod structs {
         }
     }
 }
+
+mod enums {
+    use derive_more::{DivAssign, MulAssign};
+
+    #[derive(MulAssign, DivAssign, Debug, PartialEq)]
+    #[mul_assign(panic)]
+    #[div_assign(try)]
+    enum Scale {
+        Linear(i32),
+        Planar { x: i32, y: i32 },
+    }
+
+    #[test]
+    fn panic() {
+        let mut a = Scale::Linear(2);
+        a *= Scale::Linear(3);
+        assert_eq!(a, Scale::Linear(6));
+
+        let mut b = Scale::Planar { x: 1, y: 2 };
+        b *= Scale::Planar { x: 3, y: 4 };
+        assert_eq!(b, Scale::Planar { x: 3, y: 8 });
+    }
+
+    #[test]
+    #[should_panic(expected = "Trying to mul_assign() mismatched enum variants")]
+    fn panic_on_mismatch() {
+        let mut a = Scale::Linear(2);
+        a *= Scale::Planar { x: 3, y: 4 };
+    }
+
+    #[test]
+    fn try_() {
+        let mut a = Scale::Planar { x: 6, y: 8 };
+        assert!(a.try_div_assign(Scale::Planar { x: 3, y: 2 }).is_ok());
+        assert_eq!(a, Scale::Planar { x: 2, y: 4 });
+
+        let err = a.try_div_assign(Scale::Linear(2)).unwrap_err();
+        assert!(matches!(err, derive_more::BinaryError::Mismatch(_)));
+        assert_eq!(a, Scale::Planar { x: 2, y: 4 });
+    }
+}
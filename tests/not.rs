@@ -85,3 +85,33 @@ mod deprecated {
         },
     }
 }
+
+mod const_ {
+    use derive_more::{Neg, Not};
+
+    #[derive(Debug, Neg, PartialEq)]
+    #[neg(const)]
+    struct Vec2 {
+        x: i64,
+        y: i32,
+    }
+
+    #[derive(Debug, Not, PartialEq)]
+    #[not(const)]
+    struct Flags(u8, bool);
+
+    const NEGATED: Vec2 = Vec2 { x: 15, y: -2 }.neg();
+    const INVERTED: Flags = Flags(0b1010_1010, true).not();
+
+    #[test]
+    fn neg() {
+        assert_eq!(NEGATED, Vec2 { x: -15, y: 2 });
+        assert_eq!(-Vec2 { x: 15, y: -2 }, NEGATED);
+    }
+
+    #[test]
+    fn not() {
+        assert_eq!(INVERTED, Flags(0b0101_0101, false));
+        assert_eq!(!Flags(0b1010_1010, true), INVERTED);
+    }
+}
@@ -0,0 +1,357 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+mod structs {
+    mod structural {
+        #[cfg(not(feature = "std"))]
+        use ::alloc::{boxed::Box, vec, vec::Vec};
+        use core::cmp::Ordering;
+
+        use derive_more::{Eq, Ord, PartialEq, PartialOrd};
+
+        #[test]
+        fn unit() {
+            #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+            struct Baz;
+
+            assert_eq!(Baz.cmp(&Baz), Ordering::Equal);
+        }
+
+        #[test]
+        fn empty_struct() {
+            #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+            struct Bar {}
+
+            assert_eq!(Bar {}.cmp(&Bar {}), Ordering::Equal);
+        }
+
+        #[test]
+        fn multi_field_tuple() {
+            #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+            struct Foo(bool, i32);
+
+            assert_eq!(Foo(false, 1).cmp(&Foo(true, 0)), Ordering::Less);
+            assert_eq!(Foo(true, 1).cmp(&Foo(true, 0)), Ordering::Greater);
+            assert_eq!(Foo(true, 0).cmp(&Foo(true, 0)), Ordering::Equal);
+        }
+
+        #[test]
+        fn multi_field_struct() {
+            #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+            struct Bar {
+                b: bool,
+                i: i32,
+            }
+
+            assert_eq!(
+                Bar { b: false, i: 1 }.cmp(&Bar { b: true, i: 0 }),
+                Ordering::Less,
+            );
+            assert_eq!(
+                Bar { b: true, i: 1 }.max(Bar { b: true, i: 0 }),
+                Bar { b: true, i: 1 },
+            );
+        }
+
+        #[test]
+        fn recursive() {
+            #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+            struct Foo(i32, Vec<Foo>, Option<Box<Self>>);
+
+            assert_eq!(
+                Foo(1, vec![], None).cmp(&Foo(1, vec![Foo(0, vec![], None)], None)),
+                Ordering::Less,
+            );
+        }
+
+        mod skip {
+            use core::cmp::Ordering;
+
+            use derive_more::{Eq, Ord, PartialEq, PartialOrd};
+
+            #[derive(Debug)]
+            struct NoOrd;
+
+            #[test]
+            fn fields() {
+                #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+                struct Foo(#[eq(skip)] NoOrd, i32);
+
+                assert_eq!(Foo(NoOrd, 0).cmp(&Foo(NoOrd, 1)), Ordering::Less);
+                assert_eq!(Foo(NoOrd, 0).cmp(&Foo(NoOrd, 0)), Ordering::Equal);
+            }
+
+            #[test]
+            fn all_fields() {
+                #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+                #[eq(skip)]
+                struct Foo(NoOrd, i32);
+
+                assert_eq!(Foo(NoOrd, 0).cmp(&Foo(NoOrd, 1)), Ordering::Equal);
+            }
+        }
+
+        mod customized {
+            use core::cmp::Ordering;
+
+            use derive_more::{Eq, Ord, PartialEq, PartialOrd};
+
+            fn by_len(a: &&str, b: &&str) -> Ordering {
+                a.len().cmp(&b.len())
+            }
+
+            #[test]
+            fn reverse_and_by() {
+                #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+                struct Foo {
+                    #[ord(reverse)]
+                    a: u8,
+                    #[ord(by = by_len)]
+                    b: &'static str,
+                    #[partial_ord(reverse, by = by_len)]
+                    c: &'static str,
+                }
+
+                assert_eq!(
+                    Foo {
+                        a: 1,
+                        b: "z",
+                        c: ""
+                    }
+                    .cmp(&Foo {
+                        a: 0,
+                        b: "a",
+                        c: ""
+                    }),
+                    Ordering::Less,
+                );
+                assert_eq!(
+                    Foo {
+                        a: 0,
+                        b: "z",
+                        c: ""
+                    }
+                    .cmp(&Foo {
+                        a: 0,
+                        b: "aa",
+                        c: ""
+                    }),
+                    Ordering::Less,
+                );
+                assert_eq!(
+                    Foo {
+                        a: 0,
+                        b: "z",
+                        c: "zz"
+                    }
+                    .cmp(&Foo {
+                        a: 0,
+                        b: "a",
+                        c: "a"
+                    }),
+                    Ordering::Less,
+                );
+            }
+
+            #[test]
+            fn order() {
+                #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+                #[ord(order(priority))]
+                struct Task {
+                    id: u32,
+                    priority: u8,
+                }
+
+                let mut tasks = [
+                    Task { id: 1, priority: 2 },
+                    Task { id: 2, priority: 1 },
+                    Task { id: 0, priority: 2 },
+                ];
+                tasks.sort();
+
+                assert_eq!(
+                    tasks,
+                    [
+                        Task { id: 2, priority: 1 },
+                        Task { id: 0, priority: 2 },
+                        Task { id: 1, priority: 2 },
+                    ],
+                );
+            }
+        }
+
+        mod generic {
+            use core::{cmp::Ordering, marker::PhantomData};
+
+            use derive_more::{Eq, Ord, PartialEq, PartialOrd};
+
+            trait Some {
+                type Assoc;
+            }
+
+            impl<T> Some for T {
+                type Assoc = u32;
+            }
+
+            #[derive(Debug, Eq, PartialEq)]
+            struct NoOrd;
+
+            #[test]
+            fn multi_field() {
+                #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+                struct Foo<A: Some, B> {
+                    a: A::Assoc,
+                    b: B,
+                    c: PhantomData<A>,
+                }
+
+                assert_eq!(
+                    Foo::<NoOrd, _> {
+                        a: 0,
+                        b: 1,
+                        c: PhantomData
+                    }
+                    .cmp(&Foo {
+                        a: 1,
+                        b: 0,
+                        c: PhantomData
+                    }),
+                    Ordering::Less,
+                );
+            }
+
+            #[test]
+            fn lifetime_and_const_param() {
+                #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+                struct Foo<'a, const N: usize>(&'a [u8; N]);
+
+                assert_eq!(Foo(&[1, 2]).cmp(&Foo(&[1, 3])), Ordering::Less);
+            }
+        }
+    }
+}
+
+mod enums {
+    mod structural {
+        use core::cmp::Ordering;
+
+        use derive_more::{Eq, Ord, PartialEq, PartialOrd};
+
+        #[test]
+        fn empty() {
+            #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+            enum E {}
+        }
+
+        #[test]
+        fn single_variant() {
+            #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+            enum E {
+                Foo(i32, i32),
+            }
+
+            assert_eq!(E::Foo(0, 1).cmp(&E::Foo(1, 0)), Ordering::Less);
+        }
+
+        #[test]
+        fn multi_variant() {
+            #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+            enum E {
+                Foo(i32),
+                Bar,
+                Baz { a: u8 },
+            }
+
+            let mut values = [
+                E::Baz { a: 1 },
+                E::Bar,
+                E::Foo(1),
+                E::Baz { a: 0 },
+                E::Foo(0),
+            ];
+            values.sort();
+
+            assert_eq!(
+                values,
+                [
+                    E::Foo(0),
+                    E::Foo(1),
+                    E::Bar,
+                    E::Baz { a: 0 },
+                    E::Baz { a: 1 }
+                ],
+            );
+        }
+
+        mod skip {
+            use core::cmp::Ordering;
+
+            use derive_more::{Eq, Ord, PartialEq, PartialOrd};
+
+            #[derive(Debug)]
+            struct NoOrd;
+
+            #[test]
+            fn fields_and_variants() {
+                #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+                enum E {
+                    Foo(#[partial_eq(skip)] NoOrd, i32),
+                    #[eq(skip)]
+                    Bar(NoOrd),
+                }
+
+                assert_eq!(E::Foo(NoOrd, 0).cmp(&E::Foo(NoOrd, 1)), Ordering::Less);
+                assert_eq!(E::Bar(NoOrd).cmp(&E::Bar(NoOrd)), Ordering::Equal);
+                assert_eq!(E::Foo(NoOrd, 1).cmp(&E::Bar(NoOrd)), Ordering::Less);
+            }
+        }
+
+        mod customized {
+            use core::cmp::Ordering;
+
+            use derive_more::{Eq, Ord, PartialEq, PartialOrd};
+
+            #[test]
+            fn variants_order() {
+                #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+                #[ord(order(Critical, Error))]
+                enum Level {
+                    Debug,
+                    Error(u8),
+                    Critical,
+                }
+
+                assert_eq!(Level::Critical.cmp(&Level::Error(0)), Ordering::Less);
+                assert_eq!(Level::Error(0).cmp(&Level::Error(1)), Ordering::Less);
+                assert_eq!(Level::Error(1).cmp(&Level::Debug), Ordering::Less);
+            }
+        }
+
+        mod generic {
+            use core::{cmp::Ordering, marker::PhantomData};
+
+            use derive_more::{Eq, Ord, PartialEq, PartialOrd};
+
+            #[derive(Debug, Eq, PartialEq)]
+            struct NoOrd;
+
+            #[test]
+            fn multi_variant() {
+                #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+                enum E<A, B> {
+                    Foo(A),
+                    Bar(PhantomData<B>),
+                }
+
+                assert_eq!(E::<_, NoOrd>::Foo(1).cmp(&E::Foo(2)), Ordering::Less);
+                assert_eq!(
+                    E::<u8, NoOrd>::Bar(PhantomData).cmp(&E::Foo(2)),
+                    Ordering::Greater,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,426 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use core::cmp::Ordering;
+
+mod structs {
+    mod structural {
+        #[cfg(not(feature = "std"))]
+        use ::alloc::{boxed::Box, vec, vec::Vec};
+        use core::cmp::Ordering;
+
+        use derive_more::{PartialEq, PartialOrd};
+
+        #[test]
+        fn unit() {
+            #[derive(Debug, PartialEq, PartialOrd)]
+            struct Baz;
+
+            assert_eq!(Baz.partial_cmp(&Baz), Some(Ordering::Equal));
+        }
+
+        #[test]
+        fn empty_tuple() {
+            #[derive(Debug, PartialEq, PartialOrd)]
+            struct Foo();
+
+            assert_eq!(Foo().partial_cmp(&Foo()), Some(Ordering::Equal));
+        }
+
+        #[test]
+        fn multi_field_tuple() {
+            #[derive(Debug, PartialEq, PartialOrd)]
+            struct Foo(bool, f32);
+
+            assert!(Foo(false, 1.0) < Foo(true, 0.0));
+            assert!(Foo(true, 0.0) < Foo(true, 1.0));
+            assert_eq!(
+                Foo(true, 0.0).partial_cmp(&Foo(true, 0.0)),
+                Some(Ordering::Equal)
+            );
+            assert_eq!(Foo(true, f32::NAN).partial_cmp(&Foo(true, 0.0)), None);
+        }
+
+        #[test]
+        fn multi_field_struct() {
+            #[derive(Debug, PartialEq, PartialOrd)]
+            struct Bar {
+                b: bool,
+                i: i32,
+            }
+
+            assert!(Bar { b: false, i: 1 } < Bar { b: true, i: 0 });
+            assert!(Bar { b: true, i: 0 } < Bar { b: true, i: 1 });
+        }
+
+        #[test]
+        fn recursive() {
+            #[derive(Debug, PartialEq, PartialOrd)]
+            struct Foo(i32, Vec<Foo>, Option<Box<Self>>);
+
+            assert!(Foo(1, vec![], None) < Foo(1, vec![Foo(0, vec![], None)], None));
+            assert!(
+                Foo(1, vec![], None)
+                    < Foo(1, vec![], Some(Box::new(Foo(0, vec![], None))))
+            );
+        }
+
+        mod skip {
+            use core::cmp::Ordering;
+
+            use derive_more::{PartialEq, PartialOrd};
+
+            #[derive(Debug)]
+            struct NoOrd;
+
+            #[test]
+            fn fields() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                struct Foo(#[partial_eq(skip)] NoOrd, i32, #[partial_ord(skip)] f32);
+
+                #[derive(Debug, PartialEq, PartialOrd)]
+                struct Bar {
+                    i: i32,
+                    #[partial_eq(ignore)]
+                    cache: u64,
+                }
+
+                assert!(Foo(NoOrd, 0, 0.0) < Foo(NoOrd, 1, 0.0));
+                assert_eq!(
+                    Foo(NoOrd, 0, 1.0).partial_cmp(&Foo(NoOrd, 0, 0.0)),
+                    Some(Ordering::Equal),
+                );
+
+                assert!(Bar { i: 0, cache: 1 } < Bar { i: 1, cache: 0 });
+                assert_eq!(
+                    Bar { i: 0, cache: 1 }.partial_cmp(&Bar { i: 0, cache: 0 }),
+                    Some(Ordering::Equal),
+                );
+            }
+
+            #[test]
+            fn all_fields() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                #[partial_eq(skip)]
+                struct Foo(NoOrd, i32);
+
+                #[derive(Debug, PartialEq, PartialOrd)]
+                struct Bar(#[partial_ord(skip)] i32);
+
+                assert_eq!(
+                    Foo(NoOrd, 0).partial_cmp(&Foo(NoOrd, 1)),
+                    Some(Ordering::Equal)
+                );
+                assert_eq!(Bar(0).partial_cmp(&Bar(1)), Some(Ordering::Equal));
+            }
+        }
+
+        mod customized {
+            use core::cmp::Ordering;
+
+            use derive_more::{PartialEq, PartialOrd};
+
+            fn by_abs(a: &i32, b: &i32) -> Ordering {
+                a.abs().cmp(&b.abs())
+            }
+
+            #[derive(Debug, PartialEq)]
+            struct NoOrd(i32);
+
+            fn by_inner(a: &NoOrd, b: &NoOrd) -> Ordering {
+                a.0.cmp(&b.0)
+            }
+
+            #[test]
+            fn reverse() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                struct Foo(i32, #[partial_ord(reverse)] i32);
+
+                assert!(Foo(0, 1) < Foo(0, 0));
+                assert!(Foo(0, 0) < Foo(1, 1));
+            }
+
+            #[test]
+            fn by() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                struct Foo {
+                    #[partial_ord(by = by_abs)]
+                    a: i32,
+                    #[ord(by = by_inner)]
+                    b: NoOrd,
+                }
+
+                assert!(Foo { a: 1, b: NoOrd(0) } < Foo { a: -2, b: NoOrd(0) });
+                assert!(Foo { a: 1, b: NoOrd(0) } < Foo { a: 1, b: NoOrd(1) });
+            }
+
+            #[test]
+            fn reverse_by() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                struct Foo(#[partial_ord(reverse, by = by_abs)] i32);
+
+                assert!(Foo(-2) < Foo(1));
+            }
+
+            #[test]
+            fn order() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                #[partial_ord(order(c, a))]
+                struct Foo {
+                    a: i32,
+                    b: i32,
+                    c: i32,
+                }
+
+                #[derive(Debug, PartialEq, PartialOrd)]
+                #[ord(order(2, 1))]
+                struct Bar(i32, i32, i32);
+
+                assert!(Foo { a: 1, b: 1, c: 0 } < Foo { a: 0, b: 0, c: 1 });
+                assert!(Foo { a: 0, b: 1, c: 0 } < Foo { a: 1, b: 0, c: 0 });
+                assert!(Foo { a: 0, b: 0, c: 0 } < Foo { a: 0, b: 1, c: 0 });
+
+                assert!(Bar(1, 1, 0) < Bar(0, 0, 1));
+                assert!(Bar(1, 0, 0) < Bar(0, 1, 0));
+                assert!(Bar(0, 0, 0) < Bar(1, 0, 0));
+            }
+        }
+
+        mod generic {
+            use core::{cmp::Ordering, marker::PhantomData};
+
+            use derive_more::{PartialEq, PartialOrd};
+
+            trait Some {
+                type Assoc;
+            }
+
+            impl<T> Some for T {
+                type Assoc = u32;
+            }
+
+            #[derive(Debug, PartialEq)]
+            struct NoOrd;
+
+            #[test]
+            fn multi_field_tuple() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                struct Foo<A: Some, B>(A::Assoc, B, PhantomData<A>);
+
+                assert!(
+                    Foo::<NoOrd, _>(0, 1.0, PhantomData) < Foo(1, 0.0, PhantomData)
+                );
+            }
+
+            #[test]
+            fn lifetime() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                struct Foo<'a>(&'a str, i32);
+
+                assert!(Foo("a", 1) < Foo("b", 0));
+            }
+
+            #[test]
+            fn const_param() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                struct Foo<const N: usize>([i32; N], i8);
+
+                assert!(Foo([3], 1) < Foo([4], 0));
+            }
+
+            #[test]
+            fn skip() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                struct Foo<A, B>(A, #[partial_eq(skip)] B);
+
+                assert_eq!(
+                    Foo(1, NoOrd).partial_cmp(&Foo(1, NoOrd)),
+                    Some(Ordering::Equal),
+                );
+            }
+        }
+    }
+}
+
+mod enums {
+    mod structural {
+        #[cfg(not(feature = "std"))]
+        use ::alloc::{boxed::Box, vec, vec::Vec};
+        use core::cmp::Ordering;
+
+        use derive_more::{PartialEq, PartialOrd};
+
+        #[test]
+        fn empty() {
+            #[derive(Debug, PartialEq, PartialOrd)]
+            enum E {}
+        }
+
+        #[test]
+        fn single_variant_unit() {
+            #[derive(Debug, PartialEq, PartialOrd)]
+            enum E {
+                Foo,
+            }
+
+            assert_eq!(E::Foo.partial_cmp(&E::Foo), Some(Ordering::Equal));
+        }
+
+        #[test]
+        fn single_variant_multi_field() {
+            #[derive(Debug, PartialEq, PartialOrd)]
+            enum E {
+                Foo { a: bool, b: f32 },
+            }
+
+            assert!(E::Foo { a: false, b: 1.0 } < E::Foo { a: true, b: 0.0 });
+            assert_eq!(
+                E::Foo {
+                    a: true,
+                    b: f32::NAN
+                }
+                .partial_cmp(&E::Foo { a: true, b: 0.0 }),
+                None,
+            );
+        }
+
+        #[test]
+        fn multi_variant_empty_and_multi_field() {
+            #[derive(Debug, PartialEq, PartialOrd)]
+            enum E {
+                Foo(i32, i32),
+                Bar,
+                Baz { a: u8 },
+            }
+
+            assert!(E::Foo(0, 1) < E::Foo(1, 0));
+            assert!(E::Foo(100, 100) < E::Bar);
+            assert!(E::Bar < E::Baz { a: 0 });
+            assert!(E::Baz { a: 0 } < E::Baz { a: 1 });
+            assert_eq!(E::Bar.partial_cmp(&E::Bar), Some(Ordering::Equal));
+        }
+
+        #[test]
+        fn recursive() {
+            #[derive(Debug, PartialEq, PartialOrd)]
+            enum E {
+                Foo(Vec<E>),
+                Bar(Option<Box<Self>>),
+            }
+
+            assert!(E::Foo(vec![]) < E::Foo(vec![E::Bar(None)]));
+            assert!(E::Bar(None) < E::Bar(Some(Box::new(E::Foo(vec![])))));
+        }
+
+        mod skip {
+            use core::cmp::Ordering;
+
+            use derive_more::{PartialEq, PartialOrd};
+
+            #[derive(Debug)]
+            struct NoOrd;
+
+            #[test]
+            fn fields_and_variants() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                enum E {
+                    Foo(i32, #[partial_eq(skip)] NoOrd),
+                    #[partial_eq(skip)]
+                    Bar(NoOrd),
+                    #[partial_ord(skip)]
+                    Baz(i32),
+                }
+
+                assert!(E::Foo(0, NoOrd) < E::Foo(1, NoOrd));
+                assert_eq!(
+                    E::Foo(0, NoOrd).partial_cmp(&E::Foo(0, NoOrd)),
+                    Some(Ordering::Equal),
+                );
+                assert_eq!(
+                    E::Bar(NoOrd).partial_cmp(&E::Bar(NoOrd)),
+                    Some(Ordering::Equal)
+                );
+                assert_eq!(E::Baz(0).partial_cmp(&E::Baz(1)), Some(Ordering::Equal));
+                assert!(E::Foo(0, NoOrd) < E::Bar(NoOrd));
+                assert!(E::Bar(NoOrd) < E::Baz(0));
+            }
+        }
+
+        mod customized {
+            use derive_more::{PartialEq, PartialOrd};
+
+            #[test]
+            fn variants_order() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                #[partial_ord(order(Baz, Bar))]
+                enum E {
+                    Foo(i32),
+                    Bar,
+                    Baz { a: u8 },
+                }
+
+                assert!(E::Baz { a: 100 } < E::Bar);
+                assert!(E::Bar < E::Foo(0));
+                assert!(E::Baz { a: 100 } < E::Foo(0));
+            }
+
+            #[test]
+            fn variant_fields() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                enum E {
+                    #[partial_ord(order(b))]
+                    Foo {
+                        a: i32,
+                        #[partial_ord(reverse)]
+                        b: i32,
+                    },
+                    Bar(i32),
+                }
+
+                assert!(E::Foo { a: 0, b: 1 } < E::Foo { a: 1, b: 0 });
+                assert!(E::Foo { a: 0, b: 0 } < E::Foo { a: 1, b: 0 });
+                assert!(E::Foo { a: 1, b: 0 } < E::Bar(0));
+            }
+        }
+
+        mod generic {
+            use core::marker::PhantomData;
+
+            use derive_more::{PartialEq, PartialOrd};
+
+            #[derive(Debug, PartialEq)]
+            struct NoOrd;
+
+            #[test]
+            fn multi_variant() {
+                #[derive(Debug, PartialEq, PartialOrd)]
+                enum E<A, B> {
+                    Foo(A),
+                    Bar(PhantomData<B>),
+                }
+
+                assert!(E::<_, NoOrd>::Foo(1) < E::Foo(2));
+                assert!(E::<i32, NoOrd>::Foo(2) < E::Bar(PhantomData));
+            }
+        }
+    }
+}
+
+#[test]
+fn ordering_is_consistent_with_equality() {
+    use derive_more::{PartialEq, PartialOrd};
+
+    #[derive(Debug, PartialEq, PartialOrd)]
+    struct Foo {
+        a: i32,
+        #[partial_eq(skip)]
+        b: i32,
+    }
+
+    let (x, y) = (Foo { a: 1, b: 2 }, Foo { a: 1, b: 3 });
+    assert_eq!(x, y);
+    assert_eq!(x.partial_cmp(&y), Some(Ordering::Equal));
+}
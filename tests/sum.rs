@@ -1,7 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(dead_code)] // some code is tested for type checking only
 
-use derive_more::Sum;
+use derive_more::{Product, Sum};
 
 #[derive(Sum)]
 struct MyInts(i32, i64);
@@ -32,3 +32,112 @@ impl ::core::ops::Add for Point2D {
         }
     }
 }
+
+struct OwnedOnly;
+
+impl ::core::iter::Sum for OwnedOnly {
+    fn sum<I: Iterator<Item = Self>>(_: I) -> Self {
+        OwnedOnly
+    }
+}
+
+// `Sum<&Self>` is not required to be implemented by fields.
+#[derive(Sum)]
+struct OwnedOnlyWrapper(OwnedOnly);
+
+// `Add` implementation is required for `Sum`.
+impl ::core::ops::Add for OwnedOnlyWrapper {
+    type Output = OwnedOnlyWrapper;
+    #[inline]
+    fn add(self, _: OwnedOnlyWrapper) -> OwnedOnlyWrapper {
+        self
+    }
+}
+
+#[derive(Product)]
+struct Wrapper<T>(T);
+
+// `Mul` implementation is required for `Product`.
+impl<T: ::core::ops::Mul<Output = T>> ::core::ops::Mul for Wrapper<T> {
+    type Output = Wrapper<T>;
+    #[inline]
+    fn mul(self, rhs: Wrapper<T>) -> Wrapper<T> {
+        Wrapper(self.0.mul(rhs.0))
+    }
+}
+
+#[derive(Debug, PartialEq, Sum, Product)]
+enum MixedInts {
+    Small(i32),
+    Big(i64),
+}
+
+// `Add` implementation is required for `Sum`.
+impl ::core::ops::Add for MixedInts {
+    type Output = Result<MixedInts, &'static str>;
+    #[inline]
+    fn add(self, rhs: MixedInts) -> Self::Output {
+        match (self, rhs) {
+            (Self::Small(l), Self::Small(r)) => Ok(Self::Small(l + r)),
+            (Self::Big(l), Self::Big(r)) => Ok(Self::Big(l + r)),
+            _ => Err("mismatched variants"),
+        }
+    }
+}
+
+// `Mul` implementation is required for `Product`.
+impl ::core::ops::Mul for MixedInts {
+    type Output = Result<MixedInts, &'static str>;
+    #[inline]
+    fn mul(self, rhs: MixedInts) -> Self::Output {
+        match (self, rhs) {
+            (Self::Small(l), Self::Small(r)) => Ok(Self::Small(l * r)),
+            (Self::Big(l), Self::Big(r)) => Ok(Self::Big(l * r)),
+            _ => Err("mismatched variants"),
+        }
+    }
+}
+
+#[test]
+fn sums_refs() {
+    let ints = [MyInts(1, 2), MyInts(3, 4)];
+    let sum = ints.iter().sum::<MyInts>();
+    assert_eq!((sum.0, sum.1), (4, 6));
+
+    let points = [Point2D { x: 1, y: 2 }, Point2D { x: 3, y: 4 }];
+    let sum: Point2D = points.iter().sum();
+    assert_eq!((sum.x, sum.y), (4, 6));
+}
+
+#[test]
+fn multiplies_refs() {
+    let wrappers = [Wrapper(2), Wrapper(3), Wrapper(4)];
+    assert_eq!(wrappers.iter().product::<Wrapper<i32>>().0, 24);
+    assert_eq!(wrappers.into_iter().product::<Wrapper<i32>>().0, 24);
+}
+
+#[test]
+fn enum_try_sum() {
+    assert_eq!(
+        MixedInts::try_sum([MixedInts::Small(1), MixedInts::Small(2)]),
+        Ok(Some(MixedInts::Small(3))),
+    );
+    assert_eq!(
+        MixedInts::try_sum([MixedInts::Small(1), MixedInts::Big(2)]),
+        Err("mismatched variants"),
+    );
+    assert_eq!(MixedInts::try_sum([]), Ok(None));
+}
+
+#[test]
+fn enum_try_product() {
+    assert_eq!(
+        MixedInts::try_product([MixedInts::Big(2), MixedInts::Big(3)]),
+        Ok(Some(MixedInts::Big(6))),
+    );
+    assert_eq!(
+        MixedInts::try_product([MixedInts::Big(2), MixedInts::Small(3)]),
+        Err("mismatched variants"),
+    );
+    assert_eq!(MixedInts::try_product([]), Ok(None));
+}
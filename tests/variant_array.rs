@@ -0,0 +1,83 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use derive_more::VariantArray;
+
+#[derive(Debug, PartialEq, VariantArray)]
+#[repr(u8)]
+enum Enum {
+    Foo,
+    Bar = 5,
+    Baz(),
+    Qux {},
+}
+
+#[test]
+fn variants() {
+    assert_eq!(
+        Enum::VARIANTS,
+        [Enum::Foo, Enum::Bar, Enum::Baz(), Enum::Qux {}]
+    );
+    assert_eq!(Enum::COUNT, 4);
+    assert_eq!(Enum::COUNT, Enum::VARIANTS.len());
+}
+
+#[test]
+fn index() {
+    const BAR: usize = Enum::Bar.index();
+
+    assert_eq!(BAR, 1);
+    for (n, variant) in Enum::VARIANTS.iter().enumerate() {
+        assert_eq!(variant.index(), n);
+        assert_eq!(Enum::from_index(n).as_ref(), Some(variant));
+    }
+    assert_eq!(Enum::from_index(4), None);
+}
+
+#[test]
+fn iter() {
+    let mut iter = Enum::iter();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(Enum::Foo));
+    assert_eq!(iter.next_back(), Some(Enum::Qux {}));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.collect::<Vec<_>>(), [Enum::Bar, Enum::Baz()]);
+
+    assert_eq!(
+        Enum::iter().rev().map(|v| v.index()).collect::<Vec<_>>(),
+        [3, 2, 1, 0],
+    );
+}
+
+#[test]
+fn empty() {
+    #[derive(Debug, PartialEq, VariantArray)]
+    enum Empty {}
+
+    assert!(Empty::VARIANTS.is_empty());
+    assert_eq!(Empty::COUNT, 0);
+    assert_eq!(Empty::iter().next(), None);
+    assert_eq!(Empty::from_index(0), None);
+}
+
+#[test]
+fn non_copy() {
+    #[derive(Debug, PartialEq, VariantArray)]
+    enum Enum {
+        Foo,
+        Bar,
+    }
+
+    impl Drop for Enum {
+        fn drop(&mut self) {}
+    }
+
+    assert_eq!(Enum::iter().collect::<Vec<_>>(), [Enum::Foo, Enum::Bar]);
+    assert_eq!(Enum::VARIANTS.len(), 2);
+}
@@ -0,0 +1,108 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code)] // some code is tested for type checking only
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use derive_more::VariantName;
+
+#[derive(VariantName)]
+enum Enum {
+    Unit,
+    Tuple(i32, String),
+    Named { a: bool },
+    Empty(),
+    r#Raw,
+}
+
+#[test]
+fn variant_name() {
+    assert_eq!(Enum::Unit.variant_name(), "Unit");
+    assert_eq!(Enum::Tuple(1, "a".into()).variant_name(), "Tuple");
+    assert_eq!(Enum::Named { a: true }.variant_name(), "Named");
+    assert_eq!(Enum::Empty().variant_name(), "Empty");
+    assert_eq!(Enum::r#Raw.variant_name(), "Raw");
+
+    const NAME: &str = Enum::Named { a: false }.variant_name();
+    assert_eq!(NAME, "Named");
+}
+
+#[test]
+fn variant_names() {
+    assert_eq!(
+        Enum::VARIANT_NAMES,
+        ["Unit", "Tuple", "Named", "Empty", "Raw"],
+    );
+}
+
+#[test]
+fn empty() {
+    #[derive(VariantName)]
+    enum Enum {}
+
+    assert!(Enum::VARIANT_NAMES.is_empty());
+}
+
+#[test]
+#[deny(unreachable_code)]
+fn uninhabited() {
+    #[derive(VariantName)]
+    enum Enum {
+        Never(core::convert::Infallible),
+        Unit,
+    }
+
+    assert_eq!(Enum::Unit.variant_name(), "Unit");
+}
+
+#[test]
+fn generic() {
+    #[derive(VariantName)]
+    enum Enum<'a, T, const N: usize> {
+        Foo(&'a T),
+        Bar([T; N]),
+    }
+
+    assert_eq!(Enum::<'_, u8, 1>::Bar([1]).variant_name(), "Bar");
+    assert_eq!(Enum::<_, 2>::Foo(&1).variant_name(), "Foo");
+    assert_eq!(Enum::<'_, u8, 3>::VARIANT_NAMES, ["Foo", "Bar"]);
+}
+
+mod rename_all {
+    use derive_more::VariantName;
+
+    #[derive(VariantName)]
+    #[variant_name(rename_all = "snake_case")]
+    enum Enum {
+        FooBar(u8),
+        #[variant_name(rename_all = "lowercase")]
+        BazQux {
+            a: u8,
+        },
+        #[variant_name(rename_all = "SCREAMING-KEBAB-CASE")]
+        QuuxCorge,
+    }
+
+    #[test]
+    fn container_and_variant() {
+        assert_eq!(Enum::FooBar(1).variant_name(), "foo_bar");
+        assert_eq!(Enum::BazQux { a: 1 }.variant_name(), "bazqux");
+        assert_eq!(Enum::QuuxCorge.variant_name(), "QUUX-CORGE");
+        assert_eq!(Enum::VARIANT_NAMES, ["foo_bar", "bazqux", "QUUX-CORGE"]);
+    }
+
+    #[test]
+    fn variant_only() {
+        #[derive(VariantName)]
+        enum Enum {
+            FooBar,
+            #[variant_name(rename_all = "camelCase")]
+            BazQux(u8),
+        }
+
+        assert_eq!(Enum::VARIANT_NAMES, ["FooBar", "bazQux"]);
+    }
+}